@@ -1,14 +1,393 @@
+use proc_macro2::TokenStream;
 use quote::quote;
-use syn::DeriveInput;
+use syn::{parse_quote, Data, DeriveInput, Fields};
 
-#[proc_macro_derive(BorshSchema, attributes(alias, schema_skip))]
+#[proc_macro_derive(
+    BorshSchema,
+    attributes(alias, borsh, schema_accounts, schema_rename, schema_skip)
+)]
 pub fn borsh_schema(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
-    let name = input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(parse_quote!(::agsol_borsh_schema::BorshSchema));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // type arguments of concrete instances, e.g. `u64` in `Wrapper<u64>`
+    let type_args = generics
+        .type_params()
+        .map(|param| {
+            let ident = &param.ident;
+            quote!(<#ident as ::agsol_borsh_schema::BorshSchema>::schema_type())
+        })
+        .collect::<Vec<_>>();
+    let schema_type = |name: &str| {
+        if type_args.is_empty() {
+            quote!(::agsol_borsh_schema::BorshType::Custom(#name.to_owned()))
+        } else {
+            quote! {
+                ::agsol_borsh_schema::BorshType::Generic(
+                    #name.to_owned(),
+                    vec![#(#type_args),*],
+                )
+            }
+        }
+    };
+
+    let type_name = name.to_string();
+    let self_type = schema_type(&type_name);
+    let docs = doc_strings(&input.attrs);
+    let add_layouts = match &input.data {
+        Data::Struct(data) => {
+            let (fields, dependencies) = layout_fields(&data.fields)?;
+            quote! {
+                layouts.push(::agsol_borsh_schema::Layout::derived(
+                    name,
+                    ::agsol_borsh_schema::Kind::Struct,
+                    vec![#(#fields),*],
+                    &[#(#docs),*],
+                    module_path!(),
+                    file!(),
+                ));
+                #(#dependencies)*
+            }
+        }
+        Data::Enum(data) => {
+            let discriminants = discriminants(&input.attrs, data)?;
+            let mut variant_fields = Vec::new();
+            let mut variant_layouts = Vec::new();
+            for (variant, discriminant) in data.variants.iter().zip(discriminants) {
+                let discriminant = match discriminant {
+                    Some(discriminant) => quote!(Some(#discriminant)),
+                    None => quote!(None),
+                };
+                let variant_str = variant.ident.to_string();
+                let variant_name = format!("{}{}", type_name, variant_str);
+                let variant_type = schema_type(&variant_name);
+                let variant_docs = doc_strings(&variant.attrs);
+                let accounts = accounts(&variant.attrs)?;
+                let (fields, dependencies) = layout_fields(&variant.fields)?;
+                variant_fields.push(quote! {
+                    ::agsol_borsh_schema::LayoutField::derived_variant(
+                        #variant_name,
+                        #variant_type,
+                        #variant_str,
+                        &[#(#variant_docs),*],
+                        #discriminant,
+                        vec![#(#accounts),*],
+                        options,
+                    )
+                });
+                variant_layouts.push(quote! {
+                    layouts.push(::agsol_borsh_schema::Layout::derived(
+                        #variant_type.to_ident(),
+                        ::agsol_borsh_schema::Kind::Struct,
+                        vec![#(#fields),*],
+                        &[#(#variant_docs),*],
+                        module_path!(),
+                        file!(),
+                    ));
+                    #(#dependencies)*
+                });
+            }
+            quote! {
+                layouts.push(::agsol_borsh_schema::Layout::derived(
+                    name,
+                    ::agsol_borsh_schema::Kind::Enum,
+                    vec![#(#variant_fields),*],
+                    &[#(#docs),*],
+                    module_path!(),
+                    file!(),
+                ));
+                #(#variant_layouts)*
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "BorshSchema can't be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::agsol_borsh_schema::BorshSchema for #name #ty_generics #where_clause {
+            ::agsol_borsh_schema::__schema_methods! {
+                fn schema_type() -> ::agsol_borsh_schema::BorshType {
+                    #self_type
+                }
+
+                fn add_layouts(
+                    layouts: &mut ::std::vec::Vec<::agsol_borsh_schema::Layout>,
+                    options: &::agsol_borsh_schema::LayoutOptions,
+                ) {
+                    let name = <Self as ::agsol_borsh_schema::BorshSchema>::schema_type().to_ident();
+                    if layouts.iter().any(|layout| layout.is_derived(&name, module_path!())) {
+                        return;
+                    }
+                    #add_layouts
+                }
+            }
+        }
+    })
+}
+
+/// Returns the expressions constructing the layout fields along with the
+/// statements that add the layouts of the field types. Fields skipped by
+/// `schema_skip` or by borsh don't need to implement `BorshSchema`, while aliased fields are represented by
+/// the type in the `alias` attribute. Fields renamed by `schema_rename` keep
+/// the provided name.
+fn layout_fields(fields: &Fields) -> Result<(Vec<TokenStream>, Vec<TokenStream>), syn::Error> {
+    let mut layout_fields = Vec::new();
+    let mut dependencies = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let ident = match &field.ident {
+            Some(ident) => {
+                let ident = ident.to_string();
+                let ident = ident.trim_start_matches("r#");
+                quote!(Some(#ident))
+            }
+            None => quote!(None),
+        };
+        let rename = match rename(&field.attrs)? {
+            Some(rename) => quote!(Some(#rename)),
+            None => quote!(None),
+        };
+        let docs = doc_strings(&field.attrs);
+        let ty = if field.attrs.iter().any(is_skip_attribute) {
+            quote!(::agsol_borsh_schema::BorshType::Skip)
+        } else {
+            let ty = match field.attrs.iter().find(|attr| attr.path.is_ident("alias")) {
+                Some(alias) => alias.parse_args::<syn::Type>()?,
+                None => field.ty.clone(),
+            };
+            dependencies.push(quote! {
+                <#ty as ::agsol_borsh_schema::BorshSchema>::add_layouts(layouts, options);
+            });
+            quote!(<#ty as ::agsol_borsh_schema::BorshSchema>::schema_type())
+        };
+        layout_fields.push(quote! {
+            ::agsol_borsh_schema::LayoutField::derived(
+                #ident,
+                #rename,
+                #i,
+                #ty,
+                &[#(#docs),*],
+                options,
+            )
+        });
+    }
+    Ok((layout_fields, dependencies))
+}
+
+/// Returns the serialized discriminant of every variant, or `None`s if the
+/// variants are serialized by their indices. Explicit discriminants are
+/// serialized only with `#[borsh(use_discriminant = true)]`, and have to be
+/// integer literals.
+fn discriminants(
+    attrs: &[syn::Attribute],
+    data: &syn::DataEnum,
+) -> Result<Vec<Option<u8>>, syn::Error> {
+    let explicit = data
+        .variants
+        .iter()
+        .any(|variant| variant.discriminant.is_some());
+    if !explicit || use_discriminant(attrs)? != Some(true) {
+        return Ok(vec![None; data.variants.len()]);
+    }
+
+    let mut next = 0_i128;
+    let mut discriminants = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        let value = match &variant.discriminant {
+            Some((_, expr)) => discriminant_value(expr).ok_or_else(|| {
+                syn::Error::new_spanned(expr, "discriminants have to be integer literals")
+            })?,
+            None => next,
+        };
+        let discriminant = u8::try_from(value).map_err(|_| {
+            syn::Error::new(
+                variant.ident.span(),
+                format!("discriminant {} doesn't fit into a `u8`", value),
+            )
+        })?;
+        discriminants.push(Some(discriminant));
+        next = value + 1;
+    }
+    Ok(discriminants)
+}
 
-    let token_stream2 = quote! {
-        impl #impl_generics BorshSchema for #name #ty_generics #where_clause {}
+/// Parses the value of `use_discriminant` from the `#[borsh(...)]` attribute.
+fn use_discriminant(attrs: &[syn::Attribute]) -> Result<Option<bool>, syn::Error> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("borsh")) {
+        if let Ok(syn::Meta::List(list)) = attr.parse_meta() {
+            for nested in &list.nested {
+                if let syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) = nested {
+                    if name_value.path.is_ident("use_discriminant") {
+                        return match &name_value.lit {
+                            syn::Lit::Bool(value) => Ok(Some(value.value)),
+                            lit => Err(syn::Error::new_spanned(
+                                lit,
+                                "`use_discriminant` expects `true` or `false`",
+                            )),
+                        };
+                    }
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn discriminant_value(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => discriminant_value(expr).map(|value| -value),
+        syn::Expr::Paren(paren) => discriminant_value(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Returns the expressions constructing the accounts declared by the
+/// `#[schema_accounts(...)]` attribute of an instruction variant, e.g.
+/// `#[schema_accounts(signer writable payer, readonly system_program)]`.
+fn accounts(attrs: &[syn::Attribute]) -> Result<Vec<TokenStream>, syn::Error> {
+    let attr = match attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("schema_accounts"))
+    {
+        Some(attr) => attr,
+        None => return Ok(Vec::new()),
+    };
+    let entries = attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let mut entries = Vec::new();
+        while !input.is_empty() {
+            let mut idents = Vec::new();
+            while !input.is_empty() && !input.peek(syn::Token![,]) {
+                idents.push(input.parse::<syn::Ident>()?);
+            }
+            entries.push(idents);
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(entries)
+    })?;
+
+    let mut names = Vec::<String>::new();
+    let mut accounts = Vec::new();
+    for entry in entries {
+        let (name, flags) = entry
+            .split_last()
+            .ok_or_else(|| syn::Error::new_spanned(attr, "expected an account name"))?;
+        let (mut signer, mut writable, mut readonly) = (false, false, false);
+        for flag in flags {
+            match flag.to_string().as_str() {
+                "signer" => signer = true,
+                "writable" => writable = true,
+                "readonly" => readonly = true,
+                _ => {
+                    return Err(syn::Error::new(
+                        flag.span(),
+                        "unknown account flag, expected `signer`, `writable` or `readonly`",
+                    ))
+                }
+            }
+        }
+        if readonly && writable {
+            return Err(syn::Error::new(
+                name.span(),
+                "an account can't be both `readonly` and `writable`",
+            ));
+        }
+        let name_str = name.to_string();
+        if names.contains(&name_str) {
+            return Err(syn::Error::new(
+                name.span(),
+                "the account is declared multiple times",
+            ));
+        }
+        accounts.push(quote! {
+            ::agsol_borsh_schema::InstructionAccount::derived(#name_str, #signer, #writable)
+        });
+        names.push(name_str);
+    }
+    Ok(accounts)
+}
+
+/// Parses the name of a field renamed by `#[schema_rename = "..."]`.
+fn rename(attrs: &[syn::Attribute]) -> Result<Option<String>, syn::Error> {
+    let attr = match attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("schema_rename"))
+    {
+        Some(attr) => attr,
+        None => return Ok(None),
     };
-    token_stream2.into()
+    match attr.parse_meta()? {
+        syn::Meta::NameValue(syn::MetaNameValue {
+            lit: syn::Lit::Str(name),
+            ..
+        }) if is_identifier(&name.value()) => Ok(Some(name.value())),
+        _ => Err(syn::Error::new_spanned(
+            attr,
+            "expected `#[schema_rename = \"...\"]` with a valid identifier",
+        )),
+    }
+}
+
+/// Checks whether the name consists of alphanumeric characters and
+/// underscores and doesn't start with a digit.
+fn is_identifier(name: &str) -> bool {
+    matches!(name.chars().next(), Some(c) if !c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns `true` for `#[schema_skip]` and for the attributes skipping the
+/// field in borsh, i.e. `#[borsh_skip]` and `#[borsh(skip)]`.
+fn is_skip_attribute(attr: &syn::Attribute) -> bool {
+    if attr.path.is_ident("schema_skip") || attr.path.is_ident("borsh_skip") {
+        return true;
+    }
+    match attr.parse_meta() {
+        Ok(syn::Meta::List(list)) if list.path.is_ident("borsh") => {
+            list.nested.iter().any(|nested| {
+                matches!(nested, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip"))
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Returns the raw lines of the doc comment of an item.
+fn doc_strings(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(doc),
+                ..
+            })) => Some(doc.value()),
+            _ => None,
+        })
+        .collect()
 }
@@ -0,0 +1,81 @@
+mod python;
+mod typescript;
+
+pub use python::PythonBackend;
+pub use typescript::TypeScriptBackend;
+
+use crate::error::SchemaError;
+use crate::layout::Layout;
+
+use std::path::PathBuf;
+
+/// Converts layouts into the code of a specific language or format.
+///
+/// The generated file consists of the prelude, followed by every converted
+/// layout and the schema map. Only [`SchemaBackend::file_name`] and
+/// [`SchemaBackend::layout`] are required, the rest of the parts are empty
+/// by default. Backends that generate multiple files should override
+/// [`SchemaBackend::files`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// # use agsol_borsh_schema::{Layout, SchemaBackend};
+/// /// Lists the fields of every data structure in a markdown file.
+/// struct MarkdownBackend;
+///
+/// impl SchemaBackend for MarkdownBackend {
+///     fn file_name(&self) -> String {
+///         "schema.md".to_owned()
+///     }
+///
+///     fn layout(&self, layout: &Layout, _layouts: &[Layout]) -> String {
+///         let fields = layout
+///             .fields
+///             .iter()
+///             .map(|field| format!("- `{}`\n", field.name))
+///             .collect::<String>();
+///         format!("## {}\n\n{}\n", layout.name, fields)
+///     }
+/// }
+/// ```
+pub trait SchemaBackend {
+    /// Returns the name of the generated file, e.g. `schema.ts`.
+    fn file_name(&self) -> String;
+
+    /// Returns the code preceding the converted layouts, e.g. imports and
+    /// helper functions.
+    fn prelude(&self) -> String {
+        String::new()
+    }
+
+    /// Converts a single layout. Every layout is provided as well, so that
+    /// the layouts referenced by this one can be looked up.
+    fn layout(&self, layout: &Layout, layouts: &[Layout]) -> String;
+
+    /// Returns the code following the converted layouts, e.g. the schema
+    /// that maps classes to their serialization layouts.
+    fn schema_map(&self, _layouts: &[Layout]) -> String {
+        String::new()
+    }
+
+    /// Checks that the layouts can be converted before any file is written,
+    /// e.g. that no two generated files share a path.
+    fn validate(&self, _layouts: &[Layout]) -> Result<(), SchemaError> {
+        Ok(())
+    }
+
+    /// Returns the generated files along with their paths relative to the
+    /// output directory. By default, it generates a single file named
+    /// [`SchemaBackend::file_name`].
+    fn files(&self, layouts: &[Layout]) -> Vec<(PathBuf, String)> {
+        let layouts_string = layouts
+            .iter()
+            .map(|layout| self.layout(layout, layouts))
+            .collect::<String>();
+        vec![(
+            PathBuf::from(self.file_name()),
+            self.prelude() + &layouts_string + &self.schema_map(layouts),
+        )]
+    }
+}
@@ -0,0 +1,89 @@
+use super::SchemaBackend;
+use crate::layout::Layout;
+
+/// Generates Python dataclasses and the respective `borsh-construct`
+/// layouts. Field names are converted to snake_case.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PythonBackend;
+
+impl SchemaBackend for PythonBackend {
+    fn file_name(&self) -> String {
+        "schema.py".to_owned()
+    }
+
+    fn prelude(&self) -> String {
+        PRELUDE.to_owned()
+    }
+
+    fn layout(&self, layout: &Layout, _layouts: &[Layout]) -> String {
+        format!("\n\n{}", layout.to_py_class())
+    }
+}
+
+const PRELUDE: &str = r#"from __future__ import annotations
+
+import typing
+from dataclasses import dataclass, fields
+
+from borsh_construct import (
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Bool,
+    CStruct,
+    HashMap,
+    HashSet,
+    Option,
+    String,
+    Vec,
+)
+from construct import Adapter, Array, Error, LazyBound, Struct, Switch, this
+from construct import Bytes as FixedBytes
+
+BorshPubkey = FixedBytes(32)
+
+
+class _StructAdapter(Adapter):
+    def __init__(self, cls, subcon):
+        super().__init__(subcon)
+        self.cls = cls
+
+    def _decode(self, obj, context, path):
+        return self.cls(**{key: value for key, value in obj.items() if not key.startswith("_")})
+
+    def _encode(self, obj, context, path):
+        return {field.name: getattr(obj, field.name) for field in fields(obj)}
+
+
+class _EnumAdapter(Adapter):
+    def __init__(self, cls, kinds, subcon):
+        super().__init__(subcon)
+        self.cls = cls
+        self.kinds = kinds
+        self.indices = {kind: index for index, kind in kinds.items()}
+
+    def _decode(self, obj, context, path):
+        return self.cls(self.kinds[obj.index], obj.value)
+
+    def _encode(self, obj, context, path):
+        return {"index": self.indices[obj.kind], "value": obj.value}
+
+
+def _struct(cls, *subcons):
+    return _StructAdapter(cls, CStruct(*subcons))
+
+
+def _enum(cls, *variants):
+    # variants are (kind, subcon) or (kind, subcon, discriminant) tuples
+    indices = [variant[2] if len(variant) > 2 else index for index, variant in enumerate(variants)]
+    cases = {index: variant[1] for index, variant in zip(indices, variants)}
+    subcon = Struct("index" / U8, "value" / Switch(this.index, cases, default=Error))
+    return _EnumAdapter(cls, {index: variant[0] for index, variant in zip(indices, variants)}, subcon)
+"#;
@@ -0,0 +1,598 @@
+use super::SchemaBackend;
+use crate::error::SchemaError;
+use crate::layout::{BorshType, Layout};
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+/// Generates TypeScript classes and the respective `borsh-js` schema. This is
+/// the default backend of [`generate_output`](crate::generate_output).
+#[derive(Clone, Debug)]
+pub struct TypeScriptBackend {
+    /// Generates classes with typed constructors and `decode`/`encode`
+    /// methods that don't depend on the `Struct` and `Enum` base classes.
+    /// The borsh extensions are inlined as well, so the output doesn't
+    /// require the extensions directory.
+    pub self_contained: bool,
+    /// Generates a discriminated union type (`{Name}Union`) for every enum,
+    /// along with type guards for its variants and functions that convert
+    /// between the union and the enum class.
+    pub discriminated_unions: bool,
+    /// Generates a `{LAYOUT}_{FIELD}_MAX_LEN` constant for every field
+    /// holding a bounded container from `agsol-common`.
+    pub bound_constants: bool,
+    /// Generates a `{LAYOUT}_DISCRIMINATOR` constant next to the
+    /// `{LAYOUT}_MAX_SERIALIZED_LEN` constant of every struct deriving
+    /// `MaxSerializedLen`.
+    pub account_discriminators: bool,
+    /// Writes a separate file for every Rust module along with an `index.ts`
+    /// that re-exports every class and assembles `SCHEMA`.
+    pub split_modules: bool,
+    /// Generates a `build{Variant}` function for every variant of the enums
+    /// whose variants declare their accounts with `#[schema_accounts(...)]`,
+    /// returning a `TransactionInstruction`.
+    pub instruction_builders: bool,
+    /// The path of the directory holding the borsh extensions, relative to
+    /// the output directory. Defaults to `./extensions`.
+    pub extensions_path: String,
+}
+
+impl Default for TypeScriptBackend {
+    fn default() -> Self {
+        Self {
+            self_contained: false,
+            discriminated_unions: false,
+            bound_constants: false,
+            account_discriminators: false,
+            split_modules: false,
+            instruction_builders: false,
+            extensions_path: "./extensions".to_owned(),
+        }
+    }
+}
+
+impl SchemaBackend for TypeScriptBackend {
+    fn file_name(&self) -> String {
+        "schema.ts".to_owned()
+    }
+
+    /// Returns the prelude of layouts without signed integers or sets, while
+    /// the files generated by [`SchemaBackend::files`] inline the borsh
+    /// extensions of signed integers and sets if a layout holds one.
+    fn prelude(&self) -> String {
+        self.prelude_with(InlineExtensions::default())
+    }
+
+    /// Converts a single layout. The layouts generated for tuple types are
+    /// converted into classes returning TypeScript tuples.
+    fn layout(&self, layout: &Layout, layouts: &[Layout]) -> String {
+        let mut output = if tuple_names(layouts).contains(&layout.name) {
+            layout.to_ts_tuple_class()
+        } else if self.self_contained {
+            layout.to_self_contained_ts_class()
+        } else {
+            layout.to_ts_class()
+        };
+        if self.bound_constants {
+            output += &layout.to_ts_bound_constants();
+        }
+        output += &layout.to_ts_account_constants(layouts, self.account_discriminators);
+        if self.discriminated_unions {
+            output += &layout.to_ts_union(layouts);
+        }
+        if self.instruction_builders {
+            output += &layout.to_ts_instruction_builders(layouts);
+        }
+        output
+    }
+
+    fn schema_map(&self, layouts: &[Layout]) -> String {
+        let tuple_names = tuple_names(layouts);
+        let schema_string = layouts
+            .iter()
+            .map(|layout| {
+                if tuple_names.contains(&layout.name) {
+                    layout.to_tuple_borsh_schema()
+                } else {
+                    layout.to_borsh_schema()
+                }
+            })
+            .collect::<String>();
+        format!(
+            r#"export const SCHEMA = new Map<any, any>([{}
+]);"#,
+            schema_string
+        )
+    }
+
+    /// With `split_modules`, rejects modules that would be written into the
+    /// same file, like the root module and a module named `lib`, or a module
+    /// named `index` and the `index.ts` that exports every module.
+    fn validate(&self, layouts: &[Layout]) -> Result<(), SchemaError> {
+        if !self.split_modules {
+            return Ok(());
+        }
+        let mut owners = BTreeMap::<Vec<String>, &Layout>::new();
+        for layout in layouts {
+            let path = module_path(&layout.module);
+            let name = path.join("/") + ".ts";
+            if path == ["index"] {
+                return Err(SchemaError::NameCollision {
+                    name,
+                    first: layout.path(),
+                    first_file: layout.source.clone(),
+                    second: "SCHEMA".to_owned(),
+                    second_file: PathBuf::from("index.ts"),
+                });
+            }
+            let owner = owners.entry(path).or_insert(layout);
+            if owner.module != layout.module {
+                return Err(SchemaError::NameCollision {
+                    name,
+                    first: owner.path(),
+                    first_file: owner.source.clone(),
+                    second: layout.path(),
+                    second_file: layout.source.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn files(&self, layouts: &[Layout]) -> Vec<(PathBuf, String)> {
+        if !self.split_modules {
+            let layouts_string = layouts
+                .iter()
+                .map(|layout| self.layout(layout, layouts))
+                .collect::<String>();
+            return vec![(
+                PathBuf::from(self.file_name()),
+                self.prelude_with(InlineExtensions::of(layouts))
+                    + &layouts_string
+                    + &self.schema_map(layouts),
+            )];
+        }
+
+        let mut modules = BTreeMap::<&[String], Vec<&Layout>>::new();
+        for layout in layouts {
+            modules.entry(&layout.module).or_default().push(layout);
+        }
+        let defined_in = layouts
+            .iter()
+            .map(|layout| (layout.name.as_str(), layout.module.as_slice()))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut files = Vec::new();
+        let mut index_imports = String::new();
+        let mut index_exports = String::new();
+        for (module, module_layouts) in &modules {
+            // custom types defined in other modules, grouped by module
+            let mut imports = BTreeMap::<&[String], BTreeSet<String>>::new();
+            for field in module_layouts.iter().flat_map(|layout| &layout.fields) {
+                for name in field.ty.referenced_layouts() {
+                    if let Some(other) = defined_in.get(name.as_str()) {
+                        if other != module {
+                            imports.entry(other).or_default().insert(name);
+                        }
+                    }
+                }
+            }
+            let imports = imports
+                .iter()
+                .map(|(other, names)| {
+                    format!(
+                        "import {{ {} }} from \"{}\";\n",
+                        names.iter().cloned().collect::<Vec<_>>().join(", "),
+                        import_path(module, &module_path(other))
+                    )
+                })
+                .collect::<String>();
+            let prelude = if self.self_contained {
+                format!(
+                    r#"import {{ {} }} from "@solana/web3.js";
+import BN from "bn.js";
+import {{ deserializeUnchecked, serialize }} from "borsh";
+import {{ SCHEMA }} from "{}";
+{}
+"#,
+                    self.web3_imports(),
+                    import_path(module, &["index".to_owned()]),
+                    imports
+                )
+            } else {
+                let extensions_path = if self.extensions_path.starts_with('.') && module.len() > 1 {
+                    "../".repeat(module.len() - 1)
+                        + self
+                            .extensions_path
+                            .strip_prefix("./")
+                            .unwrap_or(&self.extensions_path)
+                } else {
+                    self.extensions_path.clone()
+                };
+                // the builders serialize the instructions with `SCHEMA`
+                let schema_import = if self.instruction_builders {
+                    format!(
+                        "import {{ SCHEMA }} from \"{}\";\n",
+                        import_path(module, &["index".to_owned()])
+                    )
+                } else {
+                    String::new()
+                };
+                format!(
+                    r#"import {{ {2} }} from "@solana/web3.js";
+import BN from "bn.js";
+{3}import Enum from "{0}/enum";
+import Struct from "{0}/struct";
+{4}{1}
+"#,
+                    extensions_path,
+                    imports,
+                    self.web3_imports(),
+                    self.borsh_import(InlineExtensions::default()),
+                    schema_import
+                )
+            };
+            let layouts_string = module_layouts
+                .iter()
+                .map(|layout| self.layout(layout, layouts))
+                .collect::<String>();
+            let path = module_path(module);
+            files.push((
+                PathBuf::from(path.join("/") + ".ts"),
+                prelude + layouts_string.trim_end() + "\n",
+            ));
+
+            let names = module_layouts
+                .iter()
+                .map(|layout| layout.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let path = import_path(&[], &path);
+            index_imports += &format!("import {{ {} }} from \"{}\";\n", names, path);
+            index_exports += &format!("export * from \"{}\";\n", path);
+        }
+
+        let extensions = InlineExtensions::of(layouts);
+        let index_prelude = if self.self_contained {
+            format!(
+                r#"import {{ PublicKey }} from "@solana/web3.js";
+import BN from "bn.js";
+import {{ BinaryReader, BinaryWriter }} from "borsh";
+{}
+{}
+{}{}"#,
+                index_imports,
+                index_exports,
+                PUBLIC_KEY_EXTENSION,
+                InlineExtensions::ALL.code()
+            )
+        } else {
+            let bn_import = if extensions.signed {
+                "import BN from \"bn.js\";\n"
+            } else {
+                ""
+            };
+            format!(
+                r#"{3}{4}import {{ borshPublicKey }} from "{0}/publicKey";
+{1}
+{2}
+borshPublicKey();
+
+{5}"#,
+                self.extensions_path,
+                index_imports,
+                index_exports,
+                bn_import,
+                self.borsh_import(extensions),
+                extensions.code(),
+            )
+        };
+        files.push((
+            PathBuf::from("index.ts"),
+            index_prelude + &self.schema_map(layouts),
+        ));
+        files
+    }
+}
+
+impl TypeScriptBackend {
+    /// Returns the prelude of the single generated file, inlining the
+    /// required borsh `extensions`.
+    fn prelude_with(&self, extensions: InlineExtensions) -> String {
+        if self.self_contained {
+            format!(
+                r#"import {{ {} }} from "@solana/web3.js";
+import BN from "bn.js";
+import {{ BinaryReader, BinaryWriter, deserializeUnchecked, serialize }} from "borsh";
+
+{}{}"#,
+                self.web3_imports(),
+                PUBLIC_KEY_EXTENSION,
+                InlineExtensions::ALL.code()
+            )
+        } else {
+            format!(
+                r#"import {{ {1} }} from "@solana/web3.js";
+import BN from "bn.js";
+{2}import Enum from "{0}/enum";
+import Struct from "{0}/struct";
+import {{ borshPublicKey }} from "{0}/publicKey";
+
+borshPublicKey();
+
+{3}"#,
+                self.extensions_path,
+                self.web3_imports(),
+                self.borsh_import(extensions),
+                extensions.code(),
+            )
+        }
+    }
+
+    /// Returns the names imported from `@solana/web3.js`.
+    fn web3_imports(&self) -> &'static str {
+        if self.instruction_builders {
+            "PublicKey, TransactionInstruction"
+        } else {
+            "PublicKey"
+        }
+    }
+
+    /// Returns the import of `serialize` used by the instruction builders if
+    /// they are generated, along with the classes extended by the inlined
+    /// borsh `extensions`. Self-contained classes import them anyway.
+    fn borsh_import(&self, extensions: InlineExtensions) -> String {
+        let mut names = extensions.imports();
+        if self.instruction_builders {
+            names.push("serialize");
+        }
+        if names.is_empty() {
+            String::new()
+        } else {
+            format!("import {{ {} }} from \"borsh\";\n", names.join(", "))
+        }
+    }
+}
+
+/// The borsh extensions required by the layouts that the extensions
+/// directory of the output doesn't provide, so they are inlined.
+#[derive(Clone, Copy, Default)]
+struct InlineExtensions {
+    /// Reads and writes signed integers.
+    signed: bool,
+    /// Writes `Set`s like arrays.
+    sets: bool,
+}
+
+impl InlineExtensions {
+    /// Every extension, inlined into the output of self-contained classes.
+    const ALL: Self = Self {
+        signed: true,
+        sets: true,
+    };
+
+    /// Returns the extensions required by the serialized fields of the
+    /// layouts.
+    fn of(layouts: &[Layout]) -> Self {
+        let mut extensions = Self::default();
+        for field in layouts
+            .iter()
+            .flat_map(|layout| &layout.fields)
+            .filter(|field| !field.should_skip())
+        {
+            field.ty.visit(&mut |ty| match ty {
+                BorshType::I8
+                | BorshType::I16
+                | BorshType::I32
+                | BorshType::I64
+                | BorshType::I128 => extensions.signed = true,
+                BorshType::Set(_) => extensions.sets = true,
+                _ => {}
+            });
+        }
+        extensions
+    }
+
+    /// Returns the names of the `borsh-js` classes the extensions extend.
+    fn imports(self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        if self.signed {
+            names.push("BinaryReader");
+        }
+        if self.signed || self.sets {
+            names.push("BinaryWriter");
+        }
+        names
+    }
+
+    /// Returns the code of the extensions.
+    fn code(self) -> String {
+        let mut code = String::new();
+        if self.signed {
+            code += SIGNED_EXTENSION;
+        }
+        if self.sets {
+            code += SET_EXTENSION;
+        }
+        code
+    }
+}
+
+/// Returns the names of the layouts generated for the tuple types the
+/// layouts refer to.
+fn tuple_names(layouts: &[Layout]) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    for field in layouts.iter().flat_map(|layout| &layout.fields) {
+        field.ty.visit(&mut |ty| {
+            if let BorshType::Tuple(_) = ty {
+                names.insert(ty.to_ident());
+            }
+        });
+    }
+    names
+}
+
+/// Returns the path of the file (without extension) the data structures of a
+/// module are written into, relative to the output directory. The root
+/// module is written into `lib.ts`.
+fn module_path(module: &[String]) -> Vec<String> {
+    if module.is_empty() {
+        vec!["lib".to_owned()]
+    } else {
+        module.to_vec()
+    }
+}
+
+/// Returns the relative import path of the file at `to` (see
+/// [`module_path`]) from the file of `module`.
+fn import_path(module: &[String], to: &[String]) -> String {
+    let directory = &module[..module.len().saturating_sub(1)];
+    let common = directory
+        .iter()
+        .zip(&to[..to.len() - 1])
+        .take_while(|(a, b)| a == b)
+        .count();
+    let prefix = if directory.len() == common {
+        "./".to_owned()
+    } else {
+        "../".repeat(directory.len() - common)
+    };
+    prefix + &to[common..].join("/")
+}
+
+/// The borsh extension of the `./extensions` directory, inlined into the
+/// output of self-contained classes.
+const PUBLIC_KEY_EXTENSION: &str = r#"(BinaryReader.prototype as any).readPublicKey = function (this: BinaryReader) {
+    return new PublicKey(this.readFixedArray(32));
+};
+(BinaryWriter.prototype as any).writePublicKey = function (this: BinaryWriter, value: PublicKey) {
+    this.writeFixedArray(value.toBytes());
+};
+"#;
+
+/// The borsh extension of signed integers, inlined into the output of
+/// self-contained classes and of layouts holding signed integers.
+const SIGNED_EXTENSION: &str = r#"(BinaryReader.prototype as any).readI8 = function (this: BinaryReader) {
+    return (this.readU8() << 24) >> 24;
+};
+(BinaryReader.prototype as any).readI16 = function (this: BinaryReader) {
+    return (this.readU16() << 16) >> 16;
+};
+(BinaryReader.prototype as any).readI32 = function (this: BinaryReader) {
+    return this.readU32() | 0;
+};
+(BinaryReader.prototype as any).readI64 = function (this: BinaryReader) {
+    return this.readU64().fromTwos(64);
+};
+(BinaryReader.prototype as any).readI128 = function (this: BinaryReader) {
+    return this.readU128().fromTwos(128);
+};
+(BinaryWriter.prototype as any).writeI8 = function (this: BinaryWriter, value: number) {
+    this.writeU8(value & 0xff);
+};
+(BinaryWriter.prototype as any).writeI16 = function (this: BinaryWriter, value: number) {
+    this.writeU16(value & 0xffff);
+};
+(BinaryWriter.prototype as any).writeI32 = function (this: BinaryWriter, value: number) {
+    this.writeU32(value >>> 0);
+};
+(BinaryWriter.prototype as any).writeI64 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU64(new BN(value).toTwos(64));
+};
+(BinaryWriter.prototype as any).writeI128 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU128(new BN(value).toTwos(128));
+};
+
+"#;
+
+/// The borsh extension writing `Set`s like arrays, inlined into the output of
+/// self-contained classes and of layouts holding sets. `borsh-js` reads them
+/// as arrays, which the generated classes convert into `Set`s.
+const SET_EXTENSION: &str = r#"const writeArray = BinaryWriter.prototype.writeArray;
+(BinaryWriter.prototype as any).writeArray = function (this: BinaryWriter, array: any[] | Set<any>, fn: any) {
+    writeArray.call(this, array instanceof Set ? Array.from(array) : array, fn);
+};
+
+"#;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn relative_import_paths() {
+        let module = |path: &str| {
+            path.split("::")
+                .filter(|name| !name.is_empty())
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            import_path(&module(""), &module_path(&module("state"))),
+            "./state"
+        );
+        assert_eq!(
+            import_path(&module("state"), &module_path(&module(""))),
+            "./lib"
+        );
+        assert_eq!(
+            import_path(&module("state"), &module_path(&module("ix::update"))),
+            "./ix/update"
+        );
+        assert_eq!(
+            import_path(&module("ix::update"), &module_path(&module("ix"))),
+            "../ix"
+        );
+        assert_eq!(
+            import_path(&module("ix::update"), &module_path(&module("ix::reset"))),
+            "./reset"
+        );
+        assert_eq!(
+            import_path(&module("a::b::c"), &module_path(&module("a::d"))),
+            "../d"
+        );
+        assert_eq!(
+            import_path(&module("a::b::c"), &["index".to_owned()]),
+            "../../index"
+        );
+    }
+
+    #[test]
+    fn signed_extension_only_when_needed() {
+        let unsigned = crate::generate_layout_from_file("src/test/borsh_btree.rs").unwrap();
+        let signed = crate::generate_layout_from_file("src/test/borsh_structs.rs").unwrap();
+        for split_modules in [false, true] {
+            let backend = TypeScriptBackend {
+                split_modules,
+                ..TypeScriptBackend::default()
+            };
+            let code = |layouts: &[Layout]| {
+                backend
+                    .files(layouts)
+                    .into_iter()
+                    .map(|(_, code)| code)
+                    .collect::<String>()
+            };
+            let unsigned = code(&unsigned);
+            assert!(!unsigned.contains("BinaryReader"));
+            assert!(!unsigned.contains("readI8"));
+            let code = code(&signed);
+            assert!(!code.contains("extensions/signed"));
+            assert!(code.contains("import { BinaryReader, BinaryWriter } from \"borsh\";\n"));
+            assert!(code.contains("\n(BinaryReader.prototype as any).readI8 = function"));
+        }
+    }
+
+    #[test]
+    fn set_extension_only_when_needed() {
+        let backend = TypeScriptBackend::default();
+        let code = |file: &str| {
+            let layouts = crate::generate_layout_from_file(file).unwrap();
+            backend.files(&layouts).remove(0).1
+        };
+        assert!(!code("src/test/borsh_btree.rs").contains("writeArray"));
+        let code = code("src/test/borsh_collections.rs");
+        assert!(code.contains("import { BinaryWriter } from \"borsh\";\n"));
+        assert!(code.contains("\nconst writeArray = BinaryWriter.prototype.writeArray;\n"));
+    }
+}
@@ -0,0 +1,108 @@
+//! Evaluation of `#[cfg(...)]` attributes against the enabled features.
+//!
+//! Only `feature = "..."` predicates can be enabled (see
+//! [`LayoutOptions::features`](crate::LayoutOptions::features)), every other
+//! configuration option, like `test` or `target_os = "..."`, is treated as
+//! unset. This way the generated schema describes the data structures of a
+//! regular (non-test) build. `cfg_attr` attributes are not evaluated.
+
+use syn::{Meta, NestedMeta};
+
+/// Returns `false` if any `cfg` attribute among the attributes evaluates to
+/// `false` with the provided features enabled. Attributes that can't be
+/// parsed are ignored, because the compiler would reject them anyway.
+pub(crate) fn is_enabled(attrs: &[syn::Attribute], features: &[String]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .all(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) if list.nested.len() == 1 => evaluate(&list.nested[0], features),
+            _ => true,
+        })
+}
+
+/// Returns `true` if the field is skipped by borsh, either by
+/// `#[borsh_skip]` or by `#[borsh(skip)]`.
+pub(crate) fn is_borsh_skipped(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path.is_ident("borsh_skip")
+            || attr.path.is_ident("borsh")
+                && matches!(attr.parse_meta(), Ok(Meta::List(list)) if list.nested.iter().any(
+                    |nested| matches!(nested, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip"))
+                ))
+    })
+}
+
+fn evaluate(predicate: &NestedMeta, features: &[String]) -> bool {
+    match predicate {
+        NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("feature") => {
+            match &name_value.lit {
+                syn::Lit::Str(feature) => features.contains(&feature.value()),
+                _ => false,
+            }
+        }
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("all") => list
+            .nested
+            .iter()
+            .all(|predicate| evaluate(predicate, features)),
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("any") => list
+            .nested
+            .iter()
+            .any(|predicate| evaluate(predicate, features)),
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("not") => {
+            list.nested.len() == 1 && !evaluate(&list.nested[0], features)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn enabled(attr: &str, features: &[&str]) -> bool {
+        let item = syn::parse_str::<syn::ItemStruct>(&format!("{} struct Foo;", attr)).unwrap();
+        let features = features.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+        is_enabled(&item.attrs, &features)
+    }
+
+    #[test]
+    fn evaluate_predicates() {
+        assert!(enabled("", &[]));
+        assert!(enabled("#[derive(Debug)]", &[]));
+        assert!(!enabled("#[cfg(test)]", &[]));
+        assert!(enabled("#[cfg(not(test))]", &[]));
+        assert!(!enabled("#[cfg(feature = \"client\")]", &[]));
+        assert!(enabled("#[cfg(feature = \"client\")]", &["client"]));
+        assert!(!enabled(
+            "#[cfg(all(feature = \"client\", feature = \"admin\"))]",
+            &["client"]
+        ));
+        assert!(enabled(
+            "#[cfg(any(test, feature = \"admin\"))]",
+            &["admin"]
+        ));
+        assert!(!enabled(
+            "#[cfg(not(target_os = \"solana\"))] #[cfg(test)]",
+            &[]
+        ));
+    }
+
+    #[test]
+    fn borsh_skipped_fields() {
+        let skipped = |attr: &str| {
+            let field =
+                syn::parse_str::<syn::ItemStruct>(&format!("struct Foo {{ {} a: u8 }}", attr))
+                    .unwrap()
+                    .fields
+                    .into_iter()
+                    .next()
+                    .unwrap();
+            is_borsh_skipped(&field.attrs)
+        };
+        assert!(skipped("#[borsh_skip]"));
+        assert!(skipped("#[borsh(skip)]"));
+        assert!(!skipped("#[borsh(serialize_with = \"foo\")]"));
+        assert!(!skipped("#[schema_skip]"));
+    }
+}
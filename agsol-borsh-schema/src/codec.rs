@@ -0,0 +1,459 @@
+//! Dynamic (de)serialization of borsh data driven by the layouts, without
+//! compiling the Rust data structures they describe.
+//!
+//! Values are represented as JSON:
+//! - integers up to 64 bits are numbers, 128-bit integers are decimal
+//!   strings (numbers are accepted when encoding as well)
+//! - public keys are base58 strings
+//! - `Vec`s, sets, arrays, byte arrays and tuples are arrays
+//! - maps are arrays of `[key, value]` pairs, because their keys aren't
+//!   necessarily strings
+//! - `None` is `null`
+//! - structs are objects keyed by the names of their fields, while enums are
+//!   objects with a single key, the name of the variant, holding the value
+//!   of the variant, e.g. `{ "Deposit": { "amount": 10 } }`
+
+pub use crate::error::{DecodeError, EncodeError};
+use crate::layout::{BorshType, Kind, Layout, LayoutField};
+
+use serde_json::{Map, Value};
+
+use std::str::FromStr;
+
+/// Decodes borsh serialized data of the root type, which is either the name
+/// of a layout (e.g. `Vault` or `WrapperU64`) or a Rust type that refers to
+/// the layouts (e.g. `Vec<Vault>`). The root has to span the whole input.
+pub fn decode(layouts: &[Layout], root: &str, data: &[u8]) -> Result<Value, DecodeError> {
+    let (value, len) = decode_prefix(layouts, root, data)?;
+    if len < data.len() {
+        return Err(DecodeError {
+            path: root.to_owned(),
+            offset: len,
+            message: format!("{} unexpected trailing bytes", data.len() - len),
+        });
+    }
+    Ok(value)
+}
+
+/// Same as [`decode`], but the root doesn't have to span the whole input,
+/// e.g. when the account holding the data is larger than the data itself.
+/// Returns the decoded value along with the number of bytes it spans.
+pub fn decode_prefix(
+    layouts: &[Layout],
+    root: &str,
+    data: &[u8],
+) -> Result<(Value, usize), DecodeError> {
+    let ty = BorshType::from_str(root).map_err(|err| DecodeError {
+        path: root.to_owned(),
+        offset: 0,
+        message: err.to_string(),
+    })?;
+    let mut decoder = Decoder {
+        layouts,
+        data,
+        offset: 0,
+    };
+    let value = decoder.value(&ty, root)?;
+    Ok((value, decoder.offset))
+}
+
+/// Encodes a JSON value of the root type (see [`decode`]) into borsh
+/// serialized data.
+pub fn encode(layouts: &[Layout], root: &str, value: &Value) -> Result<Vec<u8>, EncodeError> {
+    let ty = BorshType::from_str(root).map_err(|err| EncodeError {
+        path: root.to_owned(),
+        message: err.to_string(),
+    })?;
+    let mut encoder = Encoder {
+        layouts,
+        data: Vec::new(),
+    };
+    encoder.value(&ty, value, root)?;
+    Ok(encoder.data)
+}
+
+/// Looks up the layout of a custom type or a generic instance.
+fn find_layout<'a>(layouts: &'a [Layout], ty: &BorshType) -> Option<&'a Layout> {
+    let name = ty.to_ident();
+    layouts.iter().find(|layout| layout.name == name)
+}
+
+fn serialized_fields(layout: &Layout) -> impl Iterator<Item = &LayoutField> {
+    layout.fields.iter().filter(|field| !field.should_skip())
+}
+
+/// Returns the name of the variant as written in Rust and the value it is
+/// serialized as.
+fn variant(field: &LayoutField, index: usize) -> (&str, usize) {
+    (
+        field.variant.as_deref().unwrap_or(&field.name),
+        field.discriminant.map_or(index, usize::from),
+    )
+}
+
+struct Decoder<'a> {
+    layouts: &'a [Layout],
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn error(&self, path: &str, offset: usize, message: impl ToString) -> DecodeError {
+        DecodeError {
+            path: path.to_owned(),
+            offset,
+            message: message.to_string(),
+        }
+    }
+
+    fn bytes(&mut self, len: usize, path: &str) -> Result<&'a [u8], DecodeError> {
+        let remaining = self.data.len() - self.offset;
+        if remaining < len {
+            return Err(self.error(
+                path,
+                self.offset,
+                format!("expected {} bytes, found {}", len, remaining),
+            ));
+        }
+        let bytes = &self.data[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self, path: &str) -> Result<[u8; N], DecodeError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N, path)?);
+        Ok(array)
+    }
+
+    fn len(&mut self, path: &str) -> Result<usize, DecodeError> {
+        Ok(u32::from_le_bytes(self.array(path)?) as usize)
+    }
+
+    fn values(&mut self, ty: &BorshType, len: usize, path: &str) -> Result<Value, DecodeError> {
+        (0..len)
+            .map(|i| self.value(ty, &format!("{}[{}]", path, i)))
+            .collect()
+    }
+
+    fn value(&mut self, ty: &BorshType, path: &str) -> Result<Value, DecodeError> {
+        let offset = self.offset;
+        let value = match ty {
+            BorshType::U8 => Value::from(u8::from_le_bytes(self.array(path)?)),
+            BorshType::U16 => Value::from(u16::from_le_bytes(self.array(path)?)),
+            BorshType::U32 => Value::from(u32::from_le_bytes(self.array(path)?)),
+            BorshType::U64 => Value::from(u64::from_le_bytes(self.array(path)?)),
+            BorshType::U128 => Value::from(u128::from_le_bytes(self.array(path)?).to_string()),
+            BorshType::I8 => Value::from(i8::from_le_bytes(self.array(path)?)),
+            BorshType::I16 => Value::from(i16::from_le_bytes(self.array(path)?)),
+            BorshType::I32 => Value::from(i32::from_le_bytes(self.array(path)?)),
+            BorshType::I64 => Value::from(i64::from_le_bytes(self.array(path)?)),
+            BorshType::I128 => Value::from(i128::from_le_bytes(self.array(path)?).to_string()),
+            BorshType::Bool => match self.array::<1>(path)? {
+                [0] => Value::Bool(false),
+                [1] => Value::Bool(true),
+                [byte] => return Err(self.error(path, offset, format!("invalid bool {}", byte))),
+            },
+            BorshType::String => {
+                let len = self.len(path)?;
+                let bytes = self.bytes(len, path)?;
+                let string = std::str::from_utf8(bytes)
+                    .map_err(|err| self.error(path, offset, format!("invalid string: {}", err)))?;
+                Value::from(string)
+            }
+            BorshType::Pubkey => Value::from(bs58::encode(self.bytes(32, path)?).into_string()),
+            BorshType::Vec(inner) | BorshType::Set(inner) => {
+                let len = self.len(path)?;
+                self.values(inner, len, path)?
+            }
+            BorshType::FixedArray(inner, len) => self.values(inner, *len, path)?,
+            BorshType::FixedBytes(len) => Value::from(self.bytes(*len, path)?.to_vec()),
+            BorshType::Option(inner) => match self.array::<1>(path)? {
+                [0] => Value::Null,
+                [1] => self.value(inner, path)?,
+                [tag] => {
+                    return Err(self.error(path, offset, format!("invalid option tag {}", tag)))
+                }
+            },
+            BorshType::Map(key, value) => {
+                let len = self.len(path)?;
+                let entries = (0..len)
+                    .map(|i| {
+                        let path = format!("{}[{}]", path, i);
+                        let key = self.value(key, &path)?;
+                        let value = self.value(value, &path)?;
+                        Ok(Value::Array(vec![key, value]))
+                    })
+                    .collect::<Result<_, _>>()?;
+                Value::Array(entries)
+            }
+            BorshType::Tuple(elements) => elements
+                .iter()
+                .enumerate()
+                .map(|(i, element)| self.value(element, &format!("{}.{}", path, i)))
+                .collect::<Result<_, _>>()?,
+            BorshType::Bounded(inner, _) => self.value(inner, path)?,
+            BorshType::Custom(_) | BorshType::Generic(..) => {
+                let layout = find_layout(self.layouts, ty).ok_or_else(|| {
+                    self.error(path, offset, format!("type `{}` is not defined", ty))
+                })?;
+                self.layout(layout, path)?
+            }
+            BorshType::Skip => Value::Null,
+        };
+        Ok(value)
+    }
+
+    fn layout(&mut self, layout: &Layout, path: &str) -> Result<Value, DecodeError> {
+        match layout.kind {
+            Kind::Struct => serialized_fields(layout)
+                .map(|field| {
+                    let path = format!("{}.{}", path, field.name);
+                    Ok((field.name.clone(), self.value(&field.ty, &path)?))
+                })
+                .collect::<Result<Map<_, _>, _>>()
+                .map(Value::Object),
+            Kind::Enum => {
+                let offset = self.offset;
+                let [tag] = self.array::<1>(path)?;
+                let (name, field) = layout
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| (variant(field, i), field))
+                    .find(|((_, discriminant), _)| *discriminant == usize::from(tag))
+                    .map(|((name, _), field)| (name, field))
+                    .ok_or_else(|| {
+                        self.error(
+                            path,
+                            offset,
+                            format!("invalid variant {} of `{}`", tag, layout.name),
+                        )
+                    })?;
+                let value = self.value(&field.ty, &format!("{}.{}", path, name))?;
+                let mut variant = Map::new();
+                variant.insert(name.to_owned(), value);
+                Ok(Value::Object(variant))
+            }
+        }
+    }
+}
+
+struct Encoder<'a> {
+    layouts: &'a [Layout],
+    data: Vec<u8>,
+}
+
+impl Encoder<'_> {
+    fn error(&self, path: &str, message: impl ToString) -> EncodeError {
+        EncodeError {
+            path: path.to_owned(),
+            message: message.to_string(),
+        }
+    }
+
+    fn expected(&self, path: &str, expected: impl std::fmt::Display, found: &Value) -> EncodeError {
+        self.error(path, format!("expected {}, found `{}`", expected, found))
+    }
+
+    /// Parses integers from numbers and decimal strings.
+    fn integer<T: TryFrom<u128> + TryFrom<i128>>(
+        &self,
+        ty: &BorshType,
+        value: &Value,
+        path: &str,
+    ) -> Result<T, EncodeError> {
+        let integer = match value {
+            Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+                (Some(unsigned), _) => T::try_from(u128::from(unsigned)).ok(),
+                (None, Some(signed)) => T::try_from(i128::from(signed)).ok(),
+                (None, None) => None,
+            },
+            Value::String(string) => match (string.parse::<u128>(), string.parse::<i128>()) {
+                (Ok(unsigned), _) => T::try_from(unsigned).ok(),
+                (Err(_), Ok(signed)) => T::try_from(signed).ok(),
+                _ => None,
+            },
+            _ => None,
+        };
+        integer.ok_or_else(|| self.expected(path, format!("`{}`", ty), value))
+    }
+
+    fn len(&mut self, len: usize, path: &str) -> Result<(), EncodeError> {
+        let len = u32::try_from(len).map_err(|_| self.error(path, "too many elements"))?;
+        self.data.extend_from_slice(&len.to_le_bytes());
+        Ok(())
+    }
+
+    fn array<'v>(
+        &self,
+        value: &'v Value,
+        len: Option<usize>,
+        path: &str,
+    ) -> Result<&'v [Value], EncodeError> {
+        match (value.as_array(), len) {
+            (Some(array), Some(len)) if array.len() == len => Ok(array),
+            (Some(array), None) => Ok(array),
+            (_, Some(len)) => {
+                Err(self.expected(path, format!("an array of {} elements", len), value))
+            }
+            (None, None) => Err(self.expected(path, "an array", value)),
+        }
+    }
+
+    fn values(&mut self, ty: &BorshType, values: &[Value], path: &str) -> Result<(), EncodeError> {
+        for (i, value) in values.iter().enumerate() {
+            self.value(ty, value, &format!("{}[{}]", path, i))?;
+        }
+        Ok(())
+    }
+
+    fn value(&mut self, ty: &BorshType, value: &Value, path: &str) -> Result<(), EncodeError> {
+        match ty {
+            BorshType::U8 => self.data.push(self.integer::<u8>(ty, value, path)?),
+            BorshType::U16 => {
+                let bytes = self.integer::<u16>(ty, value, path)?.to_le_bytes();
+                self.data.extend_from_slice(&bytes)
+            }
+            BorshType::U32 => {
+                let bytes = self.integer::<u32>(ty, value, path)?.to_le_bytes();
+                self.data.extend_from_slice(&bytes)
+            }
+            BorshType::U64 => {
+                let bytes = self.integer::<u64>(ty, value, path)?.to_le_bytes();
+                self.data.extend_from_slice(&bytes)
+            }
+            BorshType::U128 => {
+                let bytes = self.integer::<u128>(ty, value, path)?.to_le_bytes();
+                self.data.extend_from_slice(&bytes)
+            }
+            BorshType::I8 => {
+                let bytes = self.integer::<i8>(ty, value, path)?.to_le_bytes();
+                self.data.extend_from_slice(&bytes)
+            }
+            BorshType::I16 => {
+                let bytes = self.integer::<i16>(ty, value, path)?.to_le_bytes();
+                self.data.extend_from_slice(&bytes)
+            }
+            BorshType::I32 => {
+                let bytes = self.integer::<i32>(ty, value, path)?.to_le_bytes();
+                self.data.extend_from_slice(&bytes)
+            }
+            BorshType::I64 => {
+                let bytes = self.integer::<i64>(ty, value, path)?.to_le_bytes();
+                self.data.extend_from_slice(&bytes)
+            }
+            BorshType::I128 => {
+                let bytes = self.integer::<i128>(ty, value, path)?.to_le_bytes();
+                self.data.extend_from_slice(&bytes)
+            }
+            BorshType::Bool => {
+                let boolean = value
+                    .as_bool()
+                    .ok_or_else(|| self.expected(path, "a bool", value))?;
+                self.data.push(u8::from(boolean))
+            }
+            BorshType::String => {
+                let string = value
+                    .as_str()
+                    .ok_or_else(|| self.expected(path, "a string", value))?;
+                self.len(string.len(), path)?;
+                self.data.extend_from_slice(string.as_bytes())
+            }
+            BorshType::Pubkey => {
+                let pubkey = value
+                    .as_str()
+                    .and_then(|string| bs58::decode(string).into_vec().ok())
+                    .filter(|bytes| bytes.len() == 32)
+                    .ok_or_else(|| self.expected(path, "a base58 public key", value))?;
+                self.data.extend_from_slice(&pubkey)
+            }
+            BorshType::Vec(inner) | BorshType::Set(inner) => {
+                let values = self.array(value, None, path)?;
+                self.len(values.len(), path)?;
+                self.values(inner, values, path)?
+            }
+            BorshType::FixedArray(inner, len) => {
+                let values = self.array(value, Some(*len), path)?;
+                self.values(inner, values, path)?
+            }
+            BorshType::FixedBytes(len) => {
+                let values = self.array(value, Some(*len), path)?;
+                self.values(&BorshType::U8, values, path)?
+            }
+            BorshType::Option(inner) => {
+                if value.is_null() {
+                    self.data.push(0);
+                } else {
+                    self.data.push(1);
+                    self.value(inner, value, path)?
+                }
+            }
+            BorshType::Map(key, value_ty) => {
+                let entries = self.array(value, None, path)?;
+                self.len(entries.len(), path)?;
+                for (i, entry) in entries.iter().enumerate() {
+                    let path = format!("{}[{}]", path, i);
+                    let pair = self.array(entry, Some(2), &path)?;
+                    self.value(key, &pair[0], &path)?;
+                    self.value(value_ty, &pair[1], &path)?;
+                }
+            }
+            BorshType::Tuple(elements) => {
+                let values = self.array(value, Some(elements.len()), path)?;
+                for (i, (element, value)) in elements.iter().zip(values).enumerate() {
+                    self.value(element, value, &format!("{}.{}", path, i))?;
+                }
+            }
+            BorshType::Bounded(inner, _) => self.value(inner, value, path)?,
+            BorshType::Custom(_) | BorshType::Generic(..) => {
+                let layout = find_layout(self.layouts, ty)
+                    .ok_or_else(|| self.error(path, format!("type `{}` is not defined", ty)))?;
+                self.layout(layout, value, path)?
+            }
+            BorshType::Skip => {}
+        }
+        Ok(())
+    }
+
+    fn layout(&mut self, layout: &Layout, value: &Value, path: &str) -> Result<(), EncodeError> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| self.expected(path, "an object", value))?;
+        match layout.kind {
+            Kind::Struct => {
+                for field in serialized_fields(layout) {
+                    let path = format!("{}.{}", path, field.name);
+                    let value = object
+                        .get(&field.name)
+                        .ok_or_else(|| self.error(&path, "missing field"))?;
+                    self.value(&field.ty, value, &path)?;
+                }
+            }
+            Kind::Enum => {
+                let (name, value) = match object.iter().next() {
+                    Some(entry) if object.len() == 1 => entry,
+                    _ => return Err(self.expected(path, "an object with a single variant", value)),
+                };
+                let (discriminant, field) = layout
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| (variant(field, i), field))
+                    .find(|((variant, _), _)| variant == name)
+                    .map(|((_, discriminant), field)| (discriminant, field))
+                    .ok_or_else(|| {
+                        self.error(
+                            path,
+                            format!("`{}` is not a variant of `{}`", name, layout.name),
+                        )
+                    })?;
+                let discriminant = u8::try_from(discriminant)
+                    .map_err(|_| self.error(path, "too many variants"))?;
+                self.data.push(discriminant);
+                self.value(&field.ty, value, &format!("{}.{}", path, name))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,317 @@
+//! Compatibility checks between two versions of the layouts.
+//!
+//! Borsh doesn't store field names or type information, so data serialized
+//! by an older version of a program can only be deserialized by the new
+//! version if the serialized representation of every data structure is
+//! unchanged. Appending new fields to a struct or new variants to an enum is
+//! fine as long as the maximum size of a data structure deriving
+//! `MaxSerializedLen` doesn't increase, because the accounts holding it were
+//! allocated with the old size. Renamed fields keep the data readable, but
+//! they change the generated classes, so they are reported as changes that
+//! only break the clients (see [`BreakingChange::breaks_serialization`]).
+
+use crate::layout::{BorshType, Kind, Layout, LayoutField};
+
+use std::fmt;
+
+/// A change between two versions of the layouts that breaks the
+/// deserialization of data serialized with the old layouts, or the clients
+/// using the generated code.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BreakingChange {
+    /// The data structure no longer exists.
+    RemovedLayout { layout: String },
+    /// A struct became an enum or vice versa.
+    ChangedKind { layout: String },
+    /// A field of the struct was removed.
+    RemovedField { layout: String, field: String },
+    /// A field of the struct was renamed, i.e. a field with a new name and
+    /// the same type took its position. The serialized data is unaffected,
+    /// but the generated classes and the clients using them are not.
+    RenamedField {
+        layout: String,
+        field: String,
+        new_name: String,
+    },
+    /// A field of the struct is serialized at a different position.
+    MovedField {
+        layout: String,
+        field: String,
+        old_position: usize,
+        new_position: usize,
+    },
+    /// The type of a field was changed. Changing the bound of a bounded
+    /// container is not a type change, only its size might increase.
+    ChangedType {
+        layout: String,
+        field: String,
+        old: BorshType,
+        new: BorshType,
+    },
+    /// A variant of the enum was removed.
+    RemovedVariant { layout: String, variant: String },
+    /// A variant of the enum is serialized with a different discriminant,
+    /// e.g. because a new variant was inserted before it.
+    ReorderedVariant {
+        layout: String,
+        variant: String,
+        old_discriminant: usize,
+        new_discriminant: usize,
+    },
+    /// The maximum serialized size of a data structure deriving
+    /// `MaxSerializedLen` increased, so it no longer fits into accounts
+    /// allocated with the old size. A `new` size
+    /// of `None` means that the size became unbounded.
+    IncreasedSize {
+        layout: String,
+        old: usize,
+        new: Option<usize>,
+    },
+}
+
+impl fmt::Display for BreakingChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RemovedLayout { layout } => write!(f, "`{}` was removed", layout),
+            Self::ChangedKind { layout } => {
+                write!(f, "`{}` changed between a struct and an enum", layout)
+            }
+            Self::RemovedField { layout, field } => {
+                write!(f, "field `{}` of `{}` was removed", field, layout)
+            }
+            Self::RenamedField {
+                layout,
+                field,
+                new_name,
+            } => write!(
+                f,
+                "field `{}` of `{}` was renamed to `{}`, which changes the generated code \
+                but not the serialized data",
+                field, layout, new_name
+            ),
+            Self::MovedField {
+                layout,
+                field,
+                old_position,
+                new_position,
+            } => write!(
+                f,
+                "field `{}` of `{}` moved from position {} to {}",
+                field, layout, old_position, new_position
+            ),
+            Self::ChangedType {
+                layout,
+                field,
+                old,
+                new,
+            } => write!(
+                f,
+                "type of field `{}` of `{}` changed from `{}` to `{}`",
+                field, layout, old, new
+            ),
+            Self::RemovedVariant { layout, variant } => {
+                write!(f, "variant `{}` of `{}` was removed", variant, layout)
+            }
+            Self::ReorderedVariant {
+                layout,
+                variant,
+                old_discriminant,
+                new_discriminant,
+            } => write!(
+                f,
+                "discriminant of variant `{}` of `{}` changed from {} to {}",
+                variant, layout, old_discriminant, new_discriminant
+            ),
+            Self::IncreasedSize { layout, old, new } => {
+                write!(f, "maximum size of `{}` increased from {} ", layout, old)?;
+                match new {
+                    Some(new) => write!(f, "to {} bytes", new),
+                    None => write!(f, "bytes to unbounded"),
+                }
+            }
+        }
+    }
+}
+
+impl BreakingChange {
+    /// Returns `false` for changes that keep the data serialized with the old
+    /// layouts readable, but still break the clients using the generated
+    /// code, e.g. renamed fields.
+    pub fn breaks_serialization(&self) -> bool {
+        !matches!(self, Self::RenamedField { .. })
+    }
+}
+
+/// Compares two versions of the layouts (e.g. the ones of the deployed
+/// program and the ones of the working tree) and returns every change that
+/// breaks the deserialization of data serialized with the old layouts.
+///
+/// Layouts are matched by their name, layouts added in the new version are
+/// not compared. Fields are matched by their name as well, while variants are
+/// matched by the name of the variant. Generic layouts are skipped, only their
+/// concrete instances are compared.
+pub fn breaking_changes(old: &[Layout], new: &[Layout]) -> Vec<BreakingChange> {
+    // removed variants are reported by their enums, not by their layouts
+    let variant_layouts = old
+        .iter()
+        .filter(|layout| layout.kind == Kind::Enum)
+        .flat_map(|layout| layout.fields.iter().map(|field| field.ty.to_ident()))
+        .collect::<Vec<_>>();
+    let mut changes = Vec::new();
+    for old_layout in old.iter().filter(|layout| layout.generics.is_empty()) {
+        let new_layout = match new.iter().find(|layout| layout.name == old_layout.name) {
+            Some(layout) => layout,
+            None if variant_layouts.contains(&old_layout.name) => continue,
+            None => {
+                changes.push(BreakingChange::RemovedLayout {
+                    layout: old_layout.name.clone(),
+                });
+                continue;
+            }
+        };
+        match (&old_layout.kind, &new_layout.kind) {
+            (Kind::Struct, Kind::Struct) => compare_fields(old_layout, new_layout, &mut changes),
+            (Kind::Enum, Kind::Enum) => compare_variants(old_layout, new_layout, &mut changes),
+            _ => {
+                changes.push(BreakingChange::ChangedKind {
+                    layout: old_layout.name.clone(),
+                });
+                continue;
+            }
+        }
+        // only the size of data structures stored in accounts allocated by
+        // their `MaxSerializedLen` matters, e.g. instructions can grow freely
+        if !old_layout.derives_max_len {
+            continue;
+        }
+        if let Some(old_len) = old_layout.max_serialized_len(old) {
+            let new_len = new_layout.max_serialized_len(new);
+            if !matches!(new_len, Some(new_len) if new_len <= old_len) {
+                changes.push(BreakingChange::IncreasedSize {
+                    layout: old_layout.name.clone(),
+                    old: old_len,
+                    new: new_len,
+                });
+            }
+        }
+    }
+    changes
+}
+
+fn compare_fields(old: &Layout, new: &Layout, changes: &mut Vec<BreakingChange>) {
+    let serialized = |layout: &Layout| -> Vec<LayoutField> {
+        layout
+            .fields
+            .iter()
+            .filter(|field| !field.should_skip())
+            .cloned()
+            .collect()
+    };
+    let old_fields = serialized(old);
+    let new_fields = serialized(new);
+    for (position, old_field) in old_fields.iter().enumerate() {
+        match new_fields
+            .iter()
+            .position(|field| field.name == old_field.name)
+        {
+            Some(new_position) if new_position != position => {
+                changes.push(BreakingChange::MovedField {
+                    layout: old.name.clone(),
+                    field: old_field.name.clone(),
+                    old_position: position,
+                    new_position,
+                })
+            }
+            Some(new_position) => {
+                let new_field = &new_fields[new_position];
+                if serialized_type(&old_field.ty) != serialized_type(&new_field.ty) {
+                    changes.push(BreakingChange::ChangedType {
+                        layout: old.name.clone(),
+                        field: old_field.name.clone(),
+                        old: old_field.ty.clone(),
+                        new: new_field.ty.clone(),
+                    });
+                }
+            }
+            None => {
+                // a field with a new name and the same type in the same
+                // position is a renamed field
+                let renamed = new_fields.get(position).filter(|new_field| {
+                    !old_fields.iter().any(|field| field.name == new_field.name)
+                        && serialized_type(&old_field.ty) == serialized_type(&new_field.ty)
+                });
+                match renamed {
+                    Some(new_field) => changes.push(BreakingChange::RenamedField {
+                        layout: old.name.clone(),
+                        field: old_field.name.clone(),
+                        new_name: new_field.name.clone(),
+                    }),
+                    None => changes.push(BreakingChange::RemovedField {
+                        layout: old.name.clone(),
+                        field: old_field.name.clone(),
+                    }),
+                }
+            }
+        }
+    }
+}
+
+fn compare_variants(old: &Layout, new: &Layout, changes: &mut Vec<BreakingChange>) {
+    for (index, old_variant) in old.fields.iter().enumerate() {
+        let variant = variant_name(old_variant);
+        match new
+            .fields
+            .iter()
+            .enumerate()
+            .find(|(_, new_variant)| variant_name(new_variant) == variant)
+        {
+            Some((new_index, new_variant)) => {
+                let old_discriminant = discriminant(old_variant, index);
+                let new_discriminant = discriminant(new_variant, new_index);
+                if old_discriminant != new_discriminant {
+                    changes.push(BreakingChange::ReorderedVariant {
+                        layout: old.name.clone(),
+                        variant: variant.to_owned(),
+                        old_discriminant,
+                        new_discriminant,
+                    });
+                }
+                // the payloads of inlined newtype variants are compared here,
+                // the layouts of other variants are compared on their own
+                if serialized_type(&old_variant.ty) != serialized_type(&new_variant.ty) {
+                    changes.push(BreakingChange::ChangedType {
+                        layout: old.name.clone(),
+                        field: old_variant.name.clone(),
+                        old: old_variant.ty.clone(),
+                        new: new_variant.ty.clone(),
+                    });
+                }
+            }
+            None => changes.push(BreakingChange::RemovedVariant {
+                layout: old.name.clone(),
+                variant: variant.to_owned(),
+            }),
+        }
+    }
+}
+
+/// Returns the name of the variant as written in Rust, e.g. `A` for the
+/// `FooA` variant of `Foo`.
+fn variant_name(field: &LayoutField) -> &str {
+    field.variant.as_deref().unwrap_or(&field.name)
+}
+
+/// Returns the value the variant is serialized as, which is its index unless
+/// it has an explicit discriminant.
+fn discriminant(field: &LayoutField, index: usize) -> usize {
+    field.discriminant.map_or(index, usize::from)
+}
+
+/// Strips the bounds of bounded containers, which don't affect the
+/// serialized representation.
+fn serialized_type(ty: &BorshType) -> BorshType {
+    ty.map(&|ty| match ty {
+        BorshType::Bounded(inner, _) => Some(serialized_type(inner)),
+        _ => None,
+    })
+}
@@ -0,0 +1,266 @@
+//! Ordering of the layouts by the layouts they depend on.
+//!
+//! The files of the parsed directory are read in an arbitrary order, so the
+//! layouts are sorted topologically before they are written into the output:
+//! every layout comes after the layouts its fields refer to, while layouts
+//! that don't depend on each other are ordered by name. This way the output
+//! is the same on every machine.
+
+use crate::error::{SchemaError, SchemaWarning, SourceLocation};
+use crate::layout::Layout;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Returns the names of the layouts each layout refers to, keyed by the name
+/// of the layout. References to undefined layouts are left out.
+fn dependency_graph(layouts: &[Layout]) -> BTreeMap<&str, BTreeSet<String>> {
+    let names = layouts
+        .iter()
+        .map(|layout| layout.name.as_str())
+        .collect::<BTreeSet<_>>();
+    layouts
+        .iter()
+        .map(|layout| {
+            let dependencies = layout
+                .fields
+                .iter()
+                .filter(|field| !field.should_skip())
+                .flat_map(|field| field.ty.referenced_layouts())
+                .filter(|name| names.contains(name.as_str()))
+                .collect();
+            (layout.name.as_str(), dependencies)
+        })
+        .collect()
+}
+
+/// Checks that every layout referred to by the fields of the layouts is
+/// defined.
+pub(crate) fn check_references(layouts: &[Layout]) -> Result<(), SchemaError> {
+    let names = layouts
+        .iter()
+        .map(|layout| layout.name.as_str())
+        .collect::<BTreeSet<_>>();
+    for layout in layouts {
+        for field in layout.fields.iter().filter(|field| !field.should_skip()) {
+            if let Some(name) = field
+                .ty
+                .referenced_layouts()
+                .into_iter()
+                .find(|name| !names.contains(name.as_str()))
+            {
+                return Err(SchemaError::UndefinedType {
+                    location: Box::new(SourceLocation {
+                        file: layout.source.clone(),
+                        item: layout.name.clone(),
+                        field: Some(field.name.clone()),
+                        ..SourceLocation::default()
+                    }),
+                    ty: name,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sorts the layouts topologically, using the names of the layouts as a
+/// tiebreaker. Layouts in a dependency cycle are ordered by name as well.
+pub(crate) fn sort_layouts(layouts: Vec<Layout>) -> Vec<Layout> {
+    let order = {
+        let graph = dependency_graph(&layouts);
+        let mut remaining = graph
+            .iter()
+            .map(|(name, dependencies)| {
+                let dependencies = dependencies
+                    .iter()
+                    .filter(|dependency| dependency != name)
+                    .cloned()
+                    .collect::<BTreeSet<_>>();
+                (name.to_string(), dependencies)
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut order = Vec::with_capacity(remaining.len());
+        while !remaining.is_empty() {
+            // if every remaining layout is part of a cycle, the first one by
+            // name is emitted anyway
+            let next = remaining
+                .iter()
+                .find(|(_, dependencies)| dependencies.is_empty())
+                .or_else(|| remaining.iter().next())
+                .map(|(name, _)| name.clone())
+                .unwrap();
+            remaining.remove(&next);
+            remaining.values_mut().for_each(|dependencies| {
+                dependencies.remove(&next);
+            });
+            order.push(next);
+        }
+        order
+    };
+
+    let mut layouts = layouts
+        .into_iter()
+        .map(|layout| (layout.name.clone(), layout))
+        .collect::<BTreeMap<_, _>>();
+    order
+        .iter()
+        .filter_map(|name| layouts.remove(name))
+        .collect()
+}
+
+/// Checks the layouts for problems that don't prevent generating the output,
+/// e.g. recursive types.
+pub fn schema_warnings(layouts: &[Layout]) -> Vec<SchemaWarning> {
+    cycles(&dependency_graph(layouts))
+        .into_iter()
+        .map(|layouts| SchemaWarning::RecursiveType { layouts })
+        .collect()
+}
+
+/// Finds the strongly connected components of the graph that contain a cycle
+/// using Tarjan's algorithm. The components are ordered by their first name.
+fn cycles(graph: &BTreeMap<&str, BTreeSet<String>>) -> Vec<Vec<String>> {
+    #[derive(Default)]
+    struct State<'a> {
+        index: BTreeMap<&'a str, usize>,
+        low_link: BTreeMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: BTreeSet<&'a str>,
+        components: Vec<Vec<String>>,
+    }
+
+    fn connect<'a>(
+        node: &'a str,
+        graph: &'a BTreeMap<&str, BTreeSet<String>>,
+        state: &mut State<'a>,
+    ) {
+        let index = state.index.len();
+        state.index.insert(node, index);
+        state.low_link.insert(node, index);
+        state.stack.push(node);
+        state.on_stack.insert(node);
+
+        for dependency in &graph[node] {
+            let dependency = dependency.as_str();
+            if !state.index.contains_key(dependency) {
+                connect(dependency, graph, state);
+                let low_link = state.low_link[node].min(state.low_link[dependency]);
+                state.low_link.insert(node, low_link);
+            } else if state.on_stack.contains(dependency) {
+                let low_link = state.low_link[node].min(state.index[dependency]);
+                state.low_link.insert(node, low_link);
+            }
+        }
+
+        if state.low_link[node] == state.index[node] {
+            let mut component = Vec::new();
+            while let Some(member) = state.stack.pop() {
+                state.on_stack.remove(member);
+                component.push(member.to_owned());
+                if member == node {
+                    break;
+                }
+            }
+            if component.len() > 1 || graph[node].contains(node) {
+                component.sort();
+                state.components.push(component);
+            }
+        }
+    }
+
+    let mut state = State::default();
+    for node in graph.keys() {
+        if !state.index.contains_key(node) {
+            connect(node, graph, &mut state);
+        }
+    }
+    let mut components = state.components;
+    components.sort();
+    components
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::layout::{BorshType, Kind, LayoutField};
+    use std::str::FromStr;
+
+    fn layout(name: &str, fields: &[&str]) -> Layout {
+        Layout::new(
+            name,
+            Kind::Struct,
+            fields
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| {
+                    LayoutField::new(&format!("field{}", i), BorshType::from_str(ty).unwrap())
+                })
+                .collect(),
+        )
+    }
+
+    fn names(layouts: &[Layout]) -> Vec<&str> {
+        layouts.iter().map(|layout| layout.name.as_str()).collect()
+    }
+
+    #[test]
+    fn topological_order() {
+        let layouts = vec![
+            layout("Root", &["Vec<Leaf>", "Option<Branch>"]),
+            layout("Leaf", &["u8"]),
+            layout("Branch", &["[Leaf; 2]", "Zeta"]),
+            layout("Zeta", &["u64"]),
+            layout("Alpha", &["bool"]),
+        ];
+        let sorted = sort_layouts(layouts.clone());
+        assert_eq!(
+            names(&sorted),
+            vec!["Alpha", "Leaf", "Zeta", "Branch", "Root"]
+        );
+
+        let mut reversed = layouts;
+        reversed.reverse();
+        assert_eq!(names(&sort_layouts(reversed)), names(&sorted));
+    }
+
+    #[test]
+    fn recursive_types() {
+        let layouts = vec![
+            layout("Node", &["Vec<Node>"]),
+            layout("Ping", &["Option<Pong>"]),
+            layout("Pong", &["Option<Ping>", "Leaf"]),
+            layout("Leaf", &["u8"]),
+        ];
+        assert_eq!(
+            names(&sort_layouts(layouts.clone())),
+            vec!["Leaf", "Node", "Ping", "Pong"]
+        );
+        let warnings = schema_warnings(&layouts);
+        assert_eq!(
+            warnings,
+            vec![
+                SchemaWarning::RecursiveType {
+                    layouts: vec!["Node".to_owned()]
+                },
+                SchemaWarning::RecursiveType {
+                    layouts: vec!["Ping".to_owned(), "Pong".to_owned()]
+                },
+            ]
+        );
+        assert_eq!(
+            warnings[1].to_string(),
+            "`Ping`, `Pong` refer to each other recursively, which borsh-js can't handle"
+        );
+    }
+
+    #[test]
+    fn undefined_types() {
+        let layouts = vec![layout("Foo", &["u8", "Option<Vec<Bar>>"])];
+        assert_eq!(names(&sort_layouts(layouts.clone())), vec!["Foo"]);
+        let error = check_references(&layouts).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "type `Bar` in field `field1` of `Foo` is not defined"
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Errors that might occur while parsing the input files or generating the
+//! output.
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// Points to the data structure (and possibly one of its fields) an error
+/// originates from.
+///
+/// `line` and `column` are 1-based, a `line` of `0` means that the exact
+/// position is unknown.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SourceLocation {
+    pub file: PathBuf,
+    pub item: String,
+    pub field: Option<String>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SourceLocation {
+    /// Returns the location at the start of the provided span.
+    pub fn from_span(span: proc_macro2::Span) -> Self {
+        let start = span.start();
+        Self {
+            line: start.line,
+            column: start.column + 1,
+            ..Self::default()
+        }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if self.line > 0 {
+            write!(f, ":{}:{}", self.line, self.column)?;
+        }
+        Ok(())
+    }
+}
+
+/// Describes the item and field of a [`SourceLocation`], e.g. "field `foo`
+/// of `Bar`", for error messages.
+struct Context<'a>(&'a SourceLocation);
+
+impl fmt::Display for Context<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.0.field, self.0.item.is_empty()) {
+            (Some(field), false) => write!(f, " in field `{}` of `{}`", field, self.0.item),
+            (Some(field), true) => write!(f, " in field `{}`", field),
+            (None, false) => write!(f, " in `{}`", self.0.item),
+            (None, true) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SchemaError {
+    #[error("failed to access {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse file: {message}")]
+    Syntax {
+        location: Box<SourceLocation>,
+        message: String,
+    },
+    #[error("invalid type `{ty}`{}: {message}", Context(location))]
+    InvalidType {
+        location: Box<SourceLocation>,
+        ty: String,
+        message: String,
+    },
+    #[error("invalid attribute{}: {message}", Context(location))]
+    InvalidAttribute {
+        location: Box<SourceLocation>,
+        message: String,
+    },
+    #[error("invalid discriminant{}: {message}", Context(location))]
+    InvalidDiscriminant {
+        location: Box<SourceLocation>,
+        message: String,
+    },
+    #[error("type `{ty}`{} is not defined", Context(location))]
+    UndefinedType {
+        location: Box<SourceLocation>,
+        ty: String,
+    },
+    #[error("type `{ty}`{} can't be converted", Context(location))]
+    UnsupportedType {
+        location: Box<SourceLocation>,
+        ty: String,
+    },
+    #[error(
+        "`{name}` expects {expected} type arguments, found {found}{}",
+        Context(location)
+    )]
+    TypeArguments {
+        location: Box<SourceLocation>,
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    #[error(
+        "`{path}` is defined multiple times, in {} and {}",
+        first.display(),
+        second.display()
+    )]
+    DuplicateDefinition {
+        path: String,
+        first: PathBuf,
+        second: PathBuf,
+    },
+    #[error(
+        "`{first}` ({}) and `{second}` ({}) would both be generated as `{name}`",
+        first_file.display(),
+        second_file.display()
+    )]
+    NameCollision {
+        name: String,
+        first: String,
+        first_file: PathBuf,
+        second: String,
+        second_file: PathBuf,
+    },
+}
+
+impl SchemaError {
+    /// Returns the location the error originates from, if there is one.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            Self::Syntax { location, .. }
+            | Self::InvalidType { location, .. }
+            | Self::InvalidAttribute { location, .. }
+            | Self::InvalidDiscriminant { location, .. }
+            | Self::UndefinedType { location, .. }
+            | Self::UnsupportedType { location, .. }
+            | Self::TypeArguments { location, .. } => Some(location.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Updates the location of the error (if it has one) through `f`. It is
+    /// used to add context to errors while they are propagated, e.g. the
+    /// file the error occurred in.
+    pub fn with_location(mut self, f: impl FnOnce(&mut SourceLocation)) -> Self {
+        if let Some(location) = self.location_mut() {
+            f(location);
+        }
+        self
+    }
+
+    fn location_mut(&mut self) -> Option<&mut SourceLocation> {
+        match self {
+            Self::Syntax { location, .. }
+            | Self::InvalidType { location, .. }
+            | Self::InvalidAttribute { location, .. }
+            | Self::InvalidDiscriminant { location, .. }
+            | Self::UndefinedType { location, .. }
+            | Self::UnsupportedType { location, .. }
+            | Self::TypeArguments { location, .. } => Some(location.as_mut()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn invalid_type(ty: &str, message: impl ToString) -> Self {
+        Self::InvalidType {
+            location: Box::default(),
+            ty: ty.to_owned(),
+            message: message.to_string(),
+        }
+    }
+}
+
+/// An error that occurred while decoding borsh data with
+/// [`decode`](crate::decode). `path` points to the value being decoded, e.g.
+/// `Vault.history[2].Deposit`, and `offset` is the position of the first byte
+/// of the value in the input.
+#[derive(Debug, thiserror::Error)]
+#[error("failed to decode `{path}` at byte offset {offset}: {message}")]
+pub struct DecodeError {
+    pub path: String,
+    pub offset: usize,
+    pub message: String,
+}
+
+/// An error that occurred while encoding a JSON value with
+/// [`encode`](crate::encode). `path` points to the offending value like in
+/// [`DecodeError`].
+#[derive(Debug, thiserror::Error)]
+#[error("failed to encode `{path}`: {message}")]
+pub struct EncodeError {
+    pub path: String,
+    pub message: String,
+}
+
+/// Problems found in the layouts that don't prevent generating the output,
+/// but the generated code might not work as expected.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaWarning {
+    /// The layouts refer to each other (or a single layout refers to itself)
+    /// recursively, which `borsh-js` can't handle.
+    RecursiveType { layouts: Vec<String> },
+}
+
+impl fmt::Display for SchemaWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RecursiveType { layouts } => {
+                let names = layouts
+                    .iter()
+                    .map(|name| format!("`{}`", name))
+                    .collect::<Vec<_>>();
+                if let [name] = names.as_slice() {
+                    write!(f, "{} refers to itself recursively", name)?;
+                } else {
+                    write!(f, "{} refer to each other recursively", names.join(", "))?;
+                }
+                write!(f, ", which borsh-js can't handle")
+            }
+        }
+    }
+}
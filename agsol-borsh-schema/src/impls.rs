@@ -0,0 +1,254 @@
+//! Implementations of [`BorshSchema`] for primitives, collections and other
+//! foreign types that can be held by the fields of derived types.
+
+use crate::BorshSchema;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+macro_rules! impl_primitive {
+    ($($ty:ty => $borsh_type:ident),* $(,)?) => {
+        $(
+            impl BorshSchema for $ty {
+                crate::__schema_methods! {
+                    fn schema_type() -> crate::BorshType {
+                        crate::BorshType::$borsh_type
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive! {
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    bool => Bool,
+    String => String,
+}
+
+#[cfg(feature = "solana-program")]
+impl_primitive! {
+    solana_program::pubkey::Pubkey => Pubkey,
+}
+
+/// Implements the trait for containers with a single type parameter, which
+/// are represented by `BorshType::$borsh_type`.
+macro_rules! impl_container {
+    ($($ty:ident => $borsh_type:ident),* $(,)?) => {
+        $(
+            impl<T: BorshSchema> BorshSchema for $ty<T> {
+                crate::__schema_methods! {
+                    fn schema_type() -> crate::BorshType {
+                        crate::BorshType::$borsh_type(Box::new(T::schema_type()))
+                    }
+
+                    fn add_layouts(layouts: &mut Vec<crate::Layout>, options: &crate::LayoutOptions) {
+                        T::add_layouts(layouts, options);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_container! {
+    Vec => Vec,
+    VecDeque => Vec,
+    Option => Option,
+    BTreeSet => Set,
+}
+
+impl<T: BorshSchema, S> BorshSchema for HashSet<T, S> {
+    crate::__schema_methods! {
+        fn schema_type() -> crate::BorshType {
+            crate::BorshType::Set(Box::new(T::schema_type()))
+        }
+
+        fn add_layouts(layouts: &mut Vec<crate::Layout>, options: &crate::LayoutOptions) {
+            T::add_layouts(layouts, options);
+        }
+    }
+}
+
+impl<K: BorshSchema, V: BorshSchema> BorshSchema for BTreeMap<K, V> {
+    crate::__schema_methods! {
+        fn schema_type() -> crate::BorshType {
+            crate::BorshType::Map(Box::new(K::schema_type()), Box::new(V::schema_type()))
+        }
+
+        fn add_layouts(layouts: &mut Vec<crate::Layout>, options: &crate::LayoutOptions) {
+            K::add_layouts(layouts, options);
+            V::add_layouts(layouts, options);
+        }
+    }
+}
+
+impl<K: BorshSchema, V: BorshSchema, S> BorshSchema for HashMap<K, V, S> {
+    crate::__schema_methods! {
+        fn schema_type() -> crate::BorshType {
+            crate::BorshType::Map(Box::new(K::schema_type()), Box::new(V::schema_type()))
+        }
+
+        fn add_layouts(layouts: &mut Vec<crate::Layout>, options: &crate::LayoutOptions) {
+            K::add_layouts(layouts, options);
+            V::add_layouts(layouts, options);
+        }
+    }
+}
+
+impl<T: BorshSchema> BorshSchema for Box<T> {
+    crate::__schema_methods! {
+        fn schema_type() -> crate::BorshType {
+            T::schema_type()
+        }
+
+        fn add_layouts(layouts: &mut Vec<crate::Layout>, options: &crate::LayoutOptions) {
+            T::add_layouts(layouts, options);
+        }
+    }
+}
+
+impl<T: BorshSchema, const N: usize> BorshSchema for [T; N] {
+    crate::__schema_methods! {
+        fn schema_type() -> crate::BorshType {
+            match T::schema_type() {
+                crate::BorshType::U8 => crate::BorshType::FixedBytes(N),
+                ty => crate::BorshType::FixedArray(Box::new(ty), N),
+            }
+        }
+
+        fn add_layouts(layouts: &mut Vec<crate::Layout>, options: &crate::LayoutOptions) {
+            T::add_layouts(layouts, options);
+        }
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($($param:ident),+)),* $(,)?) => {
+        $(
+            impl<$($param: BorshSchema),+> BorshSchema for ($($param,)+) {
+                crate::__schema_methods! {
+                    fn schema_type() -> crate::BorshType {
+                        crate::BorshType::Tuple(vec![$($param::schema_type()),+])
+                    }
+
+                    fn add_layouts(layouts: &mut Vec<crate::Layout>, options: &crate::LayoutOptions) {
+                        $($param::add_layouts(layouts, options);)+
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple! {
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+}
+
+/// Implementations for the bounded containers of `agsol-common`, which are
+/// represented like the parser represents them.
+#[cfg(feature = "derived-layouts")]
+mod bounded {
+    use crate::layout::LOCKED_ITEM;
+    use crate::{BorshSchema, BorshType, Kind, Layout, LayoutField, LayoutOptions};
+    use agsol_common::{
+        LockedItem, MaxLenBTreeMap, MaxLenString, MaxLenVec, MaxSerializedLen, Timelock,
+    };
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use std::hash::Hash;
+
+    impl<const N: usize> BorshSchema for MaxLenString<N> {
+        fn schema_type() -> BorshType {
+            BorshType::Bounded(Box::new(BorshType::String), Some(N))
+        }
+    }
+
+    impl<T: BorshSchema, const N: usize> BorshSchema for MaxLenVec<T, N> {
+        fn schema_type() -> BorshType {
+            BorshType::Bounded(
+                Box::new(BorshType::Vec(Box::new(T::schema_type()))),
+                Some(N),
+            )
+        }
+
+        fn add_layouts(layouts: &mut Vec<Layout>, options: &LayoutOptions) {
+            T::add_layouts(layouts, options);
+        }
+    }
+
+    impl<K, V, const N: usize> BorshSchema for MaxLenBTreeMap<K, V, N>
+    where
+        K: BorshSchema + MaxSerializedLen + Clone + Ord + Hash,
+        V: BorshSchema + MaxSerializedLen + Clone,
+    {
+        fn schema_type() -> BorshType {
+            let map = BorshType::Map(Box::new(K::schema_type()), Box::new(V::schema_type()));
+            BorshType::Bounded(Box::new(map), Some(N))
+        }
+
+        fn add_layouts(layouts: &mut Vec<Layout>, options: &LayoutOptions) {
+            K::add_layouts(layouts, options);
+            V::add_layouts(layouts, options);
+        }
+    }
+
+    impl<T> BorshSchema for LockedItem<T>
+    where
+        T: BorshSchema + BorshSerialize + BorshDeserialize + MaxSerializedLen,
+    {
+        fn schema_type() -> BorshType {
+            BorshType::Generic(LOCKED_ITEM.to_owned(), vec![T::schema_type()])
+        }
+
+        fn add_layouts(layouts: &mut Vec<Layout>, options: &LayoutOptions) {
+            let name = Self::schema_type().to_ident();
+            if layouts
+                .iter()
+                .any(|layout| layout.is_derived(&name, "agsol_common"))
+            {
+                return;
+            }
+            let fields = vec![
+                LayoutField::derived(Some("item"), None, 0, T::schema_type(), &[], options),
+                LayoutField::derived(Some("expires"), None, 1, BorshType::I64, &[], options),
+            ];
+            // like the parsed `LockedItem`, it belongs to the root module
+            layouts.push(Layout::derived(
+                name,
+                Kind::Struct,
+                fields,
+                &[],
+                "agsol_common",
+                "",
+            ));
+            T::add_layouts(layouts, options);
+        }
+    }
+
+    impl<T, const N: usize> BorshSchema for Timelock<T, N>
+    where
+        T: BorshSchema + BorshSerialize + BorshDeserialize + MaxSerializedLen,
+    {
+        fn schema_type() -> BorshType {
+            let item = LockedItem::<T>::schema_type();
+            BorshType::Bounded(Box::new(BorshType::Vec(Box::new(item))), Some(N))
+        }
+
+        fn add_layouts(layouts: &mut Vec<Layout>, options: &LayoutOptions) {
+            LockedItem::<T>::add_layouts(layouts, options);
+        }
+    }
+}
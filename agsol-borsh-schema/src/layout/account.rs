@@ -0,0 +1,181 @@
+use crate::error::{SchemaError, SourceLocation};
+use heck::MixedCase;
+use serde::{Deserialize, Serialize};
+use syn::parse::ParseStream;
+use syn::spanned::Spanned;
+
+/// An account expected by an instruction, declared by the
+/// `#[schema_accounts(...)]` attribute of an instruction enum variant, e.g.
+/// `signer writable payer`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InstructionAccount {
+    pub name: String,
+    #[serde(default)]
+    pub signer: bool,
+    #[serde(default)]
+    pub writable: bool,
+}
+
+impl InstructionAccount {
+    /// Creates an account from the code generated by
+    /// `#[derive(BorshSchema)]`.
+    #[doc(hidden)]
+    pub fn derived(name: &str, signer: bool, writable: bool) -> Self {
+        Self {
+            name: name.to_mixed_case(),
+            signer,
+            writable,
+        }
+    }
+}
+
+/// Parses the accounts of the `#[schema_accounts(...)]` attribute among the
+/// attributes of an enum variant. Every account is a list of flags
+/// (`signer`, `writable` or `readonly`) followed by the name of the account,
+/// while the accounts are separated by commas:
+///
+/// ```text
+/// #[schema_accounts(signer writable payer, writable pda, readonly system_program)]
+/// ```
+pub(crate) fn instruction_accounts(
+    attrs: &[syn::Attribute],
+) -> Result<Vec<InstructionAccount>, SchemaError> {
+    let attr = match attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("schema_accounts"))
+    {
+        Some(attr) => attr,
+        None => return Ok(Vec::new()),
+    };
+    let invalid = |span: proc_macro2::Span, message: String| SchemaError::InvalidAttribute {
+        location: Box::new(SourceLocation::from_span(span)),
+        message,
+    };
+    let entries = attr
+        .parse_args_with(parse_entries)
+        .map_err(|err| invalid(err.span(), err.to_string()))?;
+
+    let mut accounts = Vec::<InstructionAccount>::with_capacity(entries.len());
+    for entry in entries {
+        let (name, flags) = entry
+            .split_last()
+            .ok_or_else(|| invalid(attr.span(), "expected an account name".to_owned()))?;
+        let mut account = InstructionAccount {
+            name: name.to_string().to_mixed_case(),
+            signer: false,
+            writable: false,
+        };
+        let mut readonly = false;
+        for flag in flags {
+            match flag.to_string().as_str() {
+                "signer" => account.signer = true,
+                "writable" => account.writable = true,
+                "readonly" => readonly = true,
+                _ => {
+                    let message = format!(
+                        "unknown account flag `{}`, expected `signer`, `writable` or `readonly`",
+                        flag
+                    );
+                    return Err(invalid(flag.span(), message));
+                }
+            }
+        }
+        if readonly && account.writable {
+            return Err(invalid(
+                name.span(),
+                format!("account `{}` can't be both `readonly` and `writable`", name),
+            ));
+        }
+        if accounts.iter().any(|other| other.name == account.name) {
+            return Err(invalid(
+                name.span(),
+                format!("account `{}` is declared multiple times", name),
+            ));
+        }
+        accounts.push(account);
+    }
+    Ok(accounts)
+}
+
+/// Parses comma separated lists of identifiers.
+fn parse_entries(input: ParseStream) -> syn::Result<Vec<Vec<syn::Ident>>> {
+    let mut entries = Vec::new();
+    while !input.is_empty() {
+        let mut idents = Vec::new();
+        while !input.is_empty() && !input.peek(syn::Token![,]) {
+            idents.push(input.parse::<syn::Ident>()?);
+        }
+        entries.push(idents);
+        if !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+        }
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn accounts(attr: &str) -> Result<Vec<InstructionAccount>, SchemaError> {
+        let item = syn::parse_str::<syn::ItemEnum>(&format!("enum Foo {{ {} A }}", attr)).unwrap();
+        instruction_accounts(&item.variants[0].attrs)
+    }
+
+    #[test]
+    fn parse_accounts() {
+        assert!(accounts("").unwrap().is_empty());
+        assert_eq!(
+            accounts(
+                "#[schema_accounts(signer writable payer, writable pda, readonly system_program)]"
+            )
+            .unwrap(),
+            vec![
+                InstructionAccount {
+                    name: "payer".to_owned(),
+                    signer: true,
+                    writable: true,
+                },
+                InstructionAccount {
+                    name: "pda".to_owned(),
+                    signer: false,
+                    writable: true,
+                },
+                InstructionAccount {
+                    name: "systemProgram".to_owned(),
+                    signer: false,
+                    writable: false,
+                },
+            ]
+        );
+        assert_eq!(
+            accounts("#[schema_accounts(owner,)]").unwrap(),
+            vec![InstructionAccount {
+                name: "owner".to_owned(),
+                signer: false,
+                writable: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_accounts() {
+        let message = |attr: &str| accounts(attr).unwrap_err().to_string();
+        assert_eq!(
+            message("#[schema_accounts(mutable payer)]"),
+            "invalid attribute: unknown account flag `mutable`, expected `signer`, `writable` or `readonly`"
+        );
+        assert_eq!(
+            message("#[schema_accounts(readonly writable payer)]"),
+            "invalid attribute: account `payer` can't be both `readonly` and `writable`"
+        );
+        assert_eq!(
+            message("#[schema_accounts(payer, signer payer)]"),
+            "invalid attribute: account `payer` is declared multiple times"
+        );
+        assert_eq!(
+            message("#[schema_accounts(payer,, pda)]"),
+            "invalid attribute: expected an account name"
+        );
+    }
+}
@@ -1,14 +1,31 @@
+use super::Layout;
+use crate::error::SchemaError;
+use heck::ShoutySnakeCase;
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
 use std::str::FromStr;
 
 /// Types that can be represented in a TypsScript borsh schema
 /// for (de)serialization.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// In the JSON IDL, types without arguments are represented by their
+/// camelCase name (e.g. `"u64"`), while the rest are objects with a single
+/// key holding the arguments, e.g. `{ "vec": "pubkey" }` or
+/// `{ "fixedArray": ["u16", 3] }`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum BorshType {
     U8,
     U16,
     U32,
     U64,
     U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
     Bool,
     String,
     Pubkey,
@@ -17,21 +34,39 @@ pub enum BorshType {
     FixedBytes(usize),
     Option(Box<BorshType>),
     Map(Box<BorshType>, Box<BorshType>),
+    /// `HashSet` and `BTreeSet` types. Their elements are serialized like a
+    /// `Vec`, in the order of the set.
+    Set(Box<BorshType>),
+    /// Tuples are converted into struct layouts named after their elements,
+    /// because `borsh-js` has no notion of tuples.
+    Tuple(Vec<BorshType>),
+    /// Containers from `agsol-common` with a maximum length, e.g.
+    /// `MaxLenString<N>`. They are serialized as the underlying container,
+    /// while the bound is kept for validation purposes. The bound is `None`
+    /// if it isn't an integer literal.
+    Bounded(Box<BorshType>, Option<usize>),
     Custom(String),
+    Generic(String, Vec<BorshType>),
     Skip,
 }
 
 impl FromStr for BorshType {
-    type Err = anyhow::Error;
+    type Err = SchemaError;
     fn from_str(input_str: &str) -> Result<Self, Self::Err> {
         let mut input = input_str.to_owned();
         input.retain(|c| !c.is_whitespace());
-        match input.as_ref() {
-            "u8" | "i8" => Ok(BorshType::U8),
-            "u16" | "i16" => Ok(BorshType::U16),
-            "u32" | "i32" => Ok(BorshType::U32),
-            "u64" | "i64" | "UnixTimestamp" => Ok(BorshType::U64),
-            "u128" | "i128" => Ok(BorshType::U128),
+        let input = strip_external_path(&input);
+        match input {
+            "u8" => Ok(BorshType::U8),
+            "u16" => Ok(BorshType::U16),
+            "u32" => Ok(BorshType::U32),
+            "u64" => Ok(BorshType::U64),
+            "u128" => Ok(BorshType::U128),
+            "i8" => Ok(BorshType::I8),
+            "i16" => Ok(BorshType::I16),
+            "i32" => Ok(BorshType::I32),
+            "i64" | "UnixTimestamp" => Ok(BorshType::I64),
+            "i128" => Ok(BorshType::I128),
             "bool" => Ok(BorshType::Bool),
             "String" => Ok(BorshType::String),
             "Pubkey" => Ok(BorshType::Pubkey),
@@ -39,45 +74,151 @@ impl FromStr for BorshType {
                 if let Some(inner) = input.strip_prefix("Option<") {
                     let inner = inner
                         .strip_suffix('>')
-                        .ok_or_else(|| anyhow::anyhow!("invalid Option"))?;
+                        .ok_or_else(|| SchemaError::invalid_type(input, "invalid Option"))?;
                     let inner_type = BorshType::from_str(inner)?;
                     Ok(BorshType::Option(Box::new(inner_type)))
                 } else if let Some(inner) = input.strip_prefix("Vec<") {
                     let inner = inner
                         .strip_suffix('>')
-                        .ok_or_else(|| anyhow::anyhow!("invalid Vec"))?;
+                        .ok_or_else(|| SchemaError::invalid_type(input, "invalid Vec"))?;
                     let inner_type = BorshType::from_str(inner)?;
                     Ok(BorshType::Vec(Box::new(inner_type)))
                 } else if let Some(inner) = input.strip_prefix("VecDeque<") {
                     let inner = inner
                         .strip_suffix('>')
-                        .ok_or_else(|| anyhow::anyhow!("invalid VecDeque"))?;
+                        .ok_or_else(|| SchemaError::invalid_type(input, "invalid VecDeque"))?;
                     let inner_type = BorshType::from_str(inner)?;
                     Ok(BorshType::Vec(Box::new(inner_type)))
                 } else if let Some(inner) = input.strip_prefix('[') {
-                    let inner = inner
-                        .strip_suffix(']')
-                        .ok_or_else(|| anyhow::anyhow!("invalid array, missing ']'"))?;
-                    let (array_type_str, array_len_str) = inner
-                        .rsplit_once(';')
-                        .ok_or_else(|| anyhow::anyhow!("invalid array, missing ';'"))?;
+                    let inner = inner.strip_suffix(']').ok_or_else(|| {
+                        SchemaError::invalid_type(input, "invalid array, missing ']'")
+                    })?;
+                    let (array_type_str, array_len_str) =
+                        inner.rsplit_once(';').ok_or_else(|| {
+                            SchemaError::invalid_type(input, "invalid array, missing ';'")
+                        })?;
                     let array_type = BorshType::from_str(array_type_str)?;
-                    let array_len = array_len_str.parse::<usize>()?;
+                    let array_len = array_len_str
+                        .parse::<usize>()
+                        .map_err(|err| SchemaError::invalid_type(input, err))?;
                     if let BorshType::U8 = array_type {
                         Ok(BorshType::FixedBytes(array_len))
                     } else {
                         Ok(BorshType::FixedArray(Box::new(array_type), array_len))
                     }
-                } else if let Some(inner) = input.strip_prefix("BTreeMap<") {
+                } else if let Some(inner) = input
+                    .strip_prefix("BTreeMap<")
+                    .or_else(|| input.strip_prefix("HashMap<"))
+                {
+                    let inner = inner
+                        .strip_suffix('>')
+                        .ok_or_else(|| SchemaError::invalid_type(input, "invalid map"))?;
+                    match split_type_args(inner).as_slice() {
+                        [key_str, value_str] => {
+                            let key = BorshType::from_str(key_str)?;
+                            let value = BorshType::from_str(value_str)?;
+                            Ok(BorshType::Map(Box::new(key), Box::new(value)))
+                        }
+                        _ => Err(SchemaError::invalid_type(
+                            input,
+                            "invalid map, expected 2 type arguments",
+                        )),
+                    }
+                } else if let Some(inner) = input
+                    .strip_prefix("BTreeSet<")
+                    .or_else(|| input.strip_prefix("HashSet<"))
+                {
+                    let inner = inner
+                        .strip_suffix('>')
+                        .ok_or_else(|| SchemaError::invalid_type(input, "invalid set"))?;
+                    let inner_type = BorshType::from_str(inner)?;
+                    Ok(BorshType::Set(Box::new(inner_type)))
+                } else if let Some(inner) = input.strip_prefix("MaxLenString<") {
+                    let bound = inner
+                        .strip_suffix('>')
+                        .ok_or_else(|| SchemaError::invalid_type(input, "invalid MaxLenString"))?;
+                    Ok(BorshType::Bounded(
+                        Box::new(BorshType::String),
+                        parse_bound(bound),
+                    ))
+                } else if let Some(inner) = input.strip_prefix("MaxLenVec<") {
                     let inner = inner
                         .strip_suffix('>')
-                        .ok_or_else(|| anyhow::anyhow!("invalid BTreeMap"))?;
-                    let (key_str, value_str) = inner
-                        .split_once(',')
-                        .ok_or_else(|| anyhow::anyhow!("invalid BTreeMap, missing ','"))?;
-                    let key = BorshType::from_str(key_str)?;
-                    let value = BorshType::from_str(value_str)?;
-                    Ok(BorshType::Map(Box::new(key), Box::new(value)))
+                        .ok_or_else(|| SchemaError::invalid_type(input, "invalid MaxLenVec"))?;
+                    match split_type_args(inner).as_slice() {
+                        [inner_str, bound] => {
+                            let inner_type = BorshType::from_str(inner_str)?;
+                            Ok(BorshType::Bounded(
+                                Box::new(BorshType::Vec(Box::new(inner_type))),
+                                parse_bound(bound),
+                            ))
+                        }
+                        _ => Err(SchemaError::invalid_type(
+                            input,
+                            "invalid MaxLenVec, expected 2 generic arguments",
+                        )),
+                    }
+                } else if let Some(inner) = input.strip_prefix("MaxLenBTreeMap<") {
+                    let inner = inner.strip_suffix('>').ok_or_else(|| {
+                        SchemaError::invalid_type(input, "invalid MaxLenBTreeMap")
+                    })?;
+                    match split_type_args(inner).as_slice() {
+                        [key_str, value_str, bound] => {
+                            let key = BorshType::from_str(key_str)?;
+                            let value = BorshType::from_str(value_str)?;
+                            Ok(BorshType::Bounded(
+                                Box::new(BorshType::Map(Box::new(key), Box::new(value))),
+                                parse_bound(bound),
+                            ))
+                        }
+                        _ => Err(SchemaError::invalid_type(
+                            input,
+                            "invalid MaxLenBTreeMap, expected 3 generic arguments",
+                        )),
+                    }
+                } else if let Some(inner) = input.strip_prefix("Timelock<") {
+                    let inner = inner
+                        .strip_suffix('>')
+                        .ok_or_else(|| SchemaError::invalid_type(input, "invalid Timelock"))?;
+                    match split_type_args(inner).as_slice() {
+                        [inner_str, bound] => {
+                            let item = BorshType::from_str(inner_str)?;
+                            let locked_item =
+                                BorshType::Generic(LOCKED_ITEM.to_owned(), vec![item]);
+                            Ok(BorshType::Bounded(
+                                Box::new(BorshType::Vec(Box::new(locked_item))),
+                                parse_bound(bound),
+                            ))
+                        }
+                        _ => Err(SchemaError::invalid_type(
+                            input,
+                            "invalid Timelock, expected 2 generic arguments",
+                        )),
+                    }
+                } else if let Some(inner) = input.strip_prefix("Box<") {
+                    let inner = inner
+                        .strip_suffix('>')
+                        .ok_or_else(|| SchemaError::invalid_type(input, "invalid Box"))?;
+                    BorshType::from_str(inner)
+                } else if let Some(inner) = input.strip_prefix('(') {
+                    let inner = inner.strip_suffix(')').ok_or_else(|| {
+                        SchemaError::invalid_type(input, "invalid tuple, missing ')'")
+                    })?;
+                    let elements = split_type_args(inner)
+                        .into_iter()
+                        .filter(|element| !element.is_empty())
+                        .map(BorshType::from_str)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(BorshType::Tuple(elements))
+                } else if let Some((name, args)) = input
+                    .strip_suffix('>')
+                    .and_then(|inner| inner.split_once('<'))
+                {
+                    let args = split_type_args(args)
+                        .into_iter()
+                        .map(BorshType::from_str)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(BorshType::Generic(name.to_owned(), args))
                 } else {
                     Ok(BorshType::Custom(input.to_owned()))
                 }
@@ -86,9 +227,122 @@ impl FromStr for BorshType {
     }
 }
 
+/// Formats the type like it would be written in Rust, e.g. `Vec<[u8; 32]>`.
+/// Bounded containers are formatted as the respective `agsol-common` types,
+/// with `_` in place of unknown bounds.
+impl fmt::Display for BorshType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |types: &[BorshType]| {
+            types
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Self::U8 => write!(f, "u8"),
+            Self::U16 => write!(f, "u16"),
+            Self::U32 => write!(f, "u32"),
+            Self::U64 => write!(f, "u64"),
+            Self::U128 => write!(f, "u128"),
+            Self::I8 => write!(f, "i8"),
+            Self::I16 => write!(f, "i16"),
+            Self::I32 => write!(f, "i32"),
+            Self::I64 => write!(f, "i64"),
+            Self::I128 => write!(f, "i128"),
+            Self::Bool => write!(f, "bool"),
+            Self::String => write!(f, "String"),
+            Self::Pubkey => write!(f, "Pubkey"),
+            Self::Vec(inner) => write!(f, "Vec<{}>", inner),
+            Self::FixedArray(inner, len) => write!(f, "[{}; {}]", inner, len),
+            Self::FixedBytes(len) => write!(f, "[u8; {}]", len),
+            Self::Option(inner) => write!(f, "Option<{}>", inner),
+            Self::Map(key, value) => write!(f, "BTreeMap<{}, {}>", key, value),
+            Self::Set(inner) => write!(f, "BTreeSet<{}>", inner),
+            Self::Tuple(elements) if elements.len() == 1 => write!(f, "({},)", elements[0]),
+            Self::Tuple(elements) => write!(f, "({})", join(elements)),
+            Self::Bounded(inner, bound) => {
+                let bound = bound.map_or_else(|| "_".to_owned(), |bound| bound.to_string());
+                match inner.as_ref() {
+                    Self::String => write!(f, "MaxLenString<{}>", bound),
+                    Self::Vec(inner) => write!(f, "MaxLenVec<{}, {}>", inner, bound),
+                    Self::Map(key, value) => {
+                        write!(f, "MaxLenBTreeMap<{}, {}, {}>", key, value, bound)
+                    }
+                    inner => write!(f, "{}", inner),
+                }
+            }
+            Self::Custom(name) => write!(f, "{}", name),
+            Self::Generic(name, args) => write!(f, "{}<{}>", name, join(args)),
+            Self::Skip => write!(f, "_"),
+        }
+    }
+}
+
+/// Crates whose types are recognized by their name only, e.g.
+/// `solana_program::pubkey::Pubkey` is treated as `Pubkey`.
+const EXTERNAL_CRATES: &[&str] = &[
+    "std",
+    "core",
+    "alloc",
+    "solana_program",
+    "solana_sdk",
+    "agsol_common",
+];
+
+/// Name of the `LockedItem<T>` struct from `agsol-common`, which is provided
+/// as a built-in generic layout.
+pub(crate) const LOCKED_ITEM: &str = "LockedItem";
+
+/// Parses the length bound of a bounded container, e.g. `32` or `1_000usize`.
+fn parse_bound(input: &str) -> Option<usize> {
+    input
+        .trim_end_matches("usize")
+        .replace('_', "")
+        .parse::<usize>()
+        .ok()
+}
+
+fn strip_external_path(input: &str) -> &str {
+    let path = input.split('<').next().unwrap_or_default();
+    let crate_name = path
+        .trim_start_matches("::")
+        .split("::")
+        .next()
+        .unwrap_or_default();
+    match path.rfind("::") {
+        Some(i) if EXTERNAL_CRATES.contains(&crate_name) => &input[i + 2..],
+        _ => input,
+    }
+}
+
+/// Splits a comma separated list of type arguments while ignoring commas
+/// that are nested inside other generic types, arrays or tuples.
+fn split_type_args(input: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '<' | '[' | '(' => depth += 1,
+            '>' | ']' | ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                args.push(&input[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(&input[start..]);
+    args
+}
+
 impl BorshType {
     /// Converts the type to a schema representation used by
     /// [`borsh-js`](https://github.com/near/borsh-js).
+    ///
+    /// Skipped types have no representation, fields holding them are left out
+    /// of the generated code altogether.
     pub fn to_borsh_schema(&self) -> String {
         match self {
             Self::U8 | Self::Bool => "'u8'".to_owned(),
@@ -96,6 +350,11 @@ impl BorshType {
             Self::U32 => "'u32'".to_owned(),
             Self::U64 => "'u64'".to_owned(),
             Self::U128 => "'u128'".to_owned(),
+            Self::I8 => "'i8'".to_owned(),
+            Self::I16 => "'i16'".to_owned(),
+            Self::I32 => "'i32'".to_owned(),
+            Self::I64 => "'i64'".to_owned(),
+            Self::I128 => "'i128'".to_owned(),
             Self::String => "'string'".to_owned(),
             Self::Pubkey => "'publicKey'".to_owned(),
             Self::Vec(inner) => format!("[{}]", inner.to_borsh_schema()),
@@ -109,12 +368,20 @@ impl BorshType {
                 key.to_borsh_schema(),
                 value.to_borsh_schema()
             ),
+            Self::Set(inner) => format!("[{}]", inner.to_borsh_schema()),
+            Self::Bounded(inner, _) => inner.to_borsh_schema(),
             Self::Custom(inner) => inner.to_owned(),
-            _ => unimplemented!(),
+            Self::Generic(..) | Self::Tuple(_) => self.to_ident(),
+            Self::Skip => String::new(),
         }
     }
 
-    /// Converts the type to a TypeScript class type.
+    /// Converts the type to a TypeScript class type. Like in
+    /// [`BorshType::to_borsh_schema`], skipped types have no representation.
+    ///
+    /// Sets are `Set<T>`s and tuples are TypeScript tuples, which the
+    /// generated classes convert from the values deserialized by `borsh-js`
+    /// (see [`BorshType::to_ts_conversion`]).
     pub fn to_class_type(&self) -> String {
         match self {
             Self::U8 => "number".to_owned(),
@@ -122,6 +389,11 @@ impl BorshType {
             Self::U32 => "number".to_owned(),
             Self::U64 => "BN".to_owned(),
             Self::U128 => "BN".to_owned(),
+            Self::I8 => "number".to_owned(),
+            Self::I16 => "number".to_owned(),
+            Self::I32 => "number".to_owned(),
+            Self::I64 => "BN".to_owned(),
+            Self::I128 => "BN".to_owned(),
             Self::Bool => "boolean".to_owned(),
             Self::String => "string".to_owned(),
             Self::Pubkey => "PublicKey".to_owned(),
@@ -134,8 +406,285 @@ impl BorshType {
             Self::Map(key, value) => {
                 format!("Map<{}, {}>", key.to_class_type(), value.to_class_type())
             }
+            Self::Set(inner) => format!("Set<{}>", inner.to_class_type()),
+            Self::Bounded(inner, _) => inner.to_class_type(),
+            Self::Tuple(elements) => format!(
+                "[{}]",
+                elements
+                    .iter()
+                    .map(Self::to_class_type)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
             Self::Custom(inner) => inner.to_owned(),
-            _ => unimplemented!(),
+            Self::Generic(..) => self.to_ident(),
+            Self::Skip => String::new(),
+        }
+    }
+
+    /// Returns the TypeScript expression converting `value` into the class
+    /// type, or `None` if no conversion is needed. `borsh-js` deserializes
+    /// sets as arrays, so they are converted into `Set`s, while tuples are
+    /// passed to the constructor of their layout's class, which returns them
+    /// as TypeScript tuples. The conversion is applied to the already
+    /// converted values as well, e.g. when a class is constructed manually.
+    pub fn to_ts_conversion(&self, value: &str) -> Option<String> {
+        match self {
+            Self::Vec(inner) | Self::FixedArray(inner, _) => inner
+                .to_ts_conversion("item")
+                .map(|item| format!("{}.map((item) => {})", value, item)),
+            Self::Option(inner) => inner
+                .to_ts_conversion(value)
+                .map(|inner| format!("{0} == null ? {0} : {1}", value, inner)),
+            Self::Map(key, value_ty) => {
+                let key_conversion = key.to_ts_conversion("key");
+                let value_conversion = value_ty.to_ts_conversion("value");
+                if key_conversion.is_none() && value_conversion.is_none() {
+                    return None;
+                }
+                Some(format!(
+                    "new Map(Array.from({}, ([key, value]) => [{}, {}] as [{}, {}]))",
+                    value,
+                    key_conversion.unwrap_or_else(|| "key".to_owned()),
+                    value_conversion.unwrap_or_else(|| "value".to_owned()),
+                    key.to_class_type(),
+                    value_ty.to_class_type()
+                ))
+            }
+            Self::Set(inner) => Some(match inner.to_ts_conversion("item") {
+                Some(item) => format!("new Set(Array.from({}, (item) => {}))", value, item),
+                None => format!("new Set({})", value),
+            }),
+            Self::Bounded(inner, _) => inner.to_ts_conversion(value),
+            Self::Tuple(_) => Some(format!(
+                "new {}({}) as {}",
+                self.to_ident(),
+                value,
+                self.to_class_type()
+            )),
+            _ => None,
+        }
+    }
+
+    /// Converts the type to a Python type hint. Public keys and fixed size
+    /// byte arrays are represented by their raw `bytes`.
+    pub fn to_py_type(&self) -> String {
+        match self {
+            Self::U8
+            | Self::U16
+            | Self::U32
+            | Self::U64
+            | Self::U128
+            | Self::I8
+            | Self::I16
+            | Self::I32
+            | Self::I64
+            | Self::I128 => "int".to_owned(),
+            Self::Bool => "bool".to_owned(),
+            Self::String => "str".to_owned(),
+            Self::Pubkey | Self::FixedBytes(_) => "bytes".to_owned(),
+            Self::Vec(inner) | Self::FixedArray(inner, _) => {
+                format!("typing.List[{}]", inner.to_py_type())
+            }
+            Self::Option(inner) => format!("typing.Optional[{}]", inner.to_py_type()),
+            Self::Map(key, value) => {
+                format!("typing.Dict[{}, {}]", key.to_py_type(), value.to_py_type())
+            }
+            Self::Set(inner) => format!("typing.Set[{}]", inner.to_py_type()),
+            Self::Bounded(inner, _) => inner.to_py_type(),
+            Self::Custom(inner) => inner.to_owned(),
+            Self::Generic(..) | Self::Tuple(_) => self.to_ident(),
+            Self::Skip => String::new(),
+        }
+    }
+
+    /// Converts the type to a `borsh-construct` layout. Custom types refer to
+    /// the `{NAME}_LAYOUT` constant of their layout lazily, so that layouts
+    /// can be declared in any order.
+    pub fn to_py_construct(&self) -> String {
+        match self {
+            Self::U8 => "U8".to_owned(),
+            Self::U16 => "U16".to_owned(),
+            Self::U32 => "U32".to_owned(),
+            Self::U64 => "U64".to_owned(),
+            Self::U128 => "U128".to_owned(),
+            Self::I8 => "I8".to_owned(),
+            Self::I16 => "I16".to_owned(),
+            Self::I32 => "I32".to_owned(),
+            Self::I64 => "I64".to_owned(),
+            Self::I128 => "I128".to_owned(),
+            Self::Bool => "Bool".to_owned(),
+            Self::String => "String".to_owned(),
+            Self::Pubkey => "BorshPubkey".to_owned(),
+            Self::Vec(inner) => format!("Vec({})", inner.to_py_construct()),
+            Self::FixedArray(inner, len) => format!("Array({}, {})", len, inner.to_py_construct()),
+            Self::FixedBytes(len) => format!("FixedBytes({})", len),
+            Self::Option(inner) => format!("Option({})", inner.to_py_construct()),
+            Self::Map(key, value) => format!(
+                "HashMap({}, {})",
+                key.to_py_construct(),
+                value.to_py_construct()
+            ),
+            Self::Set(inner) => format!("HashSet({})", inner.to_py_construct()),
+            Self::Bounded(inner, _) => inner.to_py_construct(),
+            Self::Custom(_) | Self::Generic(..) | Self::Tuple(_) => format!(
+                "LazyBound(lambda: {}_LAYOUT)",
+                self.to_py_type().to_shouty_snake_case()
+            ),
+            Self::Skip => String::new(),
+        }
+    }
+
+    /// Converts the type to an identifier fragment. It is used to name the
+    /// concrete instances of generic layouts, e.g. `Wrapper<u64>` becomes
+    /// `WrapperU64`.
+    pub fn to_ident(&self) -> String {
+        match self {
+            Self::U8 => "U8".to_owned(),
+            Self::U16 => "U16".to_owned(),
+            Self::U32 => "U32".to_owned(),
+            Self::U64 => "U64".to_owned(),
+            Self::U128 => "U128".to_owned(),
+            Self::I8 => "I8".to_owned(),
+            Self::I16 => "I16".to_owned(),
+            Self::I32 => "I32".to_owned(),
+            Self::I64 => "I64".to_owned(),
+            Self::I128 => "I128".to_owned(),
+            Self::Bool => "Bool".to_owned(),
+            Self::String => "String".to_owned(),
+            Self::Pubkey => "Pubkey".to_owned(),
+            Self::Vec(inner) => format!("Vec{}", inner.to_ident()),
+            Self::FixedArray(inner, len) => format!("{}Array{}", inner.to_ident(), len),
+            Self::FixedBytes(len) => format!("Bytes{}", len),
+            Self::Option(inner) => format!("Option{}", inner.to_ident()),
+            Self::Map(key, value) => format!("Map{}{}", key.to_ident(), value.to_ident()),
+            Self::Set(inner) => format!("Set{}", inner.to_ident()),
+            Self::Bounded(inner, _) => inner.to_ident(),
+            Self::Tuple(elements) => {
+                String::from("Tuple") + &elements.iter().map(Self::to_ident).collect::<String>()
+            }
+            Self::Custom(inner) => inner.to_owned(),
+            Self::Generic(name, args) => {
+                name.to_owned() + &args.iter().map(Self::to_ident).collect::<String>()
+            }
+            Self::Skip => String::new(),
+        }
+    }
+
+    /// Replaces generic type parameters with the respective type arguments.
+    pub fn substitute(&self, params: &[String], args: &[BorshType]) -> Self {
+        self.map(&|ty| match ty {
+            Self::Custom(name) => params
+                .iter()
+                .position(|param| param == name)
+                .and_then(|i| args.get(i).cloned()),
+            _ => None,
+        })
+    }
+
+    /// Rebuilds the type by calling `f` on this type and every type nested
+    /// inside it, starting from the outermost one. Whenever `f` returns a new
+    /// type, it replaces the original one without visiting its inner types.
+    pub fn map(&self, f: &dyn Fn(&BorshType) -> Option<BorshType>) -> Self {
+        if let Some(ty) = f(self) {
+            return ty;
+        }
+        match self {
+            Self::Vec(inner) => Self::Vec(Box::new(inner.map(f))),
+            Self::FixedArray(inner, len) => Self::FixedArray(Box::new(inner.map(f)), *len),
+            Self::Option(inner) => Self::Option(Box::new(inner.map(f))),
+            Self::Map(key, value) => Self::Map(Box::new(key.map(f)), Box::new(value.map(f))),
+            Self::Set(inner) => Self::Set(Box::new(inner.map(f))),
+            Self::Bounded(inner, bound) => Self::Bounded(Box::new(inner.map(f)), *bound),
+            Self::Tuple(elements) => Self::Tuple(elements.iter().map(|ty| ty.map(f)).collect()),
+            Self::Generic(name, args) => {
+                Self::Generic(name.to_owned(), args.iter().map(|arg| arg.map(f)).collect())
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the maximum number of bytes the type is serialized into,
+    /// following the rules of `MaxSerializedLen` in `agsol-common`: bounded
+    /// containers take a 4 byte length prefix plus `bound` elements, options
+    /// and enums take an extra byte for their tag. The provided layouts are
+    /// used to look up custom types.
+    ///
+    /// Returns `None` if the size is unbounded, i.e. the type contains an
+    /// unbounded container or a recursive type, or it refers to a layout
+    /// that isn't provided.
+    pub fn max_serialized_len(&self, layouts: &[Layout]) -> Option<usize> {
+        self.max_len(layouts, &mut Vec::new())
+    }
+
+    /// Computes [`BorshType::max_serialized_len`] while keeping track of the
+    /// layouts being visited to detect recursion.
+    pub(crate) fn max_len(&self, layouts: &[Layout], visiting: &mut Vec<String>) -> Option<usize> {
+        match self {
+            Self::U8 | Self::I8 | Self::Bool => Some(1),
+            Self::U16 | Self::I16 => Some(2),
+            Self::U32 | Self::I32 => Some(4),
+            Self::U64 | Self::I64 => Some(8),
+            Self::U128 | Self::I128 => Some(16),
+            Self::Pubkey => Some(32),
+            Self::FixedBytes(len) => Some(*len),
+            Self::FixedArray(inner, len) => inner.max_len(layouts, visiting)?.checked_mul(*len),
+            Self::Option(inner) => inner.max_len(layouts, visiting)?.checked_add(1),
+            Self::Tuple(elements) => elements.iter().try_fold(0_usize, |len, element| {
+                len.checked_add(element.max_len(layouts, visiting)?)
+            }),
+            Self::Bounded(inner, Some(bound)) => {
+                let element_len = match inner.as_ref() {
+                    Self::String => 1,
+                    Self::Vec(element) | Self::Set(element) => {
+                        element.max_len(layouts, visiting)?
+                    }
+                    Self::Map(key, value) => key
+                        .max_len(layouts, visiting)?
+                        .checked_add(value.max_len(layouts, visiting)?)?,
+                    _ => return None,
+                };
+                element_len.checked_mul(*bound)?.checked_add(4)
+            }
+            Self::String | Self::Vec(_) | Self::Map(..) | Self::Set(_) | Self::Bounded(_, None) => {
+                None
+            }
+            Self::Custom(_) | Self::Generic(..) => {
+                let name = self.to_ident();
+                let layout = layouts.iter().find(|layout| layout.name == name)?;
+                layout.max_len(layouts, visiting)
+            }
+            Self::Skip => Some(0),
+        }
+    }
+
+    /// Returns the names of the layouts this type (or a type nested inside
+    /// it) refers to, i.e. custom types, generic instances and tuples.
+    pub fn referenced_layouts(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.visit(&mut |ty| {
+            if matches!(ty, Self::Custom(_) | Self::Generic(..) | Self::Tuple(_)) {
+                names.push(ty.to_ident());
+            }
+        });
+        names
+    }
+
+    /// Calls `f` on this type and on every type nested inside it.
+    pub fn visit(&self, f: &mut dyn FnMut(&BorshType)) {
+        f(self);
+        match self {
+            Self::Vec(inner)
+            | Self::FixedArray(inner, _)
+            | Self::Option(inner)
+            | Self::Set(inner)
+            | Self::Bounded(inner, _) => inner.visit(f),
+            Self::Map(key, value) => {
+                key.visit(f);
+                value.visit(f);
+            }
+            Self::Generic(_, args) | Self::Tuple(args) => args.iter().for_each(|arg| arg.visit(f)),
+            _ => {}
         }
     }
 }
@@ -148,15 +697,19 @@ mod test {
     fn simple_type_from_str() {
         assert_eq!(BorshType::from_str("bool").unwrap(), BorshType::Bool);
         assert_eq!(BorshType::from_str("u8").unwrap(), BorshType::U8);
-        assert_eq!(BorshType::from_str("i8").unwrap(), BorshType::U8);
+        assert_eq!(BorshType::from_str("i8").unwrap(), BorshType::I8);
         assert_eq!(BorshType::from_str("u16").unwrap(), BorshType::U16);
-        assert_eq!(BorshType::from_str("i16").unwrap(), BorshType::U16);
+        assert_eq!(BorshType::from_str("i16").unwrap(), BorshType::I16);
         assert_eq!(BorshType::from_str("u32").unwrap(), BorshType::U32);
-        assert_eq!(BorshType::from_str("i32").unwrap(), BorshType::U32);
+        assert_eq!(BorshType::from_str("i32").unwrap(), BorshType::I32);
         assert_eq!(BorshType::from_str("u64").unwrap(), BorshType::U64);
-        assert_eq!(BorshType::from_str("i64").unwrap(), BorshType::U64);
+        assert_eq!(BorshType::from_str("i64").unwrap(), BorshType::I64);
         assert_eq!(BorshType::from_str("u128").unwrap(), BorshType::U128);
-        assert_eq!(BorshType::from_str("i128").unwrap(), BorshType::U128);
+        assert_eq!(BorshType::from_str("i128").unwrap(), BorshType::I128);
+        assert_eq!(
+            BorshType::from_str("UnixTimestamp").unwrap(),
+            BorshType::I64
+        );
         assert_eq!(BorshType::from_str("String").unwrap(), BorshType::String);
         assert_eq!(BorshType::from_str("Pubkey").unwrap(), BorshType::Pubkey);
         assert_eq!(
@@ -189,7 +742,7 @@ mod test {
             BorshType::from_str("[[Option<i32>; 2]; 4]").unwrap(),
             BorshType::FixedArray(
                 Box::new(BorshType::FixedArray(
-                    Box::new(BorshType::Option(Box::new(BorshType::U32))),
+                    Box::new(BorshType::Option(Box::new(BorshType::I32))),
                     2
                 )),
                 4
@@ -209,6 +762,235 @@ mod test {
         );
     }
 
+    #[test]
+    fn path_type_from_str() {
+        assert_eq!(
+            BorshType::from_str("solana_program::pubkey::Pubkey").unwrap(),
+            BorshType::Pubkey
+        );
+        assert_eq!(
+            BorshType::from_str("std::collections::BTreeMap<u8, ::std::string::String>").unwrap(),
+            BorshType::Map(Box::new(BorshType::U8), Box::new(BorshType::String))
+        );
+        assert_eq!(
+            BorshType::from_str("crate::state::Foo").unwrap(),
+            BorshType::Custom("crate::state::Foo".to_owned())
+        );
+    }
+
+    #[test]
+    fn generic_type_from_str() {
+        assert_eq!(
+            BorshType::from_str("Wrapper<u64>").unwrap(),
+            BorshType::Generic("Wrapper".to_owned(), vec![BorshType::U64])
+        );
+        assert_eq!(
+            BorshType::from_str("Pair<Option<u8>, Wrapper<Pubkey>>").unwrap(),
+            BorshType::Generic(
+                "Pair".to_owned(),
+                vec![
+                    BorshType::Option(Box::new(BorshType::U8)),
+                    BorshType::Generic("Wrapper".to_owned(), vec![BorshType::Pubkey]),
+                ]
+            )
+        );
+        assert_eq!(
+            BorshType::from_str("BTreeMap<Pair<u8, u16>, String>").unwrap(),
+            BorshType::Map(
+                Box::new(BorshType::Generic(
+                    "Pair".to_owned(),
+                    vec![BorshType::U8, BorshType::U16]
+                )),
+                Box::new(BorshType::String)
+            )
+        );
+    }
+
+    #[test]
+    fn collection_type_from_str() {
+        assert_eq!(
+            BorshType::from_str("HashMap<String, u64>").unwrap(),
+            BorshType::Map(Box::new(BorshType::String), Box::new(BorshType::U64))
+        );
+        assert_eq!(
+            BorshType::from_str("std::collections::HashSet<Pubkey>").unwrap(),
+            BorshType::Set(Box::new(BorshType::Pubkey))
+        );
+        assert_eq!(
+            BorshType::from_str("BTreeSet<(u8, i16)>").unwrap(),
+            BorshType::Set(Box::new(BorshType::Tuple(vec![
+                BorshType::U8,
+                BorshType::I16
+            ])))
+        );
+        assert_eq!(
+            BorshType::from_str("Box<Option<Foo>>").unwrap(),
+            BorshType::Option(Box::new(BorshType::Custom("Foo".to_owned())))
+        );
+        assert_eq!(
+            BorshType::from_str("(u64, Vec<(Pubkey,)>)").unwrap(),
+            BorshType::Tuple(vec![
+                BorshType::U64,
+                BorshType::Vec(Box::new(BorshType::Tuple(vec![BorshType::Pubkey])))
+            ])
+        );
+        assert_eq!(BorshType::from_str("()").unwrap(), BorshType::Tuple(vec![]));
+    }
+
+    #[test]
+    fn display_types() {
+        for ty in [
+            "Vec<Option<[Pubkey; 2]>>",
+            "[u8; 32]",
+            "BTreeMap<u8, MaxLenString<32>>",
+            "MaxLenVec<Wrapper<u64>, _>",
+            "(u64, (Pubkey,))",
+        ] {
+            assert_eq!(BorshType::from_str(ty).unwrap().to_string(), ty);
+        }
+    }
+
+    #[test]
+    fn max_serialized_len() {
+        let len = |ty: &str| BorshType::from_str(ty).unwrap().max_serialized_len(&[]);
+        assert_eq!(len("(u8, i16, u32, i64, u128)"), Some(31));
+        assert_eq!(len("Option<[Pubkey; 2]>"), Some(65));
+        assert_eq!(len("MaxLenString<32>"), Some(36));
+        assert_eq!(len("MaxLenVec<Option<u64>, 10>"), Some(94));
+        assert_eq!(len("MaxLenBTreeMap<u8, [u8; 7], 4>"), Some(36));
+        assert_eq!(len("Timelock<u64, 3>"), None);
+        assert_eq!(len("MaxLenVec<u8, MAX>"), None);
+        assert_eq!(len("Vec<u8>"), None);
+        assert_eq!(len("String"), None);
+    }
+
+    #[test]
+    fn bounded_type_from_str() {
+        let ty = BorshType::from_str("MaxLenString<32>").unwrap();
+        assert_eq!(
+            ty,
+            BorshType::Bounded(Box::new(BorshType::String), Some(32))
+        );
+        assert_eq!(ty.to_borsh_schema(), "'string'");
+        assert_eq!(ty.to_class_type(), "string");
+
+        let ty = BorshType::from_str("agsol_common::MaxLenVec<Pubkey, 1_000>").unwrap();
+        assert_eq!(
+            ty,
+            BorshType::Bounded(
+                Box::new(BorshType::Vec(Box::new(BorshType::Pubkey))),
+                Some(1000)
+            )
+        );
+        assert_eq!(ty.to_borsh_schema(), "['publicKey']");
+
+        let ty = BorshType::from_str("MaxLenBTreeMap<u8, MaxLenString<8>, MAX_ENTRIES>").unwrap();
+        assert_eq!(
+            ty,
+            BorshType::Bounded(
+                Box::new(BorshType::Map(
+                    Box::new(BorshType::U8),
+                    Box::new(BorshType::Bounded(Box::new(BorshType::String), Some(8)))
+                )),
+                None
+            )
+        );
+        assert_eq!(
+            ty.to_borsh_schema(),
+            "{ kind: 'map', key: 'u8', value: 'string' }"
+        );
+
+        let ty = BorshType::from_str("Timelock<u64, 10>").unwrap();
+        assert_eq!(ty.to_borsh_schema(), "[LockedItemU64]");
+        assert_eq!(ty.to_class_type(), "LockedItemU64[]");
+        assert_eq!(
+            BorshType::from_str("LockedItem<u64>").unwrap(),
+            BorshType::Generic("LockedItem".to_owned(), vec![BorshType::U64])
+        );
+    }
+
+    #[test]
+    fn type_to_json() {
+        let ty = BorshType::from_str("Vec<Option<[Pubkey; 3]>>").unwrap();
+        let json = serde_json::to_string(&ty).unwrap();
+        assert_eq!(json, r#"{"vec":{"option":{"fixedArray":["pubkey",3]}}}"#);
+        assert_eq!(serde_json::from_str::<BorshType>(&json).unwrap(), ty);
+
+        let ty = BorshType::from_str("BTreeMap<u8, MaxLenString<32>>").unwrap();
+        let json = serde_json::to_string(&ty).unwrap();
+        assert_eq!(json, r#"{"map":["u8",{"bounded":["string",32]}]}"#);
+        assert_eq!(serde_json::from_str::<BorshType>(&json).unwrap(), ty);
+
+        let json = serde_json::to_string(&BorshType::Custom("Foo".to_owned())).unwrap();
+        assert_eq!(json, r#"{"custom":"Foo"}"#);
+    }
+
+    #[test]
+    fn collection_type_to_borsh() {
+        let ty = BorshType::from_str("HashSet<u32>").unwrap();
+        assert_eq!(ty.to_borsh_schema(), "['u32']");
+        assert_eq!(ty.to_class_type(), "Set<number>");
+        assert_eq!(ty.to_ts_conversion("x").unwrap(), "new Set(x)");
+        let ty = BorshType::from_str("HashMap<u8, Box<String>>").unwrap();
+        assert_eq!(
+            ty.to_borsh_schema(),
+            "{ kind: 'map', key: 'u8', value: 'string' }"
+        );
+        let ty = BorshType::from_str("Option<(u64, Pubkey)>").unwrap();
+        assert_eq!(
+            ty.to_borsh_schema(),
+            "{ kind: 'option', type: TupleU64Pubkey }"
+        );
+        assert_eq!(ty.to_class_type(), "[BN, PublicKey] | null");
+        assert_eq!(
+            ty.to_ts_conversion("x").unwrap(),
+            "x == null ? x : new TupleU64Pubkey(x) as [BN, PublicKey]"
+        );
+        let ty = BorshType::from_str("BTreeMap<u8, Vec<BTreeSet<u16>>>").unwrap();
+        assert_eq!(
+            ty.to_ts_conversion("x").unwrap(),
+            "new Map(Array.from(x, ([key, value]) => \
+            [key, value.map((item) => new Set(item))] as [number, Set<number>[]]))"
+        );
+        assert!(BorshType::from_str("Vec<Option<u8>>")
+            .unwrap()
+            .to_ts_conversion("x")
+            .is_none());
+    }
+
+    #[test]
+    fn types_to_py() {
+        let ty = BorshType::from_str("Option<HashMap<u8, MaxLenString<16>>>").unwrap();
+        assert_eq!(ty.to_py_type(), "typing.Optional[typing.Dict[int, str]]");
+        assert_eq!(ty.to_py_construct(), "Option(HashMap(U8, String))");
+        let ty = BorshType::from_str("[Pubkey; 3]").unwrap();
+        assert_eq!(ty.to_py_type(), "typing.List[bytes]");
+        assert_eq!(ty.to_py_construct(), "Array(3, BorshPubkey)");
+        let ty = BorshType::from_str("[u8; 32]").unwrap();
+        assert_eq!(ty.to_py_type(), "bytes");
+        assert_eq!(ty.to_py_construct(), "FixedBytes(32)");
+        let ty = BorshType::from_str("Vec<(u64, FooBar)>").unwrap();
+        assert_eq!(ty.to_py_type(), "typing.List[TupleU64FooBar]");
+        assert_eq!(
+            ty.to_py_construct(),
+            "Vec(LazyBound(lambda: TUPLE_U64_FOO_BAR_LAYOUT))"
+        );
+    }
+
+    #[test]
+    fn generic_type_substitution() {
+        let params = vec!["T".to_owned(), "U".to_owned()];
+        let args = vec![BorshType::U64, BorshType::Pubkey];
+        let ty = BorshType::from_str("Vec<Pair<T, Option<U>>>").unwrap();
+        let concrete = ty.substitute(&params, &args);
+        assert_eq!(
+            concrete,
+            BorshType::from_str("Vec<Pair<u64, Option<Pubkey>>>").unwrap()
+        );
+        assert_eq!(concrete.to_borsh_schema(), "[PairU64OptionPubkey]");
+        assert_eq!(concrete.to_class_type(), "PairU64OptionPubkey[]");
+    }
+
     #[test]
     fn simple_type_to_borsh() {
         assert_eq!(BorshType::Bool.to_borsh_schema(), "'u8'");
@@ -217,6 +999,11 @@ mod test {
         assert_eq!(BorshType::U32.to_borsh_schema(), "'u32'");
         assert_eq!(BorshType::U64.to_borsh_schema(), "'u64'");
         assert_eq!(BorshType::U128.to_borsh_schema(), "'u128'");
+        assert_eq!(BorshType::I8.to_borsh_schema(), "'i8'");
+        assert_eq!(BorshType::I16.to_borsh_schema(), "'i16'");
+        assert_eq!(BorshType::I32.to_borsh_schema(), "'i32'");
+        assert_eq!(BorshType::I64.to_borsh_schema(), "'i64'");
+        assert_eq!(BorshType::I128.to_borsh_schema(), "'i128'");
         assert_eq!(BorshType::String.to_borsh_schema(), "'string'");
         assert_eq!(BorshType::Pubkey.to_borsh_schema(), "'publicKey'");
     }
@@ -239,7 +1026,7 @@ mod test {
             BorshType::from_str("[[Option<i32>; 2]; 4]")
                 .unwrap()
                 .to_borsh_schema(),
-            "[[{ kind: 'option', type: 'u32' }, 2], 4]"
+            "[[{ kind: 'option', type: 'i32' }, 2], 4]"
         );
 
         assert_eq!(
@@ -254,6 +1041,10 @@ mod test {
     fn types_to_ts() {
         let ty = BorshType::from_str("u64").unwrap();
         assert_eq!(ty.to_class_type(), "BN");
+        let ty = BorshType::from_str("i32").unwrap();
+        assert_eq!(ty.to_class_type(), "number");
+        let ty = BorshType::from_str("i128").unwrap();
+        assert_eq!(ty.to_class_type(), "BN");
         let ty = BorshType::from_str("Option<Vec<Pubkey>>").unwrap();
         assert_eq!(ty.to_class_type(), "PublicKey[] | null");
         let ty = BorshType::from_str("[bool; 5]").unwrap();
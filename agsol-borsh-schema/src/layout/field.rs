@@ -1,53 +1,217 @@
-use super::BorshType;
-use heck::MixedCase;
+use super::{doc_lines, normalize_docs, BorshType, InstructionAccount, LayoutOptions};
+use crate::cfg::is_borsh_skipped;
+use crate::error::{SchemaError, SourceLocation};
+use heck::{MixedCase, SnakeCase};
 use proc_macro2::TokenStream;
 use quote::ToTokens;
+use serde::{Deserialize, Serialize};
+use syn::spanned::Spanned;
 
 use std::str::FromStr;
 
 /// Represents a field in a TypeScript class and a borsh schema.
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LayoutField {
-    name: String,
-    ty: BorshType,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: BorshType,
+    /// The name of the enum variant represented by the field if the field
+    /// belongs to an enum layout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    /// Lines of the doc comment of the field (or enum variant).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    /// The serialized discriminant of the enum variant represented by the
+    /// field, if the enum is serialized by its explicit discriminants
+    /// (`#[borsh(use_discriminant = true)]`) instead of the variant indices.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminant: Option<u8>,
+    /// The accounts expected by the instruction represented by the enum
+    /// variant, declared by the `#[schema_accounts(...)]` attribute.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<InstructionAccount>,
 }
 
 impl LayoutField {
     /// Converts a [`Field`](syn::Field) type into a layout field by extracting
-    /// its name and type.
-    pub fn from_tokens(field: &syn::Field, n: usize) -> Result<Self, anyhow::Error> {
-        let name = if let Some(field_name) = field.ident.as_ref() {
-            field_name.to_string().to_mixed_case()
+    /// its name and type. Named fields follow the naming convention of the
+    /// provided options, while unnamed fields are named after their position
+    /// `n`, unless they are renamed by `#[schema_rename = "..."]`.
+    pub fn from_tokens(
+        field: &syn::Field,
+        n: usize,
+        options: &LayoutOptions,
+    ) -> Result<Self, SchemaError> {
+        let rename = schema_rename(&field.attrs).map_err(|err| {
+            err.with_location(|location| {
+                location.field = field.ident.as_ref().map(ToString::to_string)
+            })
+        })?;
+        let name = if let Some(rename) = rename {
+            rename
+        } else if let Some(field_name) = field.ident.as_ref() {
+            options.field_naming.apply(&field_name.to_string())
         } else {
-            format!("unnamed_{}", n)
+            format!("{}{}", options.positional_prefix, n)
+        };
+        let located = |span: proc_macro2::Span| {
+            let name = name.clone();
+            move |err: SchemaError| {
+                err.with_location(|location| {
+                    *location = SourceLocation {
+                        field: Some(name),
+                        ..SourceLocation::from_span(span)
+                    }
+                })
+            }
         };
         let ty = if let Some(alias) = field.attrs.iter().find(|attr| attr.path.is_ident("alias")) {
-            BorshType::from_str(&alias.parse_args::<TokenStream>()?.to_string())?
+            let tokens = alias
+                .parse_args::<TokenStream>()
+                .map_err(|err| SchemaError::InvalidAttribute {
+                    location: Box::default(),
+                    message: err.to_string(),
+                })
+                .map_err(located(alias.span()))?;
+            BorshType::from_str(&tokens.to_string()).map_err(located(alias.span()))?
         } else if field
             .attrs
             .iter()
             .any(|attr| attr.path.is_ident("schema_skip"))
+            || is_borsh_skipped(&field.attrs)
         {
             BorshType::Skip
         } else {
-            BorshType::from_str(&field.ty.to_token_stream().to_string())?
+            BorshType::from_str(&field.ty.to_token_stream().to_string())
+                .map_err(located(field.ty.span()))?
         };
-        Ok(Self { name, ty })
+        Ok(Self {
+            name,
+            ty,
+            variant: None,
+            docs: doc_lines(&field.attrs),
+            discriminant: None,
+            accounts: Vec::new(),
+        })
     }
 
-    pub fn from_enum_variant(name_str: &str) -> Result<Self, anyhow::Error> {
-        let ty = BorshType::from_str(name_str)?;
+    /// Creates the field of an enum layout that holds the `variant` of the
+    /// enum, whose layout is named `name_str`.
+    pub fn from_enum_variant(
+        name_str: &str,
+        variant: &str,
+        generics: &[String],
+    ) -> Result<Self, SchemaError> {
+        let ty = if generics.is_empty() {
+            BorshType::from_str(name_str)?
+        } else {
+            BorshType::Generic(
+                name_str.to_owned(),
+                generics
+                    .iter()
+                    .map(|param| BorshType::Custom(param.to_owned()))
+                    .collect(),
+            )
+        };
         Ok(Self {
             name: name_str.to_mixed_case(),
             ty,
+            variant: Some(variant.to_owned()),
+            docs: Vec::new(),
+            discriminant: None,
+            accounts: Vec::new(),
         })
     }
 
+    /// Creates a field from the code generated by `#[derive(BorshSchema)]`.
+    /// Fields are named according to the `options`, unless they are renamed
+    /// by `#[schema_rename = "..."]`.
+    #[doc(hidden)]
+    pub fn derived(
+        ident: Option<&str>,
+        rename: Option<&str>,
+        n: usize,
+        ty: BorshType,
+        docs: &[&str],
+        options: &LayoutOptions,
+    ) -> Self {
+        let name = match (rename, ident) {
+            (Some(rename), _) => rename.to_owned(),
+            (None, Some(ident)) => options.field_naming.apply(ident),
+            (None, None) => format!("{}{}", options.positional_prefix, n),
+        };
+        Self {
+            name,
+            ty,
+            variant: None,
+            docs: normalize_docs(docs),
+            discriminant: None,
+            accounts: Vec::new(),
+        }
+    }
+
+    /// Creates the field of a derived enum layout that holds the `variant`
+    /// of the enum, whose (possibly generic) layout is named `name_str`.
+    #[doc(hidden)]
+    pub fn derived_variant(
+        name_str: &str,
+        ty: BorshType,
+        variant: &str,
+        docs: &[&str],
+        discriminant: Option<u8>,
+        accounts: Vec<InstructionAccount>,
+        options: &LayoutOptions,
+    ) -> Self {
+        Self {
+            name: options.field_naming.apply(name_str),
+            ty,
+            variant: Some(variant.to_owned()),
+            docs: normalize_docs(docs),
+            discriminant,
+            accounts,
+        }
+    }
+
+    /// Replaces generic type parameters in the field's type with the
+    /// respective type arguments.
+    pub fn substitute(&self, params: &[String], args: &[BorshType]) -> Self {
+        Self {
+            name: self.name.clone(),
+            ty: self.ty.substitute(params, args),
+            variant: self.variant.clone(),
+            docs: self.docs.clone(),
+            discriminant: self.discriminant,
+            accounts: self.accounts.clone(),
+        }
+    }
+
     /// Converts the field into a TypeScript class field representation.
     pub fn to_class_field(&self) -> String {
         format!("{}: {}", self.name, self.ty.to_class_type())
     }
 
+    /// Returns the snake_case name of the field in Python. Names that are
+    /// reserved keywords in Python are suffixed with an underscore.
+    pub fn py_name(&self) -> String {
+        let name = self.name.to_snake_case();
+        if PY_KEYWORDS.contains(&name.as_str()) {
+            name + "_"
+        } else {
+            name
+        }
+    }
+
+    /// Converts the field into a Python dataclass field representation.
+    pub fn to_py_field(&self) -> String {
+        format!("{}: {}", self.py_name(), self.ty.to_py_type())
+    }
+
+    /// Converts the field into a `borsh-construct` struct field.
+    pub fn to_py_construct(&self) -> String {
+        format!("\"{}\" / {}", self.py_name(), self.ty.to_py_construct())
+    }
+
     /// Converts the field into a borsh schema field representation.
     pub fn to_borsh_schema(&self) -> String {
         format!("['{}', {}]", self.name, self.ty.to_borsh_schema())
@@ -58,9 +222,48 @@ impl LayoutField {
     }
 }
 
+/// Python keywords that can't be used as field names.
+const PY_KEYWORDS: &[&str] = &[
+    "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif",
+    "else", "except", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda",
+    "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+/// Checks whether the name can be used as a field name in the generated code,
+/// i.e. it consists of alphanumeric characters and underscores and doesn't
+/// start with a digit.
+fn is_identifier(name: &str) -> bool {
+    matches!(name.chars().next(), Some(c) if !c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Parses the name provided by the `#[schema_rename = "..."]` attribute of a
+/// field, which overrides the naming convention of the field.
+pub(crate) fn schema_rename(attrs: &[syn::Attribute]) -> Result<Option<String>, SchemaError> {
+    let attr = match attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("schema_rename"))
+    {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+    let invalid = || SchemaError::InvalidAttribute {
+        location: Box::new(SourceLocation::from_span(attr.span())),
+        message: "expected `#[schema_rename = \"...\"]` with a valid identifier".to_owned(),
+    };
+    match attr.parse_meta() {
+        Ok(syn::Meta::NameValue(syn::MetaNameValue {
+            lit: syn::Lit::Str(name),
+            ..
+        })) if is_identifier(&name.value()) => Ok(Some(name.value())),
+        _ => Err(invalid()),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::layout::FieldNaming;
     use proc_macro2::{Span, TokenStream};
     use syn::token::Colon;
     use syn::{Ident, Type, Visibility};
@@ -77,7 +280,7 @@ mod test {
             ty: Type::Verbatim(TokenStream::from_str("u8").unwrap()),
         };
 
-        let field = LayoutField::from_tokens(&syn_field, 0).unwrap();
+        let field = LayoutField::from_tokens(&syn_field, 0, &LayoutOptions::default()).unwrap();
 
         assert_eq!(field.name, "randomField");
         assert_eq!(field.ty, BorshType::U8);
@@ -95,7 +298,7 @@ mod test {
             ty: syn::parse_str("[Option<Pubkey>; 3]").unwrap(),
         };
 
-        let field = LayoutField::from_tokens(&syn_field, 0).unwrap();
+        let field = LayoutField::from_tokens(&syn_field, 0, &LayoutOptions::default()).unwrap();
 
         assert_eq!(field.name, "optionalAccounts");
         assert_eq!(
@@ -105,38 +308,135 @@ mod test {
     }
 
     #[test]
-    fn simple_field_to_borsh_schema() {
-        let field = LayoutField {
-            name: "someRandomString".to_owned(),
-            ty: BorshType::String,
+    fn aliased_field_construction() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            struct Aliased {
+                amounts: Vec<Amount>,
+                #[alias(u32)]
+                amount: Amount,
+            }
         };
+        let mut syn_fields = item_struct.fields.iter();
+
+        let field =
+            LayoutField::from_tokens(syn_fields.next().unwrap(), 0, &LayoutOptions::default())
+                .unwrap();
+        assert_eq!(
+            field.ty,
+            BorshType::Vec(Box::new(BorshType::Custom("Amount".to_owned())))
+        );
+
+        let field =
+            LayoutField::from_tokens(syn_fields.next().unwrap(), 1, &LayoutOptions::default())
+                .unwrap();
+        assert_eq!(field.ty, BorshType::U32);
+    }
+
+    #[test]
+    fn simple_field_to_borsh_schema() {
+        let field = LayoutField::new("someRandomString", BorshType::String);
 
         assert_eq!(field.to_borsh_schema(), "['someRandomString', 'string']");
 
-        let field = LayoutField {
-            name: "myCustomType".to_owned(),
-            ty: BorshType::Custom("aCustomType".to_owned()),
-        };
+        let field = LayoutField::new("myCustomType", BorshType::Custom("aCustomType".to_owned()));
 
         assert_eq!(field.to_borsh_schema(), "['myCustomType', aCustomType]");
     }
 
     #[test]
     fn field_to_ts_class_field() {
-        let field = LayoutField {
-            name: "fieldAlpha".to_owned(),
-            ty: BorshType::U64,
-        };
+        let field = LayoutField::new("fieldAlpha", BorshType::U64);
         assert_eq!(field.to_class_field(), "fieldAlpha: BN");
-        let field = LayoutField {
-            name: "fieldBeta".to_owned(),
-            ty: BorshType::Vec(Box::new(BorshType::String)),
-        };
+        let field = LayoutField::new("fieldBeta", BorshType::Vec(Box::new(BorshType::String)));
         assert_eq!(field.to_class_field(), "fieldBeta: string[]");
-        let field = LayoutField {
-            name: "fieldGamma".to_owned(),
-            ty: BorshType::Option(Box::new(BorshType::FixedBytes(32))),
-        };
+        let field = LayoutField::new(
+            "fieldGamma",
+            BorshType::Option(Box::new(BorshType::FixedBytes(32))),
+        );
         assert_eq!(field.to_class_field(), "fieldGamma: [32] | null");
     }
+
+    #[test]
+    fn field_to_py_field() {
+        let field = LayoutField::new("fieldAlpha", BorshType::Option(Box::new(BorshType::U64)));
+        assert_eq!(field.to_py_field(), "field_alpha: typing.Optional[int]");
+        assert_eq!(field.to_py_construct(), "\"field_alpha\" / Option(U64)");
+        let field = LayoutField::new("from", BorshType::Pubkey);
+        assert_eq!(field.to_py_field(), "from_: bytes");
+    }
+
+    #[test]
+    fn field_naming() {
+        let item = syn::parse_str::<syn::ItemStruct>(
+            r#"struct Foo {
+                max_supply: u64,
+                #[schema_rename = "owner_key"]
+                owner: Pubkey,
+                r#type: u8,
+            }"#,
+        )
+        .unwrap();
+        let names = |field_naming: FieldNaming| {
+            let options = LayoutOptions {
+                field_naming,
+                ..LayoutOptions::default()
+            };
+            item.fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| LayoutField::from_tokens(field, i, &options).unwrap().name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(FieldNaming::CamelCase),
+            ["maxSupply", "owner_key", "type"]
+        );
+        assert_eq!(
+            names(FieldNaming::SnakeCase),
+            ["max_supply", "owner_key", "type"]
+        );
+        assert_eq!(
+            names(FieldNaming::Preserve),
+            ["max_supply", "owner_key", "type"]
+        );
+        assert_eq!("snake_case".parse(), Ok(FieldNaming::SnakeCase));
+        assert!("kebab-case".parse::<FieldNaming>().is_err());
+
+        let item = syn::parse_str::<syn::ItemStruct>(
+            r#"struct Foo(#[schema_rename = "amount"] u64, u8);"#,
+        )
+        .unwrap();
+        let fields = item
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                LayoutField::from_tokens(field, i, &LayoutOptions::default())
+                    .unwrap()
+                    .name
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(fields, ["amount", "unnamed_1"]);
+
+        for attr in [
+            "#[schema_rename = \"max-supply\"]",
+            "#[schema_rename(maxSupply)]",
+            "#[schema_rename = 42]",
+        ] {
+            let item = syn::parse_str::<syn::ItemStruct>(&format!(
+                "struct Foo {{ {} max_supply: u64 }}",
+                attr
+            ))
+            .unwrap();
+            let field = item.fields.iter().next().unwrap();
+            let error = LayoutField::from_tokens(field, 0, &LayoutOptions::default()).unwrap_err();
+            assert!(
+                error
+                    .to_string()
+                    .starts_with("invalid attribute in field `max_supply`"),
+                "{}",
+                error
+            );
+        }
+    }
 }
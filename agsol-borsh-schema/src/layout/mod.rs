@@ -1,11 +1,103 @@
+mod account;
 mod borsh_type;
 mod field;
+
+pub(crate) use account::instruction_accounts;
+pub use account::InstructionAccount;
+
 pub use borsh_type::BorshType;
+pub(crate) use borsh_type::LOCKED_ITEM;
 pub use field::LayoutField;
 
+use crate::cfg::is_enabled;
+use crate::error::SchemaError;
+use heck::{MixedCase, ShoutySnakeCase, SnakeCase};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use std::path::PathBuf;
+
+/// Options that control how Rust data structures are parsed into layouts.
+#[derive(Clone, Debug)]
+pub struct LayoutOptions {
+    /// Prefix of the names generated for the unnamed fields of tuple structs
+    /// and tuple variants, followed by the position of the field. Defaults to
+    /// `unnamed_`, yielding `unnamed_0`, `unnamed_1`, etc.
+    pub positional_prefix: String,
+    /// Inlines the single field of newtype enum variants (e.g. `C(u64)`) into
+    /// the enum layout instead of wrapping it in a separate variant layout.
+    pub inline_newtype_variants: bool,
+    /// Features enabled when evaluating `#[cfg(...)]` attributes on items,
+    /// modules, fields and enum variants. Other configuration options, like
+    /// `test`, are treated as unset.
+    pub features: Vec<String>,
+    /// Naming convention of the named fields and the enum variant fields.
+    /// Fields renamed by `#[schema_rename = "..."]` keep the provided name.
+    pub field_naming: FieldNaming,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        Self {
+            positional_prefix: "unnamed_".to_owned(),
+            inline_newtype_variants: false,
+            features: Vec::new(),
+            field_naming: FieldNaming::default(),
+        }
+    }
+}
+
+/// Naming convention of the fields in the generated classes and schemas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldNaming {
+    /// Converts the names to camelCase, e.g. `max_supply` to `maxSupply`.
+    CamelCase,
+    /// Converts the names to snake_case, e.g. `maxSupply` to `max_supply`.
+    SnakeCase,
+    /// Keeps the names of the Rust fields.
+    Preserve,
+}
+
+impl Default for FieldNaming {
+    fn default() -> Self {
+        Self::CamelCase
+    }
+}
+
+impl FieldNaming {
+    /// Converts the name of a Rust field (or enum variant layout) according
+    /// to the naming convention. The `r#` prefix of raw identifiers is
+    /// removed.
+    pub fn apply(self, name: &str) -> String {
+        let name = name.trim_start_matches("r#");
+        match self {
+            Self::CamelCase => name.to_mixed_case(),
+            Self::SnakeCase => name.to_snake_case(),
+            Self::Preserve => name.to_owned(),
+        }
+    }
+}
+
+impl std::str::FromStr for FieldNaming {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "camelCase" => Ok(Self::CamelCase),
+            "snake_case" => Ok(Self::SnakeCase),
+            "preserve" => Ok(Self::Preserve),
+            _ => Err(format!(
+                "unknown naming convention `{}`, expected `camelCase`, `snake_case` or `preserve`",
+                s
+            )),
+        }
+    }
+}
+
 /// Indicates whether the layout should be generated for a `struct` or an
 /// `enum` type.
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum Kind {
     Enum,
     Struct,
@@ -13,44 +105,744 @@ pub enum Kind {
 
 /// The layout of a Rust data structure that is straghtforward to convert into
 /// a TypeScript class and the respective borsh schema.
-#[derive(Debug)]
+///
+/// The fields of enum layouts represent the variants of the enum in the order
+/// of their discriminants.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Layout {
     pub name: String,
+    /// The name of the Rust data structure if `name` is prefixed with its
+    /// module path to tell it apart from data structures with the same name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rust_name: Option<String>,
     pub kind: Kind,
     pub fields: Vec<LayoutField>,
+    /// Type parameters of generic data structures. Generic layouts are not
+    /// converted directly, only their concrete instances.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generics: Vec<String>,
+    /// Path of the module the data structure is defined in, relative to the
+    /// root of the parsed directory.
+    pub module: Vec<String>,
+    /// The file the data structure is defined in.
+    pub source: PathBuf,
+    /// Lines of the doc comment of the data structure.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    /// Whether the data structure derives `MaxSerializedLen` from
+    /// `agsol-common`, i.e. it is stored in accounts of a bounded size.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub derives_max_len: bool,
 }
 
 impl Layout {
-    /// Generates a layout from the underlying token stream.
+    /// Generates a layout from the underlying token stream. Fields disabled
+    /// by `#[cfg(...)]` attributes are left out.
     pub fn from_tokens(
         name: &str,
         fields: &mut dyn Iterator<Item = &syn::Field>,
-    ) -> Result<Self, anyhow::Error> {
+        options: &LayoutOptions,
+    ) -> Result<Self, SchemaError> {
         let fields = fields
+            .filter(|field| is_enabled(&field.attrs, &options.features))
             .enumerate()
-            .map(|(i, x)| LayoutField::from_tokens(x, i))
-            .collect::<Result<Vec<_>, _>>()?;
+            .map(|(i, x)| LayoutField::from_tokens(x, i, options))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| err.with_location(|location| location.item = name.to_owned()))?;
         Ok(Self {
             name: name.to_string(),
+            rust_name: None,
             kind: Kind::Struct,
             fields,
+            generics: Vec::new(),
+            module: Vec::new(),
+            source: PathBuf::new(),
+            docs: Vec::new(),
+            derives_max_len: false,
         })
     }
 
-    /// Converts the layout into a TypeScript class.
+    /// Creates a layout from the code generated by `#[derive(BorshSchema)]`.
+    /// The module path is provided by `module_path!()`, so the name of the
+    /// crate is stripped from it.
+    #[doc(hidden)]
+    pub fn derived(
+        name: String,
+        kind: Kind,
+        fields: Vec<LayoutField>,
+        docs: &[&str],
+        module_path: &str,
+        file: &str,
+    ) -> Self {
+        Self {
+            name,
+            rust_name: None,
+            kind,
+            fields,
+            generics: Vec::new(),
+            module: module_path.split("::").skip(1).map(str::to_owned).collect(),
+            source: PathBuf::from(file),
+            docs: normalize_docs(docs),
+            derives_max_len: false,
+        }
+    }
+
+    /// Returns whether the layout was derived for the data structure `name`
+    /// in the module provided by `module_path!()`.
+    #[doc(hidden)]
+    pub fn is_derived(&self, name: &str, module_path: &str) -> bool {
+        self.name == name
+            && self
+                .module
+                .iter()
+                .map(String::as_str)
+                .eq(module_path.split("::").skip(1))
+    }
+
+    /// Returns the path of the data structure relative to the root of the
+    /// parsed directory, e.g. `state::Foo`.
+    pub fn path(&self) -> String {
+        self.module
+            .iter()
+            .chain(std::iter::once(&self.name))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+
+    /// Creates a concrete layout from a generic one by substituting its type
+    /// parameters with the provided type arguments.
+    pub fn instantiate(&self, args: &[BorshType]) -> Result<Self, SchemaError> {
+        if args.len() != self.generics.len() {
+            return Err(SchemaError::TypeArguments {
+                location: Box::default(),
+                name: self.name.clone(),
+                expected: self.generics.len(),
+                found: args.len(),
+            });
+        }
+        Ok(Self {
+            name: BorshType::Generic(self.name.clone(), args.to_vec()).to_ident(),
+            rust_name: self
+                .rust_name
+                .as_ref()
+                .map(|name| BorshType::Generic(name.clone(), args.to_vec()).to_ident()),
+            kind: self.kind.clone(),
+            fields: self
+                .fields
+                .iter()
+                .map(|field| field.substitute(&self.generics, args))
+                .collect(),
+            generics: Vec::new(),
+            module: self.module.clone(),
+            source: self.source.clone(),
+            docs: self.docs.clone(),
+            derives_max_len: self.derives_max_len,
+        })
+    }
+
+    /// Returns every generic type (with its type arguments) referenced by the
+    /// fields of this layout.
+    pub fn generic_instances(&self) -> Vec<(String, Vec<BorshType>)> {
+        let mut instances = Vec::new();
+        for field in &self.fields {
+            field.ty.visit(&mut |ty| {
+                if let BorshType::Generic(name, args) = ty {
+                    instances.push((name.clone(), args.clone()));
+                }
+            });
+        }
+        instances
+    }
+
+    /// Returns the maximum number of bytes the data structure is serialized
+    /// into, which matches its `MaxSerializedLen::MAX_SERIALIZED_LEN` if it
+    /// implements the trait. See [`BorshType::max_serialized_len`] for the
+    /// details.
+    pub fn max_serialized_len(&self, layouts: &[Layout]) -> Option<usize> {
+        self.max_len(layouts, &mut Vec::new())
+    }
+
+    pub(crate) fn max_len(&self, layouts: &[Layout], visiting: &mut Vec<String>) -> Option<usize> {
+        if visiting.contains(&self.name) {
+            return None;
+        }
+        visiting.push(self.name.clone());
+        let mut fields = self
+            .fields
+            .iter()
+            .map(|field| field.ty.max_len(layouts, visiting));
+        let len = match self.kind {
+            Kind::Struct => fields.try_fold(0_usize, |len, field| len.checked_add(field?)),
+            Kind::Enum => fields
+                .try_fold(0_usize, |len, variant| Some(len.max(variant?)))
+                .and_then(|len| len.checked_add(1)),
+        };
+        visiting.pop();
+        len
+    }
+
+    /// Converts the layout into a TypeScript class. Doc comments are
+    /// converted into JSDoc comments. Fields holding sets or tuples are
+    /// converted by the constructor (see [`BorshType::to_ts_conversion`]).
     pub fn to_ts_class(&self) -> String {
         let class_fields = self
             .fields
             .iter()
             .filter(|field| !field.should_skip())
-            .map(|field| String::from("\n    ") + &field.to_class_field() + ";")
+            .map(|field| {
+                format!(
+                    "\n{}    {};",
+                    to_jsdoc(&field.docs, "    "),
+                    field.to_class_field()
+                )
+            })
             .collect::<String>();
+        let conversions = self.ts_conversions(|field| format!("this.{}", field.name));
+        let constructor = if conversions.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "\n\n    constructor(properties: any) {{\n        super(properties);{}\n    }}",
+                conversions
+            )
+        };
+        format!(
+            r#"{}export class {} extends {:?} {{{}{}
+}};
+
+"#,
+            to_jsdoc(&self.docs, ""),
+            self.name,
+            self.kind,
+            class_fields,
+            constructor
+        )
+    }
+
+    /// Converts a layout generated for a tuple type into a TypeScript class
+    /// whose constructor returns the elements as a TypeScript tuple. The
+    /// class only holds the schema of the tuple, so the returned array refers
+    /// to it as its `constructor`, which is how `borsh-js` looks it up.
+    pub fn to_ts_tuple_class(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|field| !field.should_skip())
+            .collect::<Vec<_>>();
+        let params = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| format!("{}: {}", i, field.ty.to_class_type()))
+            .collect::<Vec<_>>()
+            .join("; ");
+        let elements = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let element = format!("fields[{}]", i);
+                field.ty.to_ts_conversion(&element).unwrap_or(element)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
         format!(
-            r#"export class {} extends {:?} {{{}
+            r#"export class {0} {{
+    constructor(fields: {{ {1} }}) {{
+        return Object.defineProperty([{2}], "constructor", {{ value: {0} }});
+    }}
 }};
 
 "#,
-            self.name, self.kind, class_fields
+            self.name, params, elements
+        )
+    }
+
+    /// Returns the borsh schema of a layout generated for a tuple type, whose
+    /// fields are named after their indices, i.e. the elements of the tuple.
+    pub fn to_tuple_borsh_schema(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|field| !field.should_skip())
+            .enumerate()
+            .map(|(i, field)| LayoutField {
+                name: i.to_string(),
+                ..field.clone()
+            })
+            .collect();
+        Self {
+            fields,
+            ..self.clone()
+        }
+        .to_borsh_schema()
+    }
+
+    /// Returns the statements converting the fields holding sets or tuples,
+    /// where `value` returns the expression of a field. The variants of enums
+    /// are only converted if they are set.
+    fn ts_conversions(&self, value: impl Fn(&LayoutField) -> String) -> String {
+        self.fields
+            .iter()
+            .filter(|field| !field.should_skip())
+            .filter_map(|field| {
+                let conversion = field.ty.to_ts_conversion(&value(field))?;
+                Some(match self.kind {
+                    Kind::Struct => format!("\n        this.{} = {};", field.name, conversion),
+                    Kind::Enum => format!(
+                        "\n        if (this.enum === \"{0}\") {{\n            this.{0} = {1};\n        }}",
+                        field.name, conversion
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    /// Converts the layout into a self-contained TypeScript class with a typed
+    /// constructor and `decode`/`encode` methods bound to `SCHEMA`. Unlike
+    /// [`Layout::to_ts_class`], it doesn't rely on the `Struct` and `Enum`
+    /// base classes.
+    pub fn to_self_contained_ts_class(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|field| !field.should_skip())
+            .collect::<Vec<_>>();
+        let (class_fields, constructor) = match self.kind {
+            Kind::Struct => {
+                let class_fields = fields
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "\n{}    {};",
+                            to_jsdoc(&field.docs, "    "),
+                            field.to_class_field()
+                        )
+                    })
+                    .collect::<String>();
+                let constructor = if fields.is_empty() {
+                    "    constructor(_fields: {} = {}) {}\n".to_owned()
+                } else {
+                    let params = fields
+                        .iter()
+                        .map(|field| format!("\n        {},", field.to_class_field()))
+                        .collect::<String>();
+                    let assignments = fields
+                        .iter()
+                        .map(|field| {
+                            let value = format!("fields.{}", field.name);
+                            format!(
+                                "\n        this.{} = {};",
+                                field.name,
+                                field.ty.to_ts_conversion(&value).unwrap_or(value)
+                            )
+                        })
+                        .collect::<String>();
+                    format!(
+                        "    constructor(fields: {{{}\n    }}) {{{}\n    }}\n",
+                        params, assignments
+                    )
+                };
+                (class_fields, constructor)
+            }
+            Kind::Enum => {
+                let class_fields = String::from("\n    enum: string;")
+                    + &fields
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "\n{}    {}?: {};",
+                                to_jsdoc(&field.docs, "    "),
+                                field.name,
+                                field.ty.to_class_type()
+                            )
+                        })
+                        .collect::<String>();
+                let mut variants = fields
+                    .iter()
+                    .map(|field| format!("{{ {} }}", field.to_class_field()))
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+                if variants.is_empty() {
+                    variants = "never".to_owned();
+                }
+                let constructor = format!(
+                    r#"    constructor(fields: {}) {{
+        if (Object.keys(fields).length !== 1) {{
+            throw new Error("Enum can only take single value");
+        }}
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);{}
+    }}
+"#,
+                    variants,
+                    self.ts_conversions(|field| format!("this.{}", field.name))
+                );
+                (class_fields, constructor)
+            }
+        };
+        format!(
+            r#"{3}export class {0} {{{1}
+
+{2}
+    static decode(buf: Buffer): {0} {{
+        return deserializeUnchecked(SCHEMA, {0}, buf);
+    }}
+
+    encode(): Buffer {{
+        return Buffer.from(serialize(SCHEMA, this));
+    }}
+}};
+
+"#,
+            self.name,
+            class_fields,
+            constructor,
+            to_jsdoc(&self.docs, "")
+        )
+    }
+
+    /// Generates a TypeScript constant for every field of the layout holding a
+    /// bounded container with a known bound, e.g. a `name: MaxLenString<32>`
+    /// field of `Foo` generates `export const FOO_NAME_MAX_LEN = 32;`. Only
+    /// the outermost bound of each field is considered.
+    pub fn to_ts_bound_constants(&self) -> String {
+        let constants = self
+            .fields
+            .iter()
+            .filter(|field| !field.should_skip())
+            .filter_map(|field| {
+                let mut bound = None;
+                field.ty.visit(&mut |ty| {
+                    if let BorshType::Bounded(_, Some(max_len)) = ty {
+                        bound.get_or_insert(*max_len);
+                    }
+                });
+                bound.map(|max_len| {
+                    format!(
+                        "export const {}_{}_MAX_LEN = {};\n",
+                        self.name.to_shouty_snake_case(),
+                        field.name.to_shouty_snake_case(),
+                        max_len
+                    )
+                })
+            })
+            .collect::<String>();
+        if constants.is_empty() {
+            constants
+        } else {
+            constants + "\n"
+        }
+    }
+
+    /// Generates TypeScript constants for the accounts holding a struct that
+    /// derives `MaxSerializedLen`, which are useful for filtering
+    /// `getProgramAccounts` by data size and account type: the maximum
+    /// serialized length of the struct (e.g. `FOO_MAX_SERIALIZED_LEN`) and,
+    /// if `discriminator` is set, the account discriminator of the struct
+    /// (see [`Layout::discriminator`]) as `FOO_DISCRIMINATOR`.
+    pub fn to_ts_account_constants(&self, layouts: &[Layout], discriminator: bool) -> String {
+        if self.kind != Kind::Struct || !self.derives_max_len {
+            return String::new();
+        }
+        let prefix = self.name.to_shouty_snake_case();
+        let mut constants = String::new();
+        if let Some(max_len) = self.max_serialized_len(layouts) {
+            constants += &format!(
+                "export const {}_MAX_SERIALIZED_LEN = {};\n",
+                prefix, max_len
+            );
+        }
+        if discriminator {
+            let bytes = self
+                .discriminator()
+                .iter()
+                .map(u8::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            constants += &format!(
+                "export const {}_DISCRIMINATOR = Buffer.from([{}]);\n",
+                prefix, bytes
+            );
+        }
+        if constants.is_empty() {
+            constants
+        } else {
+            constants + "\n"
+        }
+    }
+
+    /// Returns the 8-byte account discriminator of the data structure, i.e.
+    /// the first 8 bytes of the SHA-256 hash of `account:{Name}`, following
+    /// the convention of Anchor. The name is the one of the Rust data
+    /// structure, without the module prefix of the generated name.
+    pub fn discriminator(&self) -> [u8; 8] {
+        let name = self.rust_name.as_deref().unwrap_or(&self.name);
+        let hash = Sha256::digest(format!("account:{}", name).as_bytes());
+        let mut discriminator = [0; 8];
+        discriminator.copy_from_slice(&hash[..8]);
+        discriminator
+    }
+
+    /// Converts an enum layout into a TypeScript discriminated union named
+    /// `{Name}Union`, where each variant is tagged by its `kind`. Variants
+    /// without fields have no `value`. Type guards and conversions between
+    /// the union and the enum class are generated as well. Returns an empty
+    /// string for struct layouts and enums without variants.
+    ///
+    /// The provided layouts are used to look up the layouts of the variants.
+    pub fn to_ts_union(&self, layouts: &[Layout]) -> String {
+        let variants = self
+            .fields
+            .iter()
+            .filter(|field| !field.should_skip())
+            .map(|field| {
+                let ty = field.ty.to_class_type();
+                let is_unit = layouts.iter().any(|layout| {
+                    layout.name == ty && layout.fields.iter().all(LayoutField::should_skip)
+                });
+                let kind = field.variant.as_deref().unwrap_or(&field.name);
+                (field, kind, is_unit)
+            })
+            .collect::<Vec<_>>();
+        if !matches!(self.kind, Kind::Enum) || variants.is_empty() {
+            return String::new();
+        }
+
+        let union_name = format!("{}Union", self.name);
+        let union_variants = variants
+            .iter()
+            .map(|(field, kind, is_unit)| {
+                if *is_unit {
+                    format!("\n    | {{ kind: \"{}\" }}", kind)
+                } else {
+                    format!(
+                        "\n    | {{ kind: \"{}\"; value: {} }}",
+                        kind,
+                        field.ty.to_class_type()
+                    )
+                }
+            })
+            .collect::<String>();
+        let type_guards = variants
+            .iter()
+            .map(|(_, kind, _)| {
+                format!(
+                    r#"export function is{0}{1}(value: {2}): value is Extract<{2}, {{ kind: "{1}" }}> {{
+    return value.kind === "{1}";
+}}
+
+"#,
+                    self.name, kind, union_name
+                )
+            })
+            .collect::<String>();
+        let to_union_cases = variants
+            .iter()
+            .map(|(field, kind, is_unit)| {
+                let result = if *is_unit {
+                    format!("{{ kind: \"{}\" }}", kind)
+                } else {
+                    format!("{{ kind: \"{}\", value: value.{}! }}", kind, field.name)
+                };
+                format!(
+                    "\n        case \"{}\":\n            return {};",
+                    field.name, result
+                )
+            })
+            .collect::<String>();
+        let from_union_cases = variants
+            .iter()
+            .map(|(field, kind, is_unit)| {
+                let value = if *is_unit {
+                    format!("new {}({{}})", field.ty.to_class_type())
+                } else {
+                    "value.value".to_owned()
+                };
+                format!(
+                    "\n        case \"{}\":\n            return new {}({{ {}: {} }});",
+                    kind, self.name, field.name, value
+                )
+            })
+            .collect::<String>();
+
+        format!(
+            r#"export type {1} ={2};
+
+{3}export function to{1}(value: {0}): {1} {{
+    switch (value.enum) {{{4}
+    }}
+    throw new Error(`Unknown {0} variant: ${{value.enum}}`);
+}}
+
+export function from{1}(value: {1}): {0} {{
+    switch (value.kind) {{{5}
+    }}
+}}
+
+"#,
+            self.name, union_name, union_variants, type_guards, to_union_cases, from_union_cases
+        )
+    }
+
+    /// Generates a `build{Name}{Variant}` function for every variant of an
+    /// instruction enum, i.e. an enum whose variants declare the accounts of
+    /// the instructions with `#[schema_accounts(...)]`. The functions take the
+    /// id of the program, the public keys of the accounts and the fields of
+    /// the variant (unless it has none), and return a `TransactionInstruction`
+    /// holding the serialized enum and the account metas in the declared
+    /// order. Returns an empty string for other layouts.
+    ///
+    /// The provided layouts are used to look up the layouts of the variants.
+    pub fn to_ts_instruction_builders(&self, layouts: &[Layout]) -> String {
+        if !matches!(self.kind, Kind::Enum)
+            || self.fields.iter().all(|field| field.accounts.is_empty())
+        {
+            return String::new();
+        }
+        self.fields
+            .iter()
+            .filter(|field| !field.should_skip())
+            .map(|field| {
+                let ty = field.ty.to_class_type();
+                let is_unit = layouts.iter().any(|layout| {
+                    layout.name == ty && layout.fields.iter().all(LayoutField::should_skip)
+                });
+                let variant = field.variant.as_deref().unwrap_or(&field.name);
+
+                let mut params = vec!["programId: PublicKey".to_owned()];
+                if !field.accounts.is_empty() {
+                    let accounts = field
+                        .accounts
+                        .iter()
+                        .map(|account| format!("\n        {}: PublicKey;", account.name))
+                        .collect::<String>();
+                    params.push(format!("accounts: {{{}\n    }}", accounts));
+                }
+                let value = if is_unit {
+                    format!("new {}({{}})", ty)
+                } else {
+                    params.push(format!("args: {}", ty));
+                    "args".to_owned()
+                };
+                let params = params
+                    .iter()
+                    .map(|param| format!("\n    {},", param))
+                    .collect::<String>();
+                let keys = if field.accounts.is_empty() {
+                    "[]".to_owned()
+                } else {
+                    let metas = field
+                        .accounts
+                        .iter()
+                        .map(|account| {
+                            format!(
+                                "\n            {{ pubkey: accounts.{}, isSigner: {}, isWritable: {} }},",
+                                account.name, account.signer, account.writable
+                            )
+                        })
+                        .collect::<String>();
+                    format!("[{}\n        ]", metas)
+                };
+                format!(
+                    r#"{0}export function build{1}{2}({3}
+): TransactionInstruction {{
+    const data = Buffer.from(serialize(SCHEMA, new {1}({{ {4}: {5} }})));
+    return new TransactionInstruction({{
+        programId,
+        keys: {6},
+        data,
+    }});
+}}
+
+"#,
+                    to_jsdoc(&field.docs, ""),
+                    self.name,
+                    variant,
+                    params,
+                    field.name,
+                    value,
+                    keys
+                )
+            })
+            .collect()
+    }
+
+    /// Converts the layout into a Python dataclass with `decode`/`encode`
+    /// methods, followed by its `borsh-construct` layout in the
+    /// `{NAME}_LAYOUT` constant. Field names are converted to snake_case.
+    ///
+    /// Enum layouts are converted into a dataclass holding the name of the
+    /// variant in `kind` and the data of the variant in `value`.
+    pub fn to_py_class(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|field| !field.should_skip())
+            .collect::<Vec<_>>();
+        let layout_name = format!("{}_LAYOUT", self.name.to_shouty_snake_case());
+        let (class_fields, layout) = match self.kind {
+            Kind::Struct => {
+                let mut class_fields = fields
+                    .iter()
+                    .map(|field| format!("    {}\n", field.to_py_field()))
+                    .collect::<String>();
+                if !class_fields.is_empty() {
+                    class_fields.push('\n');
+                }
+                let subcons = fields
+                    .iter()
+                    .map(|field| format!("\n    {},", field.to_py_construct()))
+                    .collect::<String>();
+                let layout = format!("_struct(\n    {},{}\n)", self.name, subcons);
+                (class_fields, layout)
+            }
+            Kind::Enum => {
+                let value_type = match fields.as_slice() {
+                    [] => "typing.Any".to_owned(),
+                    [field] => field.ty.to_py_type(),
+                    _ => format!(
+                        "typing.Union[{}]",
+                        fields
+                            .iter()
+                            .map(|field| field.ty.to_py_type())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                let class_fields = format!("    kind: str\n    value: {}\n\n", value_type);
+                let variants = fields
+                    .iter()
+                    .map(|field| {
+                        let discriminant = field
+                            .discriminant
+                            .map(|discriminant| format!(", {}", discriminant))
+                            .unwrap_or_default();
+                        format!(
+                            "\n    (\"{}\", {}{}),",
+                            field.variant.as_deref().unwrap_or(&field.name),
+                            field.ty.to_py_construct(),
+                            discriminant
+                        )
+                    })
+                    .collect::<String>();
+                let layout = format!("_enum(\n    {},{}\n)", self.name, variants);
+                (class_fields, layout)
+            }
+        };
+        format!(
+            r#"@dataclass
+class {0}:
+{1}    @classmethod
+    def decode(cls, data: bytes) -> {0}:
+        return {2}.parse(data)
+
+    def encode(self) -> bytes:
+        return {2}.build(self)
+
+
+{2} = {3}
+"#,
+            self.name, class_fields, layout_name, layout
         )
     }
 
@@ -60,12 +852,35 @@ impl Layout {
             Kind::Struct => "kind: 'struct', fields:",
             Kind::Enum => "kind: 'enum', field: 'enum', values:",
         };
-        let borsh_schema_fields = self
+        let borsh_schema_fields = if let Some(max) = self
             .fields
             .iter()
-            .filter(|field| !field.should_skip())
-            .map(|field| String::from("\n\t\t\t") + &field.to_borsh_schema() + ",")
-            .collect::<String>();
+            .filter_map(|field| field.discriminant)
+            .max()
+        {
+            // borsh-js serializes the index of the variant among the values,
+            // so the gaps between the discriminants are filled with
+            // placeholders that never occur in valid data
+            (0..=max)
+                .map(|discriminant| {
+                    let value = match self
+                        .fields
+                        .iter()
+                        .find(|field| field.discriminant == Some(discriminant))
+                    {
+                        Some(field) => field.to_borsh_schema(),
+                        None => format!("['unused{}', 'u8']", discriminant),
+                    };
+                    String::from("\n\t\t\t") + &value + ","
+                })
+                .collect::<String>()
+        } else {
+            self.fields
+                .iter()
+                .filter(|field| !field.should_skip())
+                .map(|field| String::from("\n\t\t\t") + &field.to_borsh_schema() + ",")
+                .collect::<String>()
+        };
         // NOTE don't change this string (tabs are included in the output string)
         format!(
             r#"
@@ -80,3 +895,352 @@ impl Layout {
         )
     }
 }
+
+/// Collects the lines of the doc comments (`///` and `/** */`) found among
+/// the attributes. The leading space of each line is removed.
+pub(crate) fn doc_lines(attrs: &[syn::Attribute]) -> Vec<String> {
+    normalize_docs(
+        attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"))
+            .filter_map(|attr| match attr.parse_meta() {
+                Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                    lit: syn::Lit::Str(doc),
+                    ..
+                })) => Some(doc.value()),
+                _ => None,
+            }),
+    )
+}
+
+/// Splits the raw `#[doc = "..."]` strings into lines, stripping the space
+/// following `///` along with leading and trailing blank lines.
+fn normalize_docs(docs: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+    let mut lines = docs
+        .into_iter()
+        .flat_map(|doc| {
+            doc.as_ref()
+                .split('\n')
+                .map(|line| {
+                    let line = line.trim_end();
+                    line.strip_prefix(' ').unwrap_or(line).to_owned()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    while matches!(lines.first(), Some(line) if line.is_empty()) {
+        lines.remove(0);
+    }
+    while matches!(lines.last(), Some(line) if line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Converts the lines of a doc comment into a JSDoc comment indented by
+/// `indent`. Returns an empty string if there are no lines.
+fn to_jsdoc(docs: &[String], indent: &str) -> String {
+    let escape = |line: &String| line.replace("*/", "*\\/");
+    match docs {
+        [] => String::new(),
+        [line] => format!("{}/** {} */\n", indent, escape(line)),
+        _ => {
+            let lines = docs
+                .iter()
+                .map(|line| {
+                    if line.is_empty() {
+                        format!("{} *\n", indent)
+                    } else {
+                        format!("{} * {}\n", indent, escape(line))
+                    }
+                })
+                .collect::<String>();
+            format!("{0}/**\n{1}{0} */\n", indent, lines)
+        }
+    }
+}
+
+#[cfg(test)]
+impl Layout {
+    /// Creates a layout without generics, docs or a source file for tests.
+    pub(crate) fn new(name: &str, kind: Kind, fields: Vec<LayoutField>) -> Self {
+        Self {
+            name: name.to_owned(),
+            rust_name: None,
+            kind,
+            fields,
+            generics: Vec::new(),
+            module: Vec::new(),
+            source: PathBuf::new(),
+            docs: Vec::new(),
+            derives_max_len: false,
+        }
+    }
+}
+
+#[cfg(test)]
+impl LayoutField {
+    /// Creates a field of a struct without docs for tests.
+    pub(crate) fn new(name: &str, ty: BorshType) -> Self {
+        Self {
+            name: name.to_owned(),
+            ty,
+            variant: None,
+            docs: Vec::new(),
+            discriminant: None,
+            accounts: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn struct_to_self_contained_ts_class() {
+        let layout = Layout::new(
+            "Foo",
+            Kind::Struct,
+            vec![
+                LayoutField::new("fieldA", BorshType::U64),
+                LayoutField::new("fieldB", BorshType::Option(Box::new(BorshType::Pubkey))),
+                LayoutField::new("skipped", BorshType::Skip),
+            ],
+        );
+        assert_eq!(
+            layout.to_self_contained_ts_class(),
+            r#"export class Foo {
+    fieldA: BN;
+    fieldB: PublicKey | null;
+
+    constructor(fields: {
+        fieldA: BN,
+        fieldB: PublicKey | null,
+    }) {
+        this.fieldA = fields.fieldA;
+        this.fieldB = fields.fieldB;
+    }
+
+    static decode(buf: Buffer): Foo {
+        return deserializeUnchecked(SCHEMA, Foo, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+"#
+        );
+    }
+
+    #[test]
+    fn enum_to_self_contained_ts_class() {
+        let layout = Layout::new(
+            "Bar",
+            Kind::Enum,
+            vec![
+                LayoutField::new("barA", BorshType::Custom("BarA".to_owned())),
+                LayoutField::new("barB", BorshType::Custom("BarB".to_owned())),
+            ],
+        );
+        let class = layout.to_self_contained_ts_class();
+        assert!(class.starts_with(
+            r#"export class Bar {
+    enum: string;
+    barA?: BarA;
+    barB?: BarB;
+
+    constructor(fields: { barA: BarA } | { barB: BarB }) {"#
+        ));
+        assert!(class.contains("static decode(buf: Buffer): Bar {"));
+    }
+
+    #[test]
+    fn bound_constants() {
+        let layout = Layout::new(
+            "UserAccount",
+            Kind::Struct,
+            vec![
+                LayoutField::new(
+                    "displayName",
+                    BorshType::from_str("MaxLenString<32>").unwrap(),
+                ),
+                LayoutField::new(
+                    "friends",
+                    BorshType::from_str("Option<MaxLenVec<MaxLenString<8>, 16>>").unwrap(),
+                ),
+                LayoutField::new(
+                    "unknown",
+                    BorshType::from_str("MaxLenVec<u8, MAX_LEN>").unwrap(),
+                ),
+            ],
+        );
+        assert_eq!(
+            layout.to_ts_bound_constants(),
+            "export const USER_ACCOUNT_DISPLAY_NAME_MAX_LEN = 32;\n\
+            export const USER_ACCOUNT_FRIENDS_MAX_LEN = 16;\n\n"
+        );
+    }
+
+    #[test]
+    fn enum_to_ts_union() {
+        let enum_layout = Layout::new(
+            "Bar",
+            Kind::Enum,
+            vec![
+                LayoutField::from_enum_variant("BarA", "A", &[]).unwrap(),
+                LayoutField::from_enum_variant("BarB", "B", &[]).unwrap(),
+            ],
+        );
+        let variant_a = Layout::new("BarA", Kind::Struct, Vec::new());
+        let variant_b = Layout::new(
+            "BarB",
+            Kind::Struct,
+            vec![LayoutField::new("amount", BorshType::U64)],
+        );
+        let union = enum_layout.to_ts_union(&[variant_a, variant_b]);
+        assert!(union.starts_with(
+            r#"export type BarUnion =
+    | { kind: "A" }
+    | { kind: "B"; value: BarB };
+
+export function isBarA(value: BarUnion): value is Extract<BarUnion, { kind: "A" }> {
+    return value.kind === "A";
+}
+"#
+        ));
+        assert!(union.contains(
+            r#"    switch (value.enum) {
+        case "barA":
+            return { kind: "A" };
+        case "barB":
+            return { kind: "B", value: value.barB! };
+    }"#
+        ));
+        assert!(union.contains(
+            r#"    switch (value.kind) {
+        case "A":
+            return new Bar({ barA: new BarA({}) });
+        case "B":
+            return new Bar({ barB: value.value });
+    }"#
+        ));
+        assert!(
+            Layout::from_tokens("Foo", &mut std::iter::empty(), &LayoutOptions::default())
+                .unwrap()
+                .to_ts_union(&[])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn layouts_to_py_class() {
+        let layout = Layout::new(
+            "FooBar",
+            Kind::Struct,
+            vec![
+                LayoutField::new("fieldA", BorshType::U64),
+                LayoutField::new("skipped", BorshType::Skip),
+                LayoutField::new(
+                    "fieldB",
+                    BorshType::Vec(Box::new(BorshType::Custom("Baz".to_owned()))),
+                ),
+            ],
+        );
+        assert_eq!(
+            layout.to_py_class(),
+            r#"@dataclass
+class FooBar:
+    field_a: int
+    field_b: typing.List[Baz]
+
+    @classmethod
+    def decode(cls, data: bytes) -> FooBar:
+        return FOO_BAR_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return FOO_BAR_LAYOUT.build(self)
+
+
+FOO_BAR_LAYOUT = _struct(
+    FooBar,
+    "field_a" / U64,
+    "field_b" / Vec(LazyBound(lambda: BAZ_LAYOUT)),
+)
+"#
+        );
+
+        let layout = Layout::new(
+            "Bar",
+            Kind::Enum,
+            vec![
+                LayoutField::from_enum_variant("BarA", "A", &[]).unwrap(),
+                LayoutField {
+                    variant: Some("B".to_owned()),
+                    ..LayoutField::new("barB", BorshType::U32)
+                },
+            ],
+        );
+        let class = layout.to_py_class();
+        assert!(class.starts_with(
+            r#"@dataclass
+class Bar:
+    kind: str
+    value: typing.Union[BarA, int]
+"#
+        ));
+        assert!(class.ends_with(
+            r#"BAR_LAYOUT = _enum(
+    Bar,
+    ("A", LazyBound(lambda: BAR_A_LAYOUT)),
+    ("B", U32),
+)
+"#
+        ));
+    }
+
+    #[test]
+    fn doc_comments_to_jsdoc() {
+        let item: syn::ItemStruct = syn::parse_quote! {
+            ///
+            /// First line.
+            ///   Indented line.
+            ///
+            struct Foo {
+                /// Single line.
+                #[alias(u64)]
+                amount: Amount,
+                /** Block comment. */
+                timestamp: i64,
+            }
+        };
+        assert_eq!(
+            doc_lines(&item.attrs),
+            vec!["First line.", "  Indented line."]
+        );
+        assert_eq!(
+            to_jsdoc(&doc_lines(&item.attrs), ""),
+            "/**\n * First line.\n *   Indented line.\n */\n"
+        );
+        let mut layout =
+            Layout::from_tokens("Foo", &mut item.fields.iter(), &LayoutOptions::default()).unwrap();
+        assert_eq!(layout.fields[0].docs, vec!["Single line."]);
+        assert_eq!(layout.fields[1].docs, vec!["Block comment."]);
+        layout.docs = vec!["Foo.".to_owned()];
+        assert_eq!(
+            layout.to_ts_class(),
+            r#"/** Foo. */
+export class Foo extends Struct {
+    /** Single line. */
+    amount: BN;
+    /** Block comment. */
+    timestamp: BN;
+};
+
+"#
+        );
+    }
+}
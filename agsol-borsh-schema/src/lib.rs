@@ -5,8 +5,9 @@
 //! [`borsh-rs`](https://docs.rs/borsh/0.9.1/borsh/index.html) for more details.
 //!
 //! By default the library provides a derivable trait `BorshSchema` without any
-//! associated methods and constants. It's an empty trait that is essentially a
-//! flag for the schema parser that works the following way:
+//! associated methods and constants. Without the `full` feature, it's an empty
+//! trait that is essentially a flag for the schema parser that works the
+//! following way:
 //!
 //! 1) the parser traverses all `.rs` files in the provided input directory
 //!
@@ -19,28 +20,64 @@
 //!
 //! The parser itself is only available through the `full` feature flag,
 //! because it uses parsing libraries incompatible with `wasm` or `bpf`
-//! targets.
+//! targets. With the `derived-layouts` feature, the derived trait provides
+//! the layouts of the compiled types as well (see `collect_layouts`), which
+//! sees through macros, `cfg` attributes and type aliases that the parser
+//! can't resolve.
+
+// the derived code refers to this crate by its name
+extern crate self as agsol_borsh_schema;
 
 pub use agsol_borsh_schema_derive::*;
 
+#[cfg(feature = "full")]
+mod backend;
+#[cfg(feature = "full")]
+mod cfg;
+#[cfg(feature = "full")]
+mod codec;
+#[cfg(feature = "full")]
+mod compat;
+#[cfg(feature = "full")]
+mod dependency;
+#[cfg(feature = "full")]
+mod error;
+mod impls;
 /// Intermediate data structures used for generating
 /// schema an TypeScript class layouts.
 #[cfg(feature = "full")]
 mod layout;
+#[cfg(feature = "full")]
+mod resolver;
 #[cfg(all(test, feature = "full"))]
 mod test;
 #[cfg(feature = "full")]
 mod utils;
 
+#[cfg(feature = "full")]
+pub use backend::{PythonBackend, SchemaBackend, TypeScriptBackend};
+#[cfg(feature = "full")]
+pub use codec::{decode, decode_prefix, encode, DecodeError, EncodeError};
+#[cfg(feature = "full")]
+pub use compat::{breaking_changes, BreakingChange};
+#[cfg(feature = "full")]
+pub use dependency::schema_warnings;
+#[cfg(feature = "full")]
+pub use layout::{
+    BorshType, FieldNaming, InstructionAccount, Kind, Layout, LayoutField, LayoutOptions,
+};
 #[cfg(feature = "full")]
 pub use utils::*;
 
-/// An empty trait that serves as a flag for the schema parser.
+/// A trait that serves as a flag for the schema parser.
 ///
-/// It has an `alias` attribute that can be used to annotate `struct` and
-/// `enum` fields to explicitly indicate the type of that field. This is needed
-/// because the parser reads the file as a raw string, therefore it has no way
-/// of knowing the underlying type of a type alias.
+/// Type aliases defined anywhere in the parsed directory are resolved
+/// automatically. However, the parser reads the files as raw strings,
+/// therefore it has no way of knowing the underlying type of an alias defined
+/// outside the parsed directory (e.g. in a dependency). For such cases there
+/// is an `alias` attribute that can be used to annotate `struct` and `enum`
+/// fields to explicitly indicate the type of that field. The `alias`
+/// attribute always takes precedence over automatic alias resolution.
 ///
 /// # Example
 /// ```rust
@@ -70,8 +107,49 @@ pub use utils::*;
 /// ```
 ///
 /// In the above example you may notice that `Foo`'s `bar` field doesn't need
-/// an alias because `Bar` implements `BorshSchema` itself, however, the parser
-/// doesn't know that `SomeAlias` is actually a byte array without the `alias`
-/// attribute. If the `alias` attribute is omitted, the generated TypeScript
-/// code will contain `SomeAlias` instead of `Uint8Array`.
-pub trait BorshSchema {}
+/// an alias because `Bar` implements `BorshSchema` itself. The `baz` field
+/// doesn't need the `alias` attribute either, because `SomeAlias` is defined
+/// in the parsed directory, however, it is shown for the sake of the example.
+/// Without automatic resolution or the `alias` attribute, the generated
+/// TypeScript code would contain `SomeAlias` instead of `Uint8Array`.
+///
+/// # Derived layouts
+///
+/// With the `derived-layouts` feature, the derived implementation returns the
+/// layout of the type along with the layouts of its fields' types, which have
+/// to implement `BorshSchema` as well. It is implemented for primitives,
+/// collections, tuples, `Pubkey` and the bounded containers of `agsol-common`
+/// out of the box. Fields of other foreign types should be annotated with
+/// `alias` (holding a type that implements `BorshSchema`) or `schema_skip`.
+pub trait BorshSchema {
+    /// Returns the type of fields holding this type, e.g.
+    /// `BorshType::Custom("Foo")` for `Foo`.
+    #[cfg(feature = "derived-layouts")]
+    fn schema_type() -> BorshType;
+
+    /// Adds the layouts of this type and of every type it refers to, unless
+    /// they are already present. The fields are named according to the
+    /// `options`.
+    #[cfg(feature = "derived-layouts")]
+    fn add_layouts(_layouts: &mut Vec<Layout>, _options: &LayoutOptions) {}
+}
+
+/// Emits the methods of [`BorshSchema`] implementations only if the
+/// `derived-layouts` feature is enabled. They refer to types that are
+/// unavailable without `full`, and require every field type to implement
+/// `BorshSchema`, which would make enabling `full` break foreign field types.
+#[cfg(feature = "derived-layouts")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __schema_methods {
+    ($($tokens:tt)*) => {
+        $($tokens)*
+    };
+}
+
+#[cfg(not(feature = "derived-layouts"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __schema_methods {
+    ($($tokens:tt)*) => {};
+}
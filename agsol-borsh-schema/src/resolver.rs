@@ -0,0 +1,291 @@
+//! Module-aware resolution of the types found in the parsed data structures.
+//!
+//! The parser reads every file as a raw string, so a field type is only known
+//! by the path it is written with, e.g. `State`, `state::State` or
+//! `crate::state::State`. The resolver keeps track of the `use` declarations
+//! and type aliases of every module, so these paths can be mapped to the
+//! layout (or alias) they are referring to.
+
+use crate::error::SchemaError;
+use crate::layout::{BorshType, Layout};
+use heck::CamelCase;
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+/// Maximum number of `use` declarations followed while resolving a path.
+const MAX_RESOLUTION_DEPTH: usize = 16;
+
+/// Names brought into the scope of a module by `use` declarations.
+#[derive(Debug, Default)]
+struct Scope {
+    imports: HashMap<String, Vec<String>>,
+    globs: Vec<Vec<String>>,
+}
+
+/// A type alias along with the module it is defined in.
+#[derive(Debug)]
+struct Alias {
+    ty: BorshType,
+    module: Vec<String>,
+}
+
+/// Collects `use` declarations and type aliases of the parsed modules and
+/// uses them to resolve the field types of layouts.
+#[derive(Debug, Default)]
+pub struct Resolver {
+    scopes: HashMap<Vec<String>, Scope>,
+    aliases: HashMap<Vec<String>, Alias>,
+    names: HashMap<Vec<String>, String>,
+}
+
+impl Resolver {
+    /// Registers a `use` declaration found in `module`.
+    pub fn add_use(&mut self, module: &[String], tree: &syn::UseTree) {
+        let scope = self.scopes.entry(module.to_vec()).or_default();
+        collect_use(scope, Vec::new(), tree);
+    }
+
+    /// Registers a type alias found in `module`.
+    pub fn add_alias(&mut self, module: &[String], name: String, ty: BorshType) {
+        self.aliases.insert(
+            [module, &[name]].concat(),
+            Alias {
+                ty,
+                module: module.to_vec(),
+            },
+        );
+    }
+
+    /// Assigns a unique name to every layout and resolves their field types.
+    ///
+    /// Layouts sharing the same name are prefixed with the shortest part of
+    /// their module path that makes them unique.
+    pub fn resolve(mut self, mut layouts: Vec<Layout>) -> Result<Vec<Layout>, SchemaError> {
+        self.names = assign_names(&layouts)?;
+        for layout in &mut layouts {
+            for field in &mut layout.fields {
+                field.ty = self.resolve_type(&field.ty, &layout.module, &layout.generics);
+            }
+            let name = &self.names[&layout_path(layout)];
+            if *name != layout.name {
+                layout.rust_name = Some(std::mem::replace(&mut layout.name, name.clone()));
+            }
+        }
+        Ok(layouts)
+    }
+
+    fn resolve_type(&self, ty: &BorshType, module: &[String], generics: &[String]) -> BorshType {
+        ty.map(&|ty| match ty {
+            BorshType::Custom(path) if !generics.contains(path) => {
+                let resolved = self.resolve_path(module, &split_path(path), 0)?;
+                if let Some(name) = self.names.get(&resolved) {
+                    Some(BorshType::Custom(name.clone()))
+                } else {
+                    let alias = self.aliases.get(&resolved)?;
+                    Some(self.resolve_type(&alias.ty, &alias.module, &[]))
+                }
+            }
+            BorshType::Generic(path, args) => {
+                let name = self
+                    .resolve_path(module, &split_path(path), 0)
+                    .and_then(|resolved| self.names.get(&resolved))
+                    .unwrap_or(path);
+                Some(BorshType::Generic(
+                    name.clone(),
+                    args.iter()
+                        .map(|arg| self.resolve_type(arg, module, generics))
+                        .collect(),
+                ))
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the absolute path of the layout or alias `segments` refers to
+    /// when written in `module`.
+    fn resolve_path(
+        &self,
+        module: &[String],
+        segments: &[String],
+        depth: usize,
+    ) -> Option<Vec<String>> {
+        for candidate in self.candidates(module, segments, depth) {
+            if self.names.contains_key(&candidate) || self.aliases.contains_key(&candidate) {
+                return Some(candidate);
+            }
+            // the item might be re-exported by another module, e.g. via
+            // `pub use inner::Foo;`
+            if let Some((last, parent)) = candidate.split_last() {
+                if parent != module
+                    && depth < MAX_RESOLUTION_DEPTH
+                    && self.scopes.contains_key(parent)
+                {
+                    if let Some(resolved) =
+                        self.resolve_path(parent, std::slice::from_ref(last), depth + 1)
+                    {
+                        return Some(resolved);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the absolute paths `segments` may refer to when written in
+    /// `module`.
+    fn candidates(&self, module: &[String], segments: &[String], depth: usize) -> Vec<Vec<String>> {
+        let (first, rest) = match segments.split_first() {
+            Some(split) => split,
+            None => return Vec::new(),
+        };
+        match first.as_str() {
+            "crate" => vec![rest.to_vec()],
+            "self" => vec![[module, rest].concat()],
+            "super" => {
+                let mut base = module.to_vec();
+                let mut rest = segments;
+                while let Some((first, tail)) = rest.split_first() {
+                    if first != "super" {
+                        break;
+                    }
+                    if base.pop().is_none() {
+                        return Vec::new();
+                    }
+                    rest = tail;
+                }
+                vec![[&base, rest].concat()]
+            }
+            _ => {
+                let mut candidates = vec![[module, segments].concat()];
+                if depth >= MAX_RESOLUTION_DEPTH {
+                    return candidates;
+                }
+                let scope = self.scopes.get(module);
+                if let Some(import) = scope.and_then(|scope| scope.imports.get(first)) {
+                    for base in self.candidates(module, import, depth + 1) {
+                        candidates.push([&base, rest].concat());
+                    }
+                }
+                if rest.is_empty() {
+                    for glob in scope.iter().flat_map(|scope| &scope.globs) {
+                        for base in self.candidates(module, glob, depth + 1) {
+                            candidates.push([&base, segments].concat());
+                        }
+                    }
+                } else {
+                    // paths relative to the crate root
+                    candidates.push(segments.to_vec());
+                }
+                candidates
+            }
+        }
+    }
+}
+
+fn collect_use(scope: &mut Scope, prefix: Vec<String>, tree: &syn::UseTree) {
+    match tree {
+        syn::UseTree::Path(use_path) => {
+            let prefix = [prefix, vec![use_path.ident.to_string()]].concat();
+            collect_use(scope, prefix, &use_path.tree);
+        }
+        syn::UseTree::Name(use_name) => {
+            let name = use_name.ident.to_string();
+            if name == "self" {
+                if let Some(last) = prefix.last() {
+                    scope.imports.insert(last.clone(), prefix);
+                }
+            } else {
+                scope
+                    .imports
+                    .insert(name.clone(), [prefix, vec![name]].concat());
+            }
+        }
+        syn::UseTree::Rename(use_rename) => {
+            let name = use_rename.ident.to_string();
+            let path = if name == "self" {
+                prefix
+            } else {
+                [prefix, vec![name]].concat()
+            };
+            scope.imports.insert(use_rename.rename.to_string(), path);
+        }
+        syn::UseTree::Glob(_) => scope.globs.push(prefix),
+        syn::UseTree::Group(use_group) => {
+            for tree in &use_group.items {
+                collect_use(scope, prefix.clone(), tree);
+            }
+        }
+    }
+}
+
+fn split_path(path: &str) -> Vec<String> {
+    path.split("::").map(str::to_owned).collect()
+}
+
+fn layout_path(layout: &Layout) -> Vec<String> {
+    [&layout.module[..], std::slice::from_ref(&layout.name)].concat()
+}
+
+/// Maps the absolute path of every layout to the name used in the generated
+/// code.
+fn assign_names(layouts: &[Layout]) -> Result<HashMap<Vec<String>, String>, SchemaError> {
+    let mut sources = HashMap::<Vec<String>, &PathBuf>::new();
+    let mut groups = BTreeMap::<&str, Vec<&Layout>>::new();
+    for layout in layouts {
+        if let Some(source) = sources.insert(layout_path(layout), &layout.source) {
+            return Err(SchemaError::DuplicateDefinition {
+                path: layout.path(),
+                first: source.clone(),
+                second: layout.source.clone(),
+            });
+        }
+        groups.entry(&layout.name).or_default().push(layout);
+    }
+
+    let mut names = HashMap::<Vec<String>, String>::new();
+    let mut owners = BTreeMap::<String, &Layout>::new();
+    for group in groups.values() {
+        let max_depth = group
+            .iter()
+            .map(|layout| layout.module.len())
+            .max()
+            .unwrap_or(0);
+        let depth = (0..=max_depth)
+            .find(|&depth| {
+                let mut prefixed = group
+                    .iter()
+                    .map(|layout| prefixed_name(layout, depth))
+                    .collect::<Vec<_>>();
+                prefixed.sort();
+                prefixed.dedup();
+                prefixed.len() == group.len()
+            })
+            .unwrap_or(max_depth);
+        for layout in group {
+            let name = prefixed_name(layout, depth);
+            if let Some(owner) = owners.insert(name.clone(), layout) {
+                return Err(SchemaError::NameCollision {
+                    name,
+                    first: owner.path(),
+                    first_file: owner.source.clone(),
+                    second: layout.path(),
+                    second_file: layout.source.clone(),
+                });
+            }
+            names.insert(layout_path(layout), name);
+        }
+    }
+    Ok(names)
+}
+
+/// Prefixes the layout's name with the last `depth` segments of its module
+/// path, e.g. `state::State` becomes `StateState` with `depth = 1`.
+fn prefixed_name(layout: &Layout, depth: usize) -> String {
+    let start = layout.module.len().saturating_sub(depth);
+    layout.module[start..]
+        .iter()
+        .map(|segment| segment.to_camel_case())
+        .chain(std::iter::once(layout.name.clone()))
+        .collect()
+}
@@ -1,4 +1,4 @@
-use super::TEST_DATA_DIRECTORY;
+use super::{assert_decodes, TEST_DATA_DIRECTORY};
 use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::borsh::try_from_slice_unchecked;
@@ -8,9 +8,11 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::io::Write;
 
+pub type BTreeKey = [u8; 32];
+
 #[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
 pub struct BTreeWrapper {
-    map0: BTreeMap<[u8; 32], Pubkey>,
+    map0: BTreeMap<BTreeKey, Pubkey>,
     map1: BTreeMap<String, Option<u32>>,
     map2: BTreeMap<u16, String>,
 }
@@ -20,6 +22,9 @@ fn generate_layout_from_this_file() {
     let layouts = generate_layout_from_file("src/test/borsh_btree.rs").unwrap();
     assert_eq!(layouts.len(), 1);
     assert_eq!(layouts[0].name, "BTreeWrapper");
+    assert!(layouts[0]
+        .to_borsh_schema()
+        .contains("['map0', { kind: 'map', key: [32], value: 'publicKey' }]"));
 
     let mut btree0 = BTreeMap::<[u8; 32], Pubkey>::new();
     let mut id = [0; 32];
@@ -56,6 +61,10 @@ fn generate_layout_from_this_file() {
         map2: btree2,
     };
 
+    let decoded = assert_decodes(&layouts, "BTreeWrapper", &wrapper);
+    assert_eq!(decoded["map1"][3], serde_json::json!(["zello", 44]));
+    assert_eq!(decoded["map2"][0], serde_json::json!([168, "value"]));
+
     fs::create_dir_all(TEST_DATA_DIRECTORY).unwrap();
     let mut file =
         fs::File::create(String::from(TEST_DATA_DIRECTORY) + "/test_btree.json").unwrap();
@@ -0,0 +1,98 @@
+use super::{assert_decodes, TEST_DATA_DIRECTORY};
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::io::Write;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct BoxedState {
+    amount: u32,
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Debug, PartialEq)]
+pub struct CollectionWrapper {
+    map: HashMap<u8, String>,
+    hash_set: HashSet<u16>,
+    btree_set: BTreeSet<String>,
+    boxed: Box<BoxedState>,
+    pair: (u64, Pubkey),
+    pairs: Vec<(u8, Option<bool>)>,
+}
+
+#[test]
+fn generate_layout_from_this_file() {
+    let layouts = generate_layout_from_file("src/test/borsh_collections.rs").unwrap();
+    assert_eq!(layouts.len(), 4);
+    assert_eq!(layouts[0].name, "BoxedState");
+    assert_eq!(layouts[1].name, "TupleU64Pubkey");
+    assert_eq!(layouts[2].name, "TupleU8OptionBool");
+    assert_eq!(layouts[3].name, "CollectionWrapper");
+
+    let schema = layouts[3].to_borsh_schema();
+    assert!(schema.contains("['map', { kind: 'map', key: 'u8', value: 'string' }],"));
+    assert!(schema.contains("['hashSet', ['u16']],"));
+    assert!(schema.contains("['btreeSet', ['string']],"));
+    assert!(schema.contains("['boxed', BoxedState],"));
+    assert!(schema.contains("['pair', TupleU64Pubkey],"));
+    assert!(schema.contains("['pairs', [TupleU8OptionBool]],"));
+    assert!(layouts[2]
+        .to_borsh_schema()
+        .contains("['unnamed_0', 'u8'],\n\t\t\t['unnamed_1', { kind: 'option', type: 'u8' }],"));
+    // borsh-js deserializes sets as arrays and tuples as the structs of
+    // their layouts, which the constructor converts
+    let class = layouts[3].to_ts_class();
+    assert!(class.contains("    hashSet: Set<number>;\n"));
+    assert!(class.contains("    btreeSet: Set<string>;\n"));
+    assert!(class.contains("    pair: [BN, PublicKey];\n"));
+    assert!(class.contains("    pairs: [number, boolean | null][];\n"));
+    assert!(class.contains("        this.hashSet = new Set(this.hashSet);\n"));
+    assert!(class.contains(
+        "        this.pairs = this.pairs.map((item) => \
+        new TupleU8OptionBool(item) as [number, boolean | null]);\n"
+    ));
+    let tuple = layouts[1].to_ts_tuple_class();
+    assert!(tuple.contains("    constructor(fields: { 0: BN; 1: PublicKey }) {\n"));
+    assert!(tuple.contains(
+        "return Object.defineProperty([fields[0], fields[1]], \"constructor\", \
+        { value: TupleU64Pubkey });"
+    ));
+    assert!(layouts[1]
+        .to_tuple_borsh_schema()
+        .contains("['0', 'u64'],\n\t\t\t['1', 'publicKey'],"));
+
+    let mut map = HashMap::new();
+    map.insert(2, "two".to_string());
+    map.insert(1, "one".to_string());
+    let wrapper = CollectionWrapper {
+        map,
+        hash_set: [300, 5].into_iter().collect(),
+        btree_set: ["b".to_string(), "a".to_string()].into_iter().collect(),
+        boxed: Box::new(BoxedState { amount: 7 }),
+        pair: (10, Pubkey::new(&[3; 32])),
+        pairs: vec![(1, None), (2, Some(true))],
+    };
+    let serialized = wrapper.try_to_vec().unwrap();
+
+    // hash maps and sets are serialized in sorted order
+    let expected_prefix = [
+        2, 0, 0, 0, 1, 3, 0, 0, 0, 111, 110, 101, 2, 3, 0, 0, 0, 116, 119, 111, 2, 0, 0, 0, 5, 0,
+        44, 1, 2, 0, 0, 0, 1, 0, 0, 0, 97, 1, 0, 0, 0, 98, 7, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    assert_eq!(serialized[..expected_prefix.len()], expected_prefix);
+    assert_eq!(
+        CollectionWrapper::try_from_slice(&serialized).unwrap(),
+        wrapper
+    );
+
+    let decoded = assert_decodes(&layouts, "CollectionWrapper", &wrapper);
+    assert_eq!(decoded["hashSet"], serde_json::json!([5, 300]));
+    assert_eq!(decoded["boxed"]["amount"], 7);
+
+    fs::create_dir_all(TEST_DATA_DIRECTORY).unwrap();
+    let mut file =
+        fs::File::create(String::from(TEST_DATA_DIRECTORY) + "/test_collections.json").unwrap();
+    write!(file, "{:?}", serialized).unwrap();
+}
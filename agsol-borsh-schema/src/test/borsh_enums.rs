@@ -1,4 +1,4 @@
-use super::TEST_DATA_DIRECTORY;
+use super::{assert_decodes, TEST_DATA_DIRECTORY};
 use crate::*;
 
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -35,7 +35,11 @@ pub enum TestEnum {
     VariantD(Option<Pubkey>),
     VariantE(Option<u8>),
     VariantF(RandomStruct),
+    /// A variant with named fields.
+    ///
+    /// Its fields are documented */ as well.
     VariantG {
+        /// Arbitrary bytes.
         hello: Vec<u8>,
         bello: [Pubkey; 3],
         yello: u16,
@@ -43,18 +47,47 @@ pub enum TestEnum {
     },
 }
 
+/// A C-like enum serialized by its explicit discriminants.
+#[allow(dead_code)]
+#[derive(BorshSchema, Clone, Copy, Debug, PartialEq)]
+#[borsh(use_discriminant = true)]
+pub enum Status {
+    Inactive = 5,
+    Active = 10,
+    Frozen,
+}
+
+/// A C-like enum serialized by its variant indices, ignoring the explicit
+/// discriminants.
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+#[borsh(use_discriminant = false)]
+pub enum Priority {
+    Low = 10,
+    High = 20,
+}
+
+/// A C-like enum with explicit discriminants but without `use_discriminant`,
+/// which borsh serializes by the variant indices.
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Tier {
+    Basic = 3,
+    Premium = 7,
+}
+
 #[test]
 fn generate_layout_from_this_file() {
     let layouts = generate_layout_from_file("src/test/borsh_enums.rs").unwrap();
-    assert_eq!(layouts.len(), 9);
-    assert_eq!(layouts[0].name, "RandomStruct");
-    assert_eq!(layouts[1].name, "TestEnum");
-    assert_eq!(layouts[2].name, "TestEnumVariantA");
-    assert_eq!(layouts[3].name, "TestEnumVariantB");
-    assert_eq!(layouts[4].name, "TestEnumVariantC");
-    assert_eq!(layouts[5].name, "TestEnumVariantD");
-    assert_eq!(layouts[6].name, "TestEnumVariantE");
-    assert_eq!(layouts[7].name, "TestEnumVariantF");
+    assert_eq!(layouts.len(), 19);
+    // variants come before the enum, as the enum layout depends on them
+    assert_eq!(layouts[3].name, "RandomStruct");
+    assert_eq!(layouts[8].name, "TestEnumVariantA");
+    assert_eq!(layouts[9].name, "TestEnumVariantB");
+    assert_eq!(layouts[10].name, "TestEnumVariantC");
+    assert_eq!(layouts[11].name, "TestEnumVariantD");
+    assert_eq!(layouts[12].name, "TestEnumVariantE");
+    assert_eq!(layouts[13].name, "TestEnumVariantF");
+    assert_eq!(layouts[14].name, "TestEnumVariantG");
+    assert_eq!(layouts[15].name, "TestEnum");
 
     let mut pubkey_array = [0; 32];
     pubkey_array[31] = 12;
@@ -82,6 +115,23 @@ fn generate_layout_from_this_file() {
         zello: false,
     };
 
+    for variant in [
+        &enum_variant_a,
+        &enum_variant_b,
+        &enum_variant_c,
+        &enum_variant_d,
+        &enum_variant_e,
+        &enum_variant_f,
+    ] {
+        assert_decodes(&layouts, "TestEnum", variant);
+    }
+    let decoded = assert_decodes(&layouts, "TestEnum", &enum_variant_g);
+    assert_eq!(
+        decoded["VariantG"]["hello"],
+        serde_json::json!([1, 2, 3, 4, 5])
+    );
+    assert_eq!(decoded["VariantG"]["yello"], 234);
+
     let test_data = TestData {
         enum_variant_a: enum_variant_a.try_to_vec().unwrap(),
         enum_variant_b: enum_variant_b.try_to_vec().unwrap(),
@@ -97,3 +147,83 @@ fn generate_layout_from_this_file() {
         fs::File::create(String::from(TEST_DATA_DIRECTORY) + "/test_enums.json").unwrap();
     write!(file, "{}", serde_json::to_string(&test_data).unwrap()).unwrap();
 }
+
+#[test]
+fn generate_c_like_enum_layouts() {
+    let layouts = generate_layout_from_file("src/test/borsh_enums.rs").unwrap();
+    let find = |name: &str| layouts.iter().find(|layout| layout.name == name).unwrap();
+
+    let status = find("Status");
+    let discriminants = status
+        .fields
+        .iter()
+        .map(|field| field.discriminant)
+        .collect::<Vec<_>>();
+    assert_eq!(discriminants, vec![Some(5), Some(10), Some(11)]);
+    // the gaps are filled, so that every variant is found at the index of its
+    // discriminant
+    let schema = status.to_borsh_schema();
+    assert!(schema.contains(
+        "['unused3', 'u8'],\n\t\t\t\
+        ['unused4', 'u8'],\n\t\t\t\
+        ['statusInactive', StatusInactive],\n\t\t\t\
+        ['unused6', 'u8'],"
+    ));
+    assert!(
+        schema.contains("['statusActive', StatusActive],\n\t\t\t['statusFrozen', StatusFrozen],\n")
+    );
+    assert_eq!(schema.matches("\n\t\t\t[").count(), 12);
+    // borsh 0.9 can't serialize the discriminants, so the layout is checked
+    // against the bytes borsh 1.0 would write
+    for (data, variant) in [([5], "Inactive"), ([10], "Active"), ([11], "Frozen")] {
+        let decoded = decode(&layouts, "Status", &data).unwrap();
+        assert!(
+            decoded.get(variant).is_some(),
+            "{} isn't {}",
+            decoded,
+            variant
+        );
+        assert_eq!(encode(&layouts, "Status", &decoded).unwrap(), data);
+    }
+    assert!(status
+        .to_py_class()
+        .contains("(\"Frozen\", LazyBound(lambda: STATUS_FROZEN_LAYOUT), 11),"));
+
+    // borsh ignores the explicit discriminants, so the indices are used
+    let priority = find("Priority");
+    assert!(priority
+        .fields
+        .iter()
+        .all(|field| field.discriminant.is_none()));
+    assert!(priority.to_borsh_schema().contains(
+        "values: [\n\t\t\t['priorityLow', PriorityLow],\n\t\t\t['priorityHigh', PriorityHigh],\n"
+    ));
+    assert_eq!(Priority::High.try_to_vec().unwrap(), vec![1]);
+
+    // without `use_discriminant` the indices are used as well
+    let tier = find("Tier");
+    assert!(tier.fields.iter().all(|field| field.discriminant.is_none()));
+    assert_eq!(Tier::Premium.try_to_vec().unwrap(), vec![1]);
+    assert_decodes(&layouts, "Tier", &Tier::Premium);
+}
+
+#[cfg(feature = "derived-layouts")]
+#[test]
+fn derive_c_like_enum_layouts() {
+    let layouts = generate_layout_from_file("src/test/borsh_enums.rs").unwrap();
+    let find = |name: &str| layouts.iter().find(|layout| layout.name == name).unwrap();
+
+    // derived layouts carry the same discriminants
+    let derived = collect_layouts(&[
+        Status::add_layouts,
+        Priority::add_layouts,
+        Tier::add_layouts,
+    ])
+    .unwrap();
+    for layout in &derived {
+        assert_eq!(
+            layout.to_borsh_schema(),
+            find(&layout.name).to_borsh_schema()
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use super::{assert_decodes, TEST_DATA_DIRECTORY};
+use crate::*;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+use solana_program::pubkey::Pubkey;
+
+use std::fs;
+use std::io::Write;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TestData {
+    generic_container_left: Vec<u8>,
+    generic_container_right: Vec<u8>,
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct Wrapper<T> {
+    inner: T,
+    history: Vec<T>,
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct GenericContainer {
+    amount: Wrapper<u64>,
+    owner: Wrapper<Pubkey>,
+    choice: Either<u8, Wrapper<String>>,
+}
+
+#[test]
+fn generate_layout_from_this_file() {
+    let layouts = generate_layout_from_file("src/test/borsh_generics.rs").unwrap();
+    assert_eq!(layouts.len(), 7);
+    assert_eq!(layouts[0].name, "EitherLeftU8WrapperString");
+    assert_eq!(layouts[1].name, "WrapperPubkey");
+    assert_eq!(layouts[2].name, "WrapperString");
+    assert_eq!(layouts[3].name, "EitherRightU8WrapperString");
+    assert_eq!(layouts[4].name, "EitherU8WrapperString");
+    assert_eq!(layouts[5].name, "WrapperU64");
+    assert_eq!(layouts[6].name, "GenericContainer");
+    assert!(layouts.iter().all(|layout| layout.generics.is_empty()));
+
+    assert!(layouts[5].to_borsh_schema().contains("['inner', 'u64']"));
+    assert!(layouts[5]
+        .to_borsh_schema()
+        .contains("['history', ['u64']]"));
+    assert!(layouts[4]
+        .to_borsh_schema()
+        .contains("['eitherLeft', EitherLeftU8WrapperString]"));
+    assert!(layouts[3]
+        .to_borsh_schema()
+        .contains("['unnamed_0', WrapperString]"));
+    assert!(layouts[6]
+        .to_borsh_schema()
+        .contains("['choice', EitherU8WrapperString]"));
+
+    let mut pubkey_array = [0; 32];
+    pubkey_array[0] = 42;
+    let pubkey = Pubkey::new(&pubkey_array);
+
+    let generic_container_left = GenericContainer {
+        amount: Wrapper {
+            inner: 1_000_000,
+            history: vec![10, 20, 30],
+        },
+        owner: Wrapper {
+            inner: pubkey,
+            history: vec![],
+        },
+        choice: Either::Left(5),
+    };
+
+    let generic_container_right = GenericContainer {
+        amount: Wrapper {
+            inner: 0,
+            history: vec![],
+        },
+        owner: Wrapper {
+            inner: pubkey,
+            history: vec![pubkey],
+        },
+        choice: Either::Right(Wrapper {
+            inner: "hello".to_string(),
+            history: vec!["bello".to_string()],
+        }),
+    };
+
+    let decoded = assert_decodes(&layouts, "GenericContainer", &generic_container_left);
+    assert_eq!(decoded["choice"]["Left"]["unnamed_0"], 5);
+    let decoded = assert_decodes(&layouts, "GenericContainer", &generic_container_right);
+    assert_eq!(
+        decoded["choice"]["Right"]["unnamed_0"]["history"][0],
+        "bello"
+    );
+
+    let test_data = TestData {
+        generic_container_left: generic_container_left.try_to_vec().unwrap(),
+        generic_container_right: generic_container_right.try_to_vec().unwrap(),
+    };
+
+    fs::create_dir_all(TEST_DATA_DIRECTORY).unwrap();
+    let mut file =
+        fs::File::create(String::from(TEST_DATA_DIRECTORY) + "/test_generics.json").unwrap();
+    write!(file, "{}", serde_json::to_string(&test_data).unwrap()).unwrap();
+}
@@ -1,4 +1,5 @@
-use super::TEST_DATA_DIRECTORY;
+use super::borsh_btree::BTreeKey;
+use super::{assert_decodes, TEST_DATA_DIRECTORY};
 use crate::*;
 
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -13,6 +14,9 @@ struct TestData {
     test_struct_some: Vec<u8>,
     test_struct_none: Vec<u8>,
     tuple_struct: Vec<u8>,
+    aliased_struct: Vec<u8>,
+    signed_struct_positive: Vec<u8>,
+    signed_struct_negative: Vec<u8>,
 }
 
 type UnixTimestamp = i64;
@@ -31,24 +35,62 @@ pub struct TestStruct {
     skipped_field: Option<u32>,
 }
 
+/// Amount held at a given time.
 #[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug)]
 #[cfg_attr(test, derive(BorshSchema))]
 pub struct OtherState {
+    /// The amount in lamports.
     #[alias(u64)]
     amount: Amount,
+    /// Unix timestamp in seconds.
     timestamp: UnixTimestamp,
 }
 
 #[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Debug)]
 pub struct TupleStruct(u8, pub i32, pub OtherState);
 
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct AliasedStruct {
+    pool: StatePool,
+    amounts: Vec<Amount>,
+    #[alias(u64)]
+    overridden: Amount,
+    key: BTreeKey,
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct SignedStruct {
+    field_a: i8,
+    field_b: i16,
+    field_c: i32,
+    field_d: i64,
+    field_e: i128,
+}
+
 #[test]
 fn generate_layout_from_this_file() {
     let layouts = generate_layout_from_file("src/test/borsh_structs.rs").unwrap();
-    assert_eq!(layouts.len(), 3);
-    assert_eq!(layouts[0].name, "TestStruct");
-    assert_eq!(layouts[1].name, "OtherState");
-    assert_eq!(layouts[2].name, "TupleStruct");
+    assert_eq!(layouts.len(), 5);
+    assert_eq!(layouts[0].name, "OtherState");
+    assert_eq!(layouts[1].name, "AliasedStruct");
+    assert_eq!(layouts[2].name, "SignedStruct");
+    assert_eq!(layouts[3].name, "TestStruct");
+    assert_eq!(layouts[4].name, "TupleStruct");
+    assert!(layouts[0]
+        .to_borsh_schema()
+        .contains("['timestamp', 'i64']"));
+    let aliased_schema = layouts[1].to_borsh_schema();
+    assert!(aliased_schema.contains("['pool', { kind: 'option', type: [OtherState] }]"));
+    assert!(aliased_schema.contains("['amounts', ['u64']]"));
+    assert!(aliased_schema.contains("['overridden', 'u64']"));
+    // the alias is defined in another file, so it is resolved only when the
+    // whole directory is parsed
+    assert!(aliased_schema.contains("['key', BTreeKey]"));
+    assert!(layouts[2].to_borsh_schema().contains("['fieldA', 'i8']"));
+    assert!(layouts[2].to_borsh_schema().contains("['fieldB', 'i16']"));
+    assert!(layouts[2].to_borsh_schema().contains("['fieldC', 'i32']"));
+    assert!(layouts[2].to_borsh_schema().contains("['fieldD', 'i64']"));
+    assert!(layouts[2].to_borsh_schema().contains("['fieldE', 'i128']"));
 
     let test_struct_none = TestStruct {
         field_a: 45678910,
@@ -81,10 +123,61 @@ fn generate_layout_from_this_file() {
 
     let tuple_struct = TupleStruct(10, -2, other_state_one);
 
+    let mut key = [0; 32];
+    key[0] = 7;
+    let aliased_struct = AliasedStruct {
+        pool: Some(vec![other_state_two]),
+        amounts: vec![1, 2, 3],
+        overridden: 4,
+        key,
+    };
+
+    let signed_struct_positive = SignedStruct {
+        field_a: 100,
+        field_b: 30_000,
+        field_c: 2_000_000_000,
+        field_d: 9_000_000_000,
+        field_e: 170_000_000_000_000_000_000,
+    };
+
+    let signed_struct_negative = SignedStruct {
+        field_a: -100,
+        field_b: -30_000,
+        field_c: -2_000_000_000,
+        field_d: -1_234_567_890,
+        field_e: -170_000_000_000_000_000_000,
+    };
+
+    let signed_negative_bytes = signed_struct_negative.try_to_vec().unwrap();
+    assert_eq!(
+        &signed_negative_bytes[..7],
+        &[156, 208, 138, 0, 108, 202, 136]
+    );
+    let signed_struct_de = SignedStruct::try_from_slice(&signed_negative_bytes).unwrap();
+    assert_eq!(signed_struct_de.field_d, signed_struct_negative.field_d);
+    assert_eq!(signed_struct_de.field_e, signed_struct_negative.field_e);
+
+    let decoded = assert_decodes(&layouts, "TestStruct", &test_struct_some);
+    assert_eq!(decoded["fieldC"][2]["amount"], 3_000_000_000_u64);
+    let decoded = assert_decodes(&layouts, "TestStruct", &test_struct_none);
+    assert_eq!(decoded["fieldC"], serde_json::Value::Null);
+    let decoded = assert_decodes(&layouts, "TupleStruct", &tuple_struct);
+    assert_eq!(decoded["unnamed_1"], -2);
+    let decoded = assert_decodes(&layouts, "SignedStruct", &signed_struct_negative);
+    assert_eq!(decoded["fieldE"], "-170000000000000000000");
+    assert_decodes(&layouts, "SignedStruct", &signed_struct_positive);
+    // `BTreeKey` is defined in another file
+    let directory_layouts = generate_layouts("src/test").unwrap();
+    let decoded = assert_decodes(&directory_layouts, "AliasedStruct", &aliased_struct);
+    assert_eq!(decoded["key"][0], 7);
+
     let test_data = TestData {
         test_struct_some: test_struct_some.try_to_vec().unwrap(),
         test_struct_none: test_struct_none.try_to_vec().unwrap(),
         tuple_struct: tuple_struct.try_to_vec().unwrap(),
+        aliased_struct: aliased_struct.try_to_vec().unwrap(),
+        signed_struct_positive: signed_struct_positive.try_to_vec().unwrap(),
+        signed_struct_negative: signed_struct_negative.try_to_vec().unwrap(),
     };
 
     fs::create_dir_all(TEST_DATA_DIRECTORY).unwrap();
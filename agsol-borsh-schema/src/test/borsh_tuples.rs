@@ -0,0 +1,94 @@
+use super::assert_decodes;
+use crate::*;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct Pair(u64, Pubkey);
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub enum TupleInstruction {
+    Reset,
+    Deposit(u64),
+    Transfer(Pubkey, u64),
+    Swap(Pair),
+    Skipped(#[schema_skip] u8),
+    Close { refund: bool },
+}
+
+#[test]
+fn generate_layout_from_this_file() {
+    let layouts = generate_layout_from_file("src/test/borsh_tuples.rs").unwrap();
+    assert_eq!(layouts.len(), 8);
+    assert!(layouts[0]
+        .to_borsh_schema()
+        .contains("['unnamed_0', 'u64'],\n\t\t\t['unnamed_1', 'publicKey'],"));
+    assert_eq!(layouts[2].name, "TupleInstructionDeposit");
+    assert!(layouts[2]
+        .to_borsh_schema()
+        .contains("['unnamed_0', 'u64'],"));
+}
+
+#[test]
+fn generate_layout_with_options() {
+    let options = LayoutOptions {
+        positional_prefix: "field".to_owned(),
+        inline_newtype_variants: true,
+        ..LayoutOptions::default()
+    };
+    let layouts =
+        generate_layout_from_file_with_options("src/test/borsh_tuples.rs", &options).unwrap();
+    let names = layouts
+        .iter()
+        .map(|layout| layout.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "Pair",
+            "TupleInstructionClose",
+            "TupleInstructionReset",
+            "TupleInstructionSkipped",
+            "TupleInstructionTransfer",
+            "TupleInstruction",
+        ]
+    );
+    assert!(layouts[0]
+        .to_borsh_schema()
+        .contains("['field0', 'u64'],\n\t\t\t['field1', 'publicKey'],"));
+    // newtype variants are inlined, other variants keep their own layout
+    let schema = layouts[5].to_borsh_schema();
+    assert!(schema.contains("['tupleInstructionReset', TupleInstructionReset],"));
+    assert!(schema.contains("['tupleInstructionDeposit', 'u64'],"));
+    assert!(schema.contains("['tupleInstructionTransfer', TupleInstructionTransfer],"));
+    assert!(schema.contains("['tupleInstructionSwap', Pair],"));
+    assert!(schema.contains("['tupleInstructionSkipped', TupleInstructionSkipped],"));
+    assert!(layouts[5]
+        .to_ts_class()
+        .contains("tupleInstructionDeposit: BN;"));
+    assert!(layouts[4]
+        .to_borsh_schema()
+        .contains("['field0', 'publicKey'],\n\t\t\t['field1', 'u64'],"));
+
+    let union = layouts[5].to_ts_union(&layouts);
+    assert!(union.contains(r#"| { kind: "Reset" }"#));
+    assert!(union.contains(r#"| { kind: "Deposit"; value: BN }"#));
+    assert!(union.contains(r#"| { kind: "Swap"; value: Pair }"#));
+
+    // the layout matches the borsh serialization of the inlined variant
+    let data = TupleInstruction::Deposit(1_000).try_to_vec().unwrap();
+    assert_eq!(data[0], 1);
+    assert_eq!(data[1..], 1_000u64.to_le_bytes());
+    let decoded = assert_decodes(
+        &layouts,
+        "TupleInstruction",
+        &TupleInstruction::Deposit(1_000),
+    );
+    assert_eq!(decoded, serde_json::json!({ "Deposit": 1_000 }));
+    let swap = TupleInstruction::Swap(Pair(5, Pubkey::new_from_array([1; 32])));
+    assert_eq!(
+        assert_decodes(&layouts, "TupleInstruction", &swap)["Swap"]["field0"],
+        5
+    );
+}
@@ -0,0 +1,134 @@
+use super::{assert_decodes, TEST_DATA_DIRECTORY};
+use crate::*;
+
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+
+/// Same serialized layout as `LockedItem<u64>` in `agsol-common`.
+#[derive(BorshSerialize)]
+struct LockedItem {
+    item: u64,
+    expires: i64,
+}
+
+/// Same serialized layout as the `Guild` fixture with bounded containers.
+#[derive(BorshSerialize)]
+struct Guild {
+    name: String,
+    admins: Vec<Pubkey>,
+    members: Vec<Pubkey>,
+    roles: BTreeMap<u8, String>,
+    locked_funds: Vec<LockedItem>,
+    last_lock: Option<LockedItem>,
+}
+
+/// Same serialized layout as the `Treasury` fixture.
+#[derive(BorshSerialize)]
+struct Treasury {
+    guild: Pubkey,
+    balance: u64,
+    spenders: Vec<Pubkey>,
+    last_lock: Option<LockedItem>,
+}
+
+#[test]
+fn bounded_containers() {
+    let layouts = generate_layouts("test-fixtures/bounded").unwrap();
+    let names = layouts
+        .iter()
+        .map(|layout| layout.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["LockedItemU64", "Guild", "Treasury"]);
+
+    let schema = layouts[1].to_borsh_schema();
+    assert!(schema.contains("['name', 'string'],"));
+    assert!(schema.contains("['admins', ['publicKey']],"));
+    assert!(schema.contains("['members', ['publicKey']],"));
+    assert!(schema.contains("['roles', { kind: 'map', key: 'u8', value: 'string' }],"));
+    assert!(schema.contains("['lockedFunds', [LockedItemU64]],"));
+    assert!(schema.contains("['lastLock', { kind: 'option', type: LockedItemU64 }],"));
+    assert!(layouts[0]
+        .to_borsh_schema()
+        .contains("['item', 'u64'],\n\t\t\t['expires', 'i64'],"));
+
+    // the bound of `members` is not an integer literal
+    assert_eq!(
+        layouts[1].to_ts_bound_constants(),
+        "export const GUILD_NAME_MAX_LEN = 32;\n\
+        export const GUILD_ADMINS_MAX_LEN = 8;\n\
+        export const GUILD_ROLES_MAX_LEN = 4;\n\
+        export const GUILD_LOCKED_FUNDS_MAX_LEN = 10;\n\n"
+    );
+
+    // the size of `Guild` is unknown because of `members`
+    assert!(layouts[1].derives_max_len);
+    assert!(layouts[1]
+        .to_ts_account_constants(&layouts, true)
+        .contains("GUILD_DISCRIMINATOR"));
+    assert!(!layouts[1]
+        .to_ts_account_constants(&layouts, true)
+        .contains("GUILD_MAX_SERIALIZED_LEN"));
+    assert!(!layouts[0].derives_max_len);
+    assert!(layouts[0]
+        .to_ts_account_constants(&layouts, true)
+        .is_empty());
+    assert_eq!(
+        layouts[2].to_ts_account_constants(&layouts, true),
+        "export const TREASURY_MAX_SERIALIZED_LEN = 189;\n\
+        export const TREASURY_DISCRIMINATOR = Buffer.from([238, 239, 123, 238, 89, 1, 168, 253]);\n\n"
+    );
+    assert_eq!(
+        layouts[2].to_ts_account_constants(&layouts, false),
+        "export const TREASURY_MAX_SERIALIZED_LEN = 189;\n\n"
+    );
+    let treasury = Treasury {
+        guild: Pubkey::new(&[1; 32]),
+        balance: u64::MAX,
+        spenders: vec![Pubkey::new(&[2; 32]); 4],
+        last_lock: Some(LockedItem {
+            item: 100,
+            expires: 1_650_000_000,
+        }),
+    };
+    assert_eq!(treasury.try_to_vec().unwrap().len(), 189);
+
+    let mut roles = BTreeMap::new();
+    roles.insert(0, "owner".to_string());
+    roles.insert(1, "member".to_string());
+    let guild = Guild {
+        name: "agora".to_string(),
+        admins: vec![Pubkey::new(&[1; 32])],
+        members: vec![Pubkey::new(&[2; 32]), Pubkey::new(&[3; 32])],
+        roles,
+        locked_funds: vec![LockedItem {
+            item: 100,
+            expires: 1_650_000_000,
+        }],
+        last_lock: Some(LockedItem {
+            item: 200,
+            expires: -1,
+        }),
+    };
+
+    let decoded = assert_decodes(&layouts, "Guild", &guild);
+    assert_eq!(decoded["roles"][1], serde_json::json!([1, "member"]));
+    assert_eq!(decoded["lastLock"]["expires"], -1);
+    assert_decodes(&layouts, "Treasury", &treasury);
+
+    fs::create_dir_all(TEST_DATA_DIRECTORY).unwrap();
+    let mut file =
+        fs::File::create(String::from(TEST_DATA_DIRECTORY) + "/test_bounded.json").unwrap();
+    write!(file, "{:?}", guild.try_to_vec().unwrap()).unwrap();
+
+    let options = OutputOptions {
+        self_contained: true,
+        bound_constants: true,
+        account_discriminators: true,
+        ..OutputOptions::default()
+    };
+    generate_output_with_options(&layouts, "test-output/bounded", &options).unwrap();
+}
@@ -0,0 +1,82 @@
+use crate::*;
+
+fn names(layouts: &[Layout]) -> Vec<&str> {
+    let mut names = layouts
+        .iter()
+        .map(|layout| layout.name.as_str())
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names
+}
+
+fn find<'a>(layouts: &'a [Layout], name: &str) -> &'a Layout {
+    layouts.iter().find(|layout| layout.name == name).unwrap()
+}
+
+#[test]
+fn cfg_without_features() {
+    let layouts = generate_layouts("test-fixtures/cfg").unwrap();
+    // test-only and feature-gated items and modules are left out
+    assert_eq!(
+        names(&layouts),
+        vec![
+            "Account",
+            "Instruction",
+            "InstructionClose",
+            "InstructionInitialize",
+            "ProgramConfig",
+        ]
+    );
+
+    let account = find(&layouts, "Account").to_borsh_schema();
+    assert!(account.contains(
+        "kind: 'struct', fields: [\n\t\t\t['owner', 'publicKey'],\n\t\t\t['balance', 'u64'],\n"
+    ));
+    // fields skipped by borsh are not expected on the wire
+    let account_class = find(&layouts, "Account").to_ts_class();
+    assert!(!account_class.contains("cachedBalance"));
+    assert!(!account_class.contains("cachedOwner"));
+    assert!(!account_class.contains("metadata"));
+
+    let instruction = find(&layouts, "Instruction").to_borsh_schema();
+    assert!(!instruction.contains("SetAdmin"));
+    let close = find(&layouts, "InstructionClose").to_borsh_schema();
+    assert!(close.contains("fields: [\n\t\t\t['refund', 'u8'],\n"));
+}
+
+#[test]
+fn cfg_with_features() {
+    let options = LayoutOptions {
+        features: vec![
+            "client".to_owned(),
+            "metadata".to_owned(),
+            "admin".to_owned(),
+        ],
+        ..LayoutOptions::default()
+    };
+    let layouts = generate_layouts_with_options("test-fixtures/cfg", &options).unwrap();
+    assert_eq!(
+        names(&layouts),
+        vec![
+            "Account",
+            "ClientConfig",
+            "Instruction",
+            "InstructionClose",
+            "InstructionInitialize",
+            "InstructionSetAdmin",
+        ]
+    );
+    assert_eq!(find(&layouts, "ClientConfig").module, ["client"]);
+
+    let account = find(&layouts, "Account").to_borsh_schema();
+    assert!(account.contains("['metadata', 'string'],\n\t\t\t['balance', 'u64'],"));
+    // variants keep their order
+    let instruction = find(&layouts, "Instruction").to_borsh_schema();
+    assert!(instruction.contains(
+        "['instructionInitialize', InstructionInitialize],\n\t\t\t\
+        ['instructionSetAdmin', InstructionSetAdmin],\n\t\t\t\
+        ['instructionClose', InstructionClose],"
+    ));
+    let close = find(&layouts, "InstructionClose").to_borsh_schema();
+    assert!(close.contains("['reason', 'string'],\n\t\t\t['refund', 'u8'],"));
+}
@@ -0,0 +1,123 @@
+use super::borsh_collections::CollectionWrapper;
+use super::borsh_enums::TestEnum;
+use super::borsh_generics::GenericContainer;
+use super::borsh_structs::{AliasedStruct, SignedStruct, TestStruct, TupleStruct};
+use super::borsh_tuples::TupleInstruction;
+use crate::*;
+
+use agsol_common::{LockedItem, MaxLenBTreeMap, MaxLenString, MaxLenVec, Timelock};
+use solana_program::pubkey::Pubkey;
+
+// same fields as the `Guild` fixture with bounded containers
+#[allow(dead_code)]
+#[derive(BorshSchema)]
+struct Guild {
+    name: MaxLenString<32>,
+    admins: MaxLenVec<Pubkey, 8>,
+    members: MaxLenVec<Pubkey, 64>,
+    roles: MaxLenBTreeMap<u8, MaxLenString<16>, 4>,
+    locked_funds: Timelock<u64, 10>,
+    last_lock: Option<LockedItem<u64>>,
+}
+
+#[test]
+fn derived_layouts_match_parsed_layouts() {
+    let derived = collect_layouts(&[
+        CollectionWrapper::add_layouts,
+        TestEnum::add_layouts,
+        GenericContainer::add_layouts,
+        TestStruct::add_layouts,
+        AliasedStruct::add_layouts,
+        SignedStruct::add_layouts,
+        TupleStruct::add_layouts,
+        TupleInstruction::add_layouts,
+    ])
+    .unwrap();
+    let parsed = generate_layouts("src/test").unwrap();
+
+    for layout in &derived {
+        let parsed_layout = parsed
+            .iter()
+            .find(|parsed_layout| parsed_layout.name == layout.name)
+            .unwrap_or_else(|| panic!("{} is not parsed", layout.name));
+        assert_eq!(layout.to_ts_class(), parsed_layout.to_ts_class());
+        assert_eq!(layout.to_borsh_schema(), parsed_layout.to_borsh_schema());
+        // module paths are relative to the crate root, while generated tuple
+        // layouts belong to the root module
+        if let Some(stem) = layout.source.file_stem() {
+            assert_eq!(layout.module, ["test", stem.to_str().unwrap()]);
+        } else {
+            assert!(layout.module.is_empty());
+        }
+    }
+    // the derived layouts are sorted like the parsed ones
+    let position = |name: &str| derived.iter().position(|layout| layout.name == name);
+    assert!(position("OtherState") < position("AliasedStruct"));
+    assert!(position("WrapperU64") < position("GenericContainer"));
+    assert!(position("TupleU64Pubkey") < position("CollectionWrapper"));
+    assert_eq!(derived.len(), 33);
+    assert!(schema_warnings(&derived).is_empty());
+}
+
+#[test]
+fn derived_bounded_containers() {
+    let derived = collect_layouts(&[Guild::add_layouts]).unwrap();
+    let parsed = generate_layouts("test-fixtures/bounded").unwrap();
+    let names = derived
+        .iter()
+        .map(|layout| layout.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["LockedItemU64", "Guild"]);
+    for (layout, parsed_layout) in derived.iter().zip(&parsed) {
+        assert_eq!(layout.to_ts_class(), parsed_layout.to_ts_class());
+        assert_eq!(layout.to_borsh_schema(), parsed_layout.to_borsh_schema());
+    }
+    // unlike the parsed constant, the derived bound is known
+    assert_eq!(
+        derived[1].fields[2].ty,
+        BorshType::Bounded(
+            Box::new(BorshType::Vec(Box::new(BorshType::Pubkey))),
+            Some(64)
+        )
+    );
+}
+
+#[test]
+fn derived_name_collision() {
+    // declared in the function, so that the parsed layouts don't change
+    mod first {
+        #[allow(dead_code)]
+        #[derive(crate::BorshSchema)]
+        pub struct Config {
+            threshold: u8,
+        }
+    }
+
+    mod second {
+        #[allow(dead_code)]
+        #[derive(crate::BorshSchema)]
+        pub struct Config {
+            threshold: u16,
+        }
+    }
+
+    // the same type is only added once
+    let derived = collect_layouts(&[first::Config::add_layouts, first::Config::add_layouts]);
+    assert_eq!(derived.unwrap().len(), 1);
+
+    let err =
+        collect_layouts(&[first::Config::add_layouts, second::Config::add_layouts]).unwrap_err();
+    match err {
+        SchemaError::NameCollision {
+            name,
+            first,
+            second,
+            ..
+        } => {
+            assert_eq!(name, "Config");
+            assert_eq!(first, "test::derived_layouts::first::Config");
+            assert_eq!(second, "test::derived_layouts::second::Config");
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+}
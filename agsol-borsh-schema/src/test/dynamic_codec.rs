@@ -0,0 +1,127 @@
+use crate::*;
+
+use borsh::BorshSerialize;
+use serde_json::json;
+use solana_program::pubkey::Pubkey;
+
+use std::collections::BTreeMap;
+
+#[allow(dead_code)]
+#[derive(BorshSchema, BorshSerialize)]
+pub struct Vault {
+    owner: Pubkey,
+    balance: u64,
+    supply: u128,
+    delta: i128,
+    label: String,
+    history: Vec<Entry>,
+    limits: BTreeMap<u8, i32>,
+    seed: [u8; 4],
+    pair: (u16, bool),
+    note: Option<String>,
+    #[schema_skip]
+    #[borsh_skip]
+    cache: Vec<u8>,
+}
+
+#[derive(BorshSchema, BorshSerialize)]
+pub enum Entry {
+    Deposit(u64),
+    Withdraw { amount: u64, fee: Option<u16> },
+    Close,
+}
+
+fn vault() -> Vault {
+    Vault {
+        owner: Pubkey::new_from_array([7; 32]),
+        balance: u64::MAX,
+        supply: u128::MAX,
+        delta: -5,
+        label: "main".to_owned(),
+        history: vec![
+            Entry::Deposit(100),
+            Entry::Withdraw {
+                amount: 40,
+                fee: Some(2),
+            },
+            Entry::Close,
+        ],
+        limits: vec![(1, -10), (2, 20)].into_iter().collect(),
+        seed: [1, 2, 3, 4],
+        pair: (513, true),
+        note: None,
+        cache: vec![0; 8],
+    }
+}
+
+#[test]
+fn decode_serialized_data() {
+    let layouts = generate_layout_from_file("src/test/dynamic_codec.rs").unwrap();
+    let data = vault().try_to_vec().unwrap();
+    let value = decode(&layouts, "Vault", &data).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "owner": Pubkey::new_from_array([7; 32]).to_string(),
+            "balance": u64::MAX,
+            "supply": u128::MAX.to_string(),
+            "delta": "-5",
+            "label": "main",
+            "history": [
+                { "Deposit": { "unnamed_0": 100 } },
+                { "Withdraw": { "amount": 40, "fee": 2 } },
+                { "Close": {} },
+            ],
+            "limits": [[1, -10], [2, 20]],
+            "seed": [1, 2, 3, 4],
+            "pair": [513, true],
+            "note": null,
+        })
+    );
+    assert_eq!(encode(&layouts, "Vault", &value).unwrap(), data);
+
+    // roots can refer to the layouts
+    let history = vault().history.try_to_vec().unwrap();
+    let value = decode(&layouts, "Vec<Entry>", &history).unwrap();
+    assert_eq!(value[2], json!({ "Close": {} }));
+    assert_eq!(encode(&layouts, "Vec<Entry>", &value).unwrap(), history);
+}
+
+#[test]
+fn decode_errors() {
+    let layouts = generate_layout_from_file("src/test/dynamic_codec.rs").unwrap();
+    let data = vault().try_to_vec().unwrap();
+
+    // truncated in the middle of the `Withdraw` variant
+    let error = decode(&layouts, "Vault", &data[..104]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "failed to decode `Vault.history[1].Withdraw.fee` at byte offset 103: expected 2 bytes, found 1"
+    );
+
+    let mut invalid = data.clone();
+    invalid[105] = 3;
+    let error = decode(&layouts, "Vault", &invalid).unwrap_err();
+    assert_eq!(error.offset, 105);
+    assert_eq!(error.message, "invalid variant 3 of `Entry`");
+
+    let mut padded = data.clone();
+    padded.extend_from_slice(&[0; 10]);
+    let error = decode(&layouts, "Vault", &padded).unwrap_err();
+    assert_eq!(error.offset, data.len());
+    assert_eq!(
+        decode_prefix(&layouts, "Vault", &padded).unwrap(),
+        (decode(&layouts, "Vault", &data).unwrap(), data.len())
+    );
+
+    let error = decode(&layouts, "Missing", &data).unwrap_err();
+    assert_eq!(error.message, "type `Missing` is not defined");
+
+    let error = encode(&layouts, "Entry", &json!({ "Withdraw": { "amount": -1 } })).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "failed to encode `Entry.Withdraw.amount`: expected `u64`, found `-1`"
+    );
+    let error = encode(&layouts, "Entry", &json!({ "Burn": {} })).unwrap_err();
+    assert_eq!(error.message, "`Burn` is not a variant of `Entry`");
+}
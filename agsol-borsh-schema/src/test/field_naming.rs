@@ -0,0 +1,122 @@
+use crate::*;
+
+use std::fs;
+
+#[allow(dead_code)]
+#[derive(BorshSchema)]
+pub struct MintConfig {
+    max_supply: u64,
+    #[schema_rename = "mintAuthority"]
+    authority: [u8; 32],
+    decimals: u8,
+}
+
+#[allow(dead_code)]
+#[derive(BorshSchema)]
+pub enum MintUpdate {
+    Supply { new_max_supply: u64 },
+    Decimals(#[schema_rename = "decimals"] u8),
+}
+
+fn find<'a>(layouts: &'a [Layout], name: &str) -> &'a Layout {
+    layouts.iter().find(|layout| layout.name == name).unwrap()
+}
+
+fn field_names(layout: &Layout) -> Vec<&str> {
+    layout
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect()
+}
+
+#[test]
+fn field_naming_conventions() {
+    let layouts = generate_layout_from_file("src/test/field_naming.rs").unwrap();
+    assert_eq!(
+        field_names(find(&layouts, "MintConfig")),
+        ["maxSupply", "mintAuthority", "decimals"]
+    );
+    assert_eq!(
+        field_names(find(&layouts, "MintUpdate")),
+        ["mintUpdateSupply", "mintUpdateDecimals"]
+    );
+    assert_eq!(
+        field_names(find(&layouts, "MintUpdateDecimals")),
+        ["decimals"]
+    );
+
+    let options = LayoutOptions {
+        field_naming: FieldNaming::SnakeCase,
+        ..LayoutOptions::default()
+    };
+    let layouts =
+        generate_layout_from_file_with_options("src/test/field_naming.rs", &options).unwrap();
+    let config = find(&layouts, "MintConfig");
+    assert_eq!(
+        field_names(config),
+        ["max_supply", "mintAuthority", "decimals"]
+    );
+    assert!(config.to_ts_class().contains("    max_supply: BN;\n"));
+    assert!(config
+        .to_borsh_schema()
+        .contains("['max_supply', 'u64'],\n\t\t\t['mintAuthority', [32]],"));
+    let update = find(&layouts, "MintUpdate");
+    assert_eq!(
+        field_names(update),
+        ["mint_update_supply", "mint_update_decimals"]
+    );
+    assert!(update
+        .to_borsh_schema()
+        .contains("['mint_update_supply', MintUpdateSupply],"));
+    assert_eq!(
+        field_names(find(&layouts, "MintUpdateSupply")),
+        ["new_max_supply"]
+    );
+
+    generate_output(&layouts, "test-output/snake-case").unwrap();
+    let schema = fs::read_to_string("test-output/snake-case/schema.ts").unwrap();
+    assert!(schema.contains("    new_max_supply: BN;\n"));
+
+    let options = LayoutOptions {
+        field_naming: FieldNaming::Preserve,
+        ..LayoutOptions::default()
+    };
+    let layouts =
+        generate_layout_from_file_with_options("src/test/field_naming.rs", &options).unwrap();
+    assert_eq!(
+        field_names(find(&layouts, "MintUpdate")),
+        ["MintUpdateSupply", "MintUpdateDecimals"]
+    );
+    assert_eq!(
+        field_names(find(&layouts, "MintUpdateSupply")),
+        ["new_max_supply"]
+    );
+}
+
+#[cfg(feature = "derived-layouts")]
+#[test]
+fn derive_field_naming_conventions() {
+    // the derived layouts are renamed the same way
+    let roots: &[fn(&mut Vec<Layout>, &LayoutOptions)] =
+        &[MintConfig::add_layouts, MintUpdate::add_layouts];
+    for field_naming in [
+        FieldNaming::CamelCase,
+        FieldNaming::SnakeCase,
+        FieldNaming::Preserve,
+    ] {
+        let options = LayoutOptions {
+            field_naming,
+            ..LayoutOptions::default()
+        };
+        let layouts =
+            generate_layout_from_file_with_options("src/test/field_naming.rs", &options).unwrap();
+        let derived = collect_layouts_with_options(roots, &options).unwrap();
+        for layout in &derived {
+            assert_eq!(
+                field_names(layout),
+                field_names(find(&layouts, &layout.name))
+            );
+        }
+    }
+}
@@ -1,7 +1,145 @@
-use crate::{generate_layouts, generate_output};
+use crate::*;
+
+use std::fs;
 
 #[test]
 fn generate_output_from_test_directory() {
     let layouts = generate_layouts("src/test").unwrap();
+    let aliased_struct = layouts
+        .iter()
+        .find(|layout| layout.name == "AliasedStruct")
+        .unwrap();
+    // aliases defined in other files are resolved as well
+    assert!(aliased_struct.to_borsh_schema().contains("['key', [32]]"));
+    // every layout comes after the layouts it refers to
+    let position = |name: &str| layouts.iter().position(|layout| layout.name == name);
+    assert!(position("OtherState") < position("AliasedStruct"));
+    assert!(position("TestEnumVariantG") < position("TestEnum"));
+    assert!(schema_warnings(&layouts).is_empty());
     generate_output(&layouts, "test-output").unwrap();
+
+    let output = fs::read_to_string("test-output/schema.ts").unwrap();
+    assert!(output.contains(
+        r#"/** Amount held at a given time. */
+export class OtherState extends Struct {
+    /** The amount in lamports. */
+    amount: BN;
+    /** Unix timestamp in seconds. */
+    timestamp: BN;
+};"#
+    ));
+    assert!(output.contains(
+        r#"/**
+ * A variant with named fields.
+ *
+ * Its fields are documented *\/ as well.
+ */
+export class TestEnumVariantG extends Struct {
+    /** Arbitrary bytes. */
+    hello: number[];"#
+    ));
+
+    let options = OutputOptions {
+        self_contained: true,
+        discriminated_unions: true,
+        bound_constants: true,
+        idl: true,
+        ..OutputOptions::default()
+    };
+    generate_output_with_options(&layouts, "test-output/self-contained", &options).unwrap();
+
+    let idl_string = fs::read_to_string("test-output/self-contained/idl.json").unwrap();
+    let idl = serde_json::from_str::<Idl>(&idl_string).unwrap();
+    assert_eq!(idl.layouts.len(), layouts.len());
+    let test_enum = idl
+        .layouts
+        .iter()
+        .find(|layout| layout.name == "TestEnum")
+        .unwrap();
+    assert_eq!(test_enum.kind, Kind::Enum);
+    assert_eq!(test_enum.fields[2].variant.as_deref(), Some("VariantC"));
+    assert_eq!(
+        test_enum.fields[2].ty,
+        BorshType::Custom("TestEnumVariantC".to_owned())
+    );
+
+    let idl_json = serde_json::from_str::<serde_json::Value>(&idl_string).unwrap();
+    let other_state = idl_json["layouts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|layout| layout["name"] == "OtherState")
+        .unwrap();
+    assert_eq!(
+        other_state["fields"],
+        serde_json::json!([
+            { "name": "amount", "type": "u64", "docs": ["The amount in lamports."] },
+            { "name": "timestamp", "type": "i64", "docs": ["Unix timestamp in seconds."] },
+        ])
+    );
+}
+
+#[test]
+fn generate_python_output() {
+    let layouts = generate_layouts("src/test").unwrap();
+    let options = OutputOptions {
+        language: Language::Python,
+        ..OutputOptions::default()
+    };
+    generate_output_with_options(&layouts, "test-output/python", &options).unwrap();
+
+    let output = fs::read_to_string("test-output/python/schema.py").unwrap();
+    assert!(output.contains(
+        r#"OTHER_STATE_LAYOUT = _struct(
+    OtherState,
+    "amount" / U64,
+    "timestamp" / I64,
+)"#
+    ));
+    assert!(output.contains("    (\"VariantC\", LazyBound(lambda: TEST_ENUM_VARIANT_C_LAYOUT)),"));
+}
+
+/// Lists the variants of every enum.
+struct VariantsBackend;
+
+impl SchemaBackend for VariantsBackend {
+    fn file_name(&self) -> String {
+        "variants.txt".to_owned()
+    }
+
+    fn prelude(&self) -> String {
+        "# variants\n".to_owned()
+    }
+
+    fn layout(&self, layout: &Layout, _layouts: &[Layout]) -> String {
+        if layout.kind != Kind::Enum {
+            return String::new();
+        }
+        let variants = layout
+            .fields
+            .iter()
+            .filter_map(|field| field.variant.as_deref())
+            .collect::<Vec<_>>();
+        format!("{}: {}\n", layout.name, variants.join(", "))
+    }
+}
+
+#[test]
+fn generate_output_with_custom_backend() {
+    let layouts = generate_layouts("src/test").unwrap();
+    // the generated files are only read back, so they stay out of the checkout
+    let output_directory = std::env::temp_dir().join("agsol-borsh-schema-custom");
+    generate_output_with_backend(&layouts, &output_directory, &VariantsBackend).unwrap();
+    let output = fs::read_to_string(output_directory.join("variants.txt")).unwrap();
+    assert!(output.starts_with("# variants\n"));
+    assert!(output.contains("\nTupleInstruction: Reset, Deposit, Transfer, Swap, Skipped, Close\n"));
+
+    let backend = TypeScriptBackend {
+        extensions_path: "../extensions".to_owned(),
+        ..TypeScriptBackend::default()
+    };
+    generate_output_with_backend(&layouts, &output_directory, &backend).unwrap();
+    let output = fs::read_to_string(output_directory.join("schema.ts")).unwrap();
+    assert!(output.contains("import Enum from \"../extensions/enum\";"));
+    assert!(output.ends_with("]);"));
 }
@@ -0,0 +1,119 @@
+use crate::*;
+
+use std::fs;
+
+#[allow(dead_code)]
+#[derive(BorshSchema)]
+pub enum PoolInstruction {
+    /// Creates a new pool.
+    #[schema_accounts(signer writable payer, writable pool, readonly system_program)]
+    Initialize {
+        fee: u16,
+    },
+    #[schema_accounts(signer owner, writable pool)]
+    Deposit(u64),
+    #[schema_accounts(signer writable owner, writable pool)]
+    Close,
+    Ping,
+}
+
+#[test]
+fn generate_instruction_builders() {
+    let layouts = generate_layout_from_file("src/test/instruction_builders.rs").unwrap();
+    let instruction = layouts
+        .iter()
+        .find(|layout| layout.name == "PoolInstruction")
+        .unwrap();
+    assert_eq!(
+        instruction.fields[0].accounts,
+        vec![
+            InstructionAccount {
+                name: "payer".to_owned(),
+                signer: true,
+                writable: true,
+            },
+            InstructionAccount {
+                name: "pool".to_owned(),
+                signer: false,
+                writable: true,
+            },
+            InstructionAccount {
+                name: "systemProgram".to_owned(),
+                signer: false,
+                writable: false,
+            },
+        ]
+    );
+    assert!(instruction.fields[3].accounts.is_empty());
+
+    let builders = instruction.to_ts_instruction_builders(&layouts);
+    assert!(builders.contains(
+        r#"/** Creates a new pool. */
+export function buildPoolInstructionInitialize(
+    programId: PublicKey,
+    accounts: {
+        payer: PublicKey;
+        pool: PublicKey;
+        systemProgram: PublicKey;
+    },
+    args: PoolInstructionInitialize,
+): TransactionInstruction {
+    const data = Buffer.from(serialize(SCHEMA, new PoolInstruction({ poolInstructionInitialize: args })));
+    return new TransactionInstruction({
+        programId,
+        keys: [
+            { pubkey: accounts.payer, isSigner: true, isWritable: true },
+            { pubkey: accounts.pool, isSigner: false, isWritable: true },
+            { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+        ],
+        data,
+    });
+}"#
+    ));
+    // variants without fields don't take arguments
+    assert!(builders.contains(
+        r#"export function buildPoolInstructionPing(
+    programId: PublicKey,
+): TransactionInstruction {
+    const data = Buffer.from(serialize(SCHEMA, new PoolInstruction({ poolInstructionPing: new PoolInstructionPing({}) })));
+    return new TransactionInstruction({
+        programId,
+        keys: [],
+        data,
+    });
+}"#
+    ));
+    let variant = layouts
+        .iter()
+        .find(|layout| layout.name == "PoolInstructionInitialize")
+        .unwrap();
+    assert!(variant.to_ts_instruction_builders(&layouts).is_empty());
+
+    let options = OutputOptions {
+        self_contained: true,
+        instruction_builders: true,
+        ..OutputOptions::default()
+    };
+    generate_output_with_options(&layouts, "test-output/instructions", &options).unwrap();
+    let output = fs::read_to_string("test-output/instructions/schema.ts").unwrap();
+    assert!(output
+        .starts_with("import { PublicKey, TransactionInstruction } from \"@solana/web3.js\";"));
+}
+
+#[cfg(feature = "derived-layouts")]
+#[test]
+fn derive_instruction_accounts() {
+    let layouts = generate_layout_from_file("src/test/instruction_builders.rs").unwrap();
+    let find = |layouts: &[Layout]| {
+        layouts
+            .iter()
+            .find(|layout| layout.name == "PoolInstruction")
+            .unwrap()
+            .clone()
+    };
+    // the derived layouts declare the same accounts
+    let derived = collect_layouts(&[PoolInstruction::add_layouts]).unwrap();
+    for (derived_field, field) in find(&derived).fields.iter().zip(&find(&layouts).fields) {
+        assert_eq!(derived_field.accounts, field.accounts);
+    }
+}
@@ -1,6 +1,51 @@
 mod borsh_btree;
+mod borsh_collections;
 mod borsh_enums;
+mod borsh_generics;
 mod borsh_structs;
+mod borsh_tuples;
+mod bounded_containers;
+mod cfg_attributes;
+#[cfg(feature = "derived-layouts")]
+mod derived_layouts;
+mod dynamic_codec;
+mod field_naming;
 mod generate_output;
+mod instruction_builders;
+mod module_resolution;
+mod schema_compat;
+mod schema_errors;
+mod snapshots;
+
+use crate::{decode, encode, Idl, Layout};
+use borsh::BorshSerialize;
 
 const TEST_DATA_DIRECTORY: &str = "test-data";
+
+/// Decodes the borsh-rs serialization of `value` with the dynamic decoder,
+/// interpreting the layouts read back from their JSON IDL, and checks that
+/// the decoded value encodes into the same bytes. Unlike the data written
+/// into [`TEST_DATA_DIRECTORY`], this doesn't require a JavaScript step to
+/// verify the schema. Returns the decoded value.
+fn assert_decodes<T: BorshSerialize>(
+    layouts: &[Layout],
+    root: &str,
+    value: &T,
+) -> serde_json::Value {
+    let idl = serde_json::to_string(&Idl {
+        layouts: layouts.to_vec(),
+    })
+    .unwrap();
+    let layouts = serde_json::from_str::<Idl>(&idl).unwrap().layouts;
+
+    let data = value.try_to_vec().unwrap();
+    let decoded = decode(&layouts, root, &data).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(
+        encode(&layouts, root, &decoded).unwrap_or_else(|err| panic!("{}", err)),
+        data,
+        "`{}` doesn't encode {} back into the same bytes",
+        root,
+        decoded
+    );
+    decoded
+}
@@ -0,0 +1,139 @@
+use crate::*;
+
+#[test]
+fn resolve_module_paths() {
+    let mut layouts = generate_layouts("test-fixtures/modules").unwrap();
+    layouts.sort_by(|a, b| a.name.cmp(&b.name));
+    let names = layouts
+        .iter()
+        .map(|layout| layout.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            "Fraction",
+            "Instruction",
+            "InstructionReset",
+            "InstructionState",
+            "InstructionUpdate",
+            "Root",
+            "StateState",
+        ]
+    );
+
+    let schema = |name: &str| {
+        layouts
+            .iter()
+            .find(|layout| layout.name == name)
+            .unwrap()
+            .to_borsh_schema()
+    };
+
+    let root = schema("Root");
+    assert!(root.contains("['state', StateState]"));
+    assert!(root.contains("['fraction', Fraction]"));
+    assert!(root.contains("['amount', 'u64']"));
+
+    let state = schema("StateState");
+    assert!(state.contains("['amount', 'u64']"));
+    assert!(state.contains("['owner', 'publicKey']"));
+
+    let instruction_state = schema("InstructionState");
+    assert!(instruction_state.contains("['previous', StateState]"));
+    assert!(instruction_state.contains("['next', StateState]"));
+    assert!(instruction_state.contains("['ratio', Fraction]"));
+    assert!(instruction_state.contains("['amounts', ['u64']]"));
+
+    assert!(schema("Instruction").contains("['instructionUpdate', InstructionUpdate]"));
+    assert!(schema("InstructionUpdate").contains("['unnamed_0', InstructionState]"));
+
+    // the account discriminator hashes the name of the Rust data structure
+    let state = layouts
+        .iter()
+        .find(|layout| layout.name == "StateState")
+        .unwrap();
+    assert_eq!(state.rust_name.as_deref(), Some("State"));
+    assert_eq!(
+        state.discriminator(),
+        [216, 146, 107, 94, 104, 75, 182, 177]
+    );
+    let root = layouts.iter().find(|layout| layout.name == "Root").unwrap();
+    assert!(root.rust_name.is_none());
+}
+
+#[test]
+fn conflicting_definitions() {
+    let error = generate_layouts("test-fixtures/collision")
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("`foo::Foo` is defined multiple times"));
+    assert!(error.contains("foo.rs"));
+    assert!(error.contains("mod.rs"));
+}
+
+#[test]
+fn split_modules_output() {
+    let layouts = generate_layouts("test-fixtures/modules").unwrap();
+    let options = OutputOptions {
+        split_modules: true,
+        ..OutputOptions::default()
+    };
+    generate_output_with_options(&layouts, "test-output/modules", &options).unwrap();
+
+    let read = |path: &str| std::fs::read_to_string(format!("test-output/modules/{}", path));
+    let instruction = read("instruction.ts").unwrap();
+    assert!(instruction.contains("import Struct from \"./extensions/struct\";"));
+    assert!(instruction.contains("import { Fraction } from \"./math\";"));
+    assert!(instruction.contains("import { StateState } from \"./state\";"));
+    assert!(instruction.contains("export class InstructionState extends Struct {"));
+    assert!(!instruction.contains("SCHEMA"));
+
+    let root = read("lib.ts").unwrap();
+    assert!(root.contains("import { StateState } from \"./state\";"));
+    assert!(root.contains("export class Root extends Struct {"));
+    assert!(read("math.ts")
+        .unwrap()
+        .contains("export class Fraction extends Struct {"));
+
+    let index = read("index.ts").unwrap();
+    assert!(index.contains("export * from \"./instruction\";"));
+    assert!(index.contains(
+        "import { InstructionReset, InstructionState, InstructionUpdate, Instruction } \
+        from \"./instruction\";"
+    ));
+    assert!(index.contains("borshPublicKey();"));
+    assert!(index.contains("export const SCHEMA = new Map<any, any>(["));
+}
+
+#[test]
+fn split_modules_collision() {
+    let mut layouts = generate_layouts("test-fixtures/modules").unwrap();
+    let options = OutputOptions {
+        split_modules: true,
+        ..OutputOptions::default()
+    };
+    let fraction = layouts
+        .iter()
+        .position(|layout| layout.name == "Fraction")
+        .unwrap();
+    // `index.ts` is taken by the file exporting every module
+    layouts[fraction].module = vec!["index".to_owned()];
+    let error =
+        generate_output_with_options(&layouts, "test-output/invalid", &options).unwrap_err();
+    assert!(matches!(error, SchemaError::NameCollision { .. }));
+    assert!(error
+        .to_string()
+        .starts_with("`index::Fraction` (test-fixtures/modules/"));
+    assert!(error
+        .to_string()
+        .ends_with("and `SCHEMA` (index.ts) would both be generated as `index.ts`"));
+
+    // the root module is written into `lib.ts`
+    layouts[fraction].module = vec!["lib".to_owned()];
+    let error = generate_output_with_options(&layouts, "test-output/invalid", &options)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("`lib::Fraction`"));
+    assert!(error.contains("`Root`"));
+    assert!(error.ends_with("would both be generated as `lib.ts`"));
+}
@@ -0,0 +1,92 @@
+use crate::*;
+
+#[test]
+fn detect_breaking_changes() {
+    let old = generate_layouts("test-fixtures/compat/old").unwrap();
+    let new = generate_layouts("test-fixtures/compat/new").unwrap();
+    let changes = breaking_changes(&old, &new);
+    let messages = changes.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "field `owner` of `Account` moved from position 0 to 1",
+            "field `balance` of `Account` moved from position 1 to 0",
+            "field `delegate` of `Account` was removed",
+            "field `name` of `Account` moved from position 3 to 2",
+            "`Config` changed between a struct and an enum",
+            "`Deprecated` was removed",
+            "type of field `unnamed_0` of `EventDeposit` changed from `u64` to `u32`",
+            "maximum size of `Member` increased from 37 to 41 bytes",
+            "maximum size of `Pool` increased from 152 bytes to unbounded",
+            "field `owner` of `Profile` was renamed to `authority`, which changes the \
+            generated code but not the serialized data",
+            "type of field `until` of `StateFrozen` changed from `i64` to `u64`",
+            "discriminant of variant `Active` of `State` changed from 0 to 1",
+            "discriminant of variant `Frozen` of `State` changed from 1 to 0",
+            "variant `Closed` of `State` was removed",
+        ]
+    );
+    assert_eq!(
+        changes[8],
+        BreakingChange::IncreasedSize {
+            layout: "Pool".to_owned(),
+            old: 4 + 4 * 37,
+            new: None,
+        }
+    );
+
+    // renamed fields don't break the serialized data, but they change the
+    // generated classes, which breaks the clients
+    let renamed = changes
+        .iter()
+        .find(|change| !change.breaks_serialization())
+        .unwrap();
+    assert_eq!(
+        renamed,
+        &BreakingChange::RenamedField {
+            layout: "Profile".to_owned(),
+            field: "owner".to_owned(),
+            new_name: "authority".to_owned(),
+        }
+    );
+    assert!(changes
+        .iter()
+        .filter(|change| *change != renamed)
+        .all(BreakingChange::breaks_serialization));
+
+    // only data structures deriving `MaxSerializedLen` are checked for
+    // their size, so instructions may grow
+    let instruction = |layouts: &[Layout]| {
+        let layout = layouts
+            .iter()
+            .find(|layout| layout.name == "Instruction")
+            .unwrap();
+        assert!(!layout.derives_max_len);
+        layout.max_serialized_len(layouts).unwrap()
+    };
+    assert!(instruction(&new) > instruction(&old));
+
+    // the layouts are compatible with themselves
+    assert!(breaking_changes(&new, &new).is_empty());
+    let profile = new.iter().find(|layout| layout.name == "Profile").unwrap();
+    assert_eq!(profile.max_serialized_len(&new), Some(32 + 4 + 16));
+}
+
+#[test]
+fn detect_changed_newtype_variants() {
+    let options = LayoutOptions {
+        inline_newtype_variants: true,
+        ..LayoutOptions::default()
+    };
+    let old = generate_layouts_with_options("test-fixtures/compat/old", &options).unwrap();
+    let new = generate_layouts_with_options("test-fixtures/compat/new", &options).unwrap();
+    let messages = breaking_changes(&old, &new)
+        .iter()
+        .map(ToString::to_string)
+        .filter(|message| message.contains("Event"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        ["type of field `eventDeposit` of `Event` changed from `u64` to `u32`"]
+    );
+}
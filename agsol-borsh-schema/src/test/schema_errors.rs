@@ -0,0 +1,94 @@
+use crate::layout::{BorshType, Kind, Layout, LayoutField};
+use crate::*;
+
+use std::path::Path;
+
+#[test]
+fn invalid_type() {
+    let error = generate_layouts("test-fixtures/errors/invalid_type").unwrap_err();
+    assert!(matches!(error, SchemaError::InvalidType { ref ty, .. } if ty == "[u8;LEN]"));
+    let location = error.location().unwrap();
+    assert_eq!(
+        location.file,
+        Path::new("test-fixtures/errors/invalid_type/lib.rs")
+    );
+    assert_eq!(location.item, "Invalid::B");
+    assert_eq!(location.field.as_deref(), Some("bytes"));
+    assert_eq!((location.line, location.column), (13, 26));
+    assert_eq!(
+        location.to_string(),
+        "test-fixtures/errors/invalid_type/lib.rs:13:26"
+    );
+    assert!(error
+        .to_string()
+        .starts_with("invalid type `[u8;LEN]` in field `bytes` of `Invalid::B`: "));
+}
+
+#[test]
+fn syntax_error() {
+    let error = generate_layouts("test-fixtures/errors/syntax").unwrap_err();
+    assert!(matches!(error, SchemaError::Syntax { .. }));
+    let location = error.location().unwrap();
+    assert_eq!(
+        location.file,
+        Path::new("test-fixtures/errors/syntax/lib.rs")
+    );
+    assert_eq!(location.line, 6);
+}
+
+#[test]
+fn missing_directory() {
+    let error = generate_layouts("test-fixtures/does-not-exist").unwrap_err();
+    assert!(matches!(error, SchemaError::Io { .. }));
+    assert!(error.location().is_none());
+}
+
+#[test]
+fn nested_skipped_type() {
+    let layout = Layout {
+        source: "foo.rs".into(),
+        ..Layout::new(
+            "Foo",
+            Kind::Struct,
+            vec![LayoutField::new(
+                "bar",
+                BorshType::Vec(Box::new(BorshType::Skip)),
+            )],
+        )
+    };
+    let error = generate_output(&[layout], "test-output/invalid").unwrap_err();
+    assert!(matches!(error, SchemaError::UnsupportedType { .. }));
+    assert_eq!(error.location().unwrap().field.as_deref(), Some("bar"));
+}
+
+#[test]
+fn invalid_discriminant() {
+    let error = generate_layouts("test-fixtures/errors/discriminant").unwrap_err();
+    assert!(matches!(error, SchemaError::InvalidDiscriminant { .. }));
+    let location = error.location().unwrap();
+    assert_eq!(location.item, "Level::Medium");
+    assert_eq!((location.line, location.column), (5, 5));
+    assert!(error
+        .to_string()
+        .starts_with("invalid discriminant in `Level::Medium`"));
+    assert!(error.to_string().ends_with(": 300 doesn't fit into a `u8`"));
+}
+
+#[test]
+fn name_collision() {
+    // both prefixed names of the `State`s are taken, the first one is reported
+    let error = generate_layouts("test-fixtures/errors/name_collision").unwrap_err();
+    match &error {
+        SchemaError::NameCollision {
+            name,
+            first,
+            second,
+            ..
+        } => {
+            assert_eq!(name, "InstructionState");
+            assert_eq!(first, "InstructionState");
+            assert_eq!(second, "instruction::State");
+        }
+        error => panic!("unexpected error: {}", error),
+    }
+}
@@ -0,0 +1,96 @@
+//! Golden-file tests of the generated TypeScript code. The output generated
+//! from every fixture module in `src/test` is compared against
+//! `test-fixtures/snapshots/{module}.ts`. Set the `BLESS_SNAPSHOTS`
+//! environment variable to overwrite the snapshots with the current output
+//! instead, e.g. `BLESS_SNAPSHOTS=1 cargo test --all-features snapshots`.
+
+use crate::*;
+
+use std::fs;
+use std::path::Path;
+
+/// The test modules whose generated code is snapshotted. `borsh_structs`
+/// refers to a type alias of another module, so it can't be generated on its
+/// own.
+const FIXTURES: &[&str] = &[
+    "borsh_btree",
+    "borsh_collections",
+    "borsh_enums",
+    "borsh_generics",
+    "borsh_tuples",
+    "bounded_containers",
+    "dynamic_codec",
+];
+
+const SNAPSHOT_DIRECTORY: &str = "test-fixtures/snapshots";
+const BLESS_VARIABLE: &str = "BLESS_SNAPSHOTS";
+
+/// Describes the first line where the output differs from the snapshot.
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for line in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(expected), Some(actual)) if expected == actual => {}
+            (None, None) => break,
+            (expected, actual) => {
+                return format!(
+                    "line {}:\n  expected: {}\n  actual:   {}",
+                    line,
+                    expected.unwrap_or("<end of file>"),
+                    actual.unwrap_or("<end of file>")
+                )
+            }
+        }
+    }
+    "the line endings differ".to_owned()
+}
+
+#[test]
+fn typescript_snapshots() {
+    let bless = std::env::var_os(BLESS_VARIABLE).is_some();
+    let mut mismatches = Vec::new();
+    for fixture in FIXTURES {
+        let layouts = generate_layout_from_file(format!("src/test/{}.rs", fixture)).unwrap();
+        // the generated files are only read back, so they stay out of the checkout
+        let output_directory = std::env::temp_dir()
+            .join("agsol-borsh-schema-snapshots")
+            .join(fixture);
+        generate_output(&layouts, &output_directory).unwrap();
+        let actual = fs::read_to_string(output_directory.join("schema.ts")).unwrap();
+
+        let snapshot = Path::new(SNAPSHOT_DIRECTORY).join(format!("{}.ts", fixture));
+        if bless {
+            fs::create_dir_all(SNAPSHOT_DIRECTORY).unwrap();
+            fs::write(&snapshot, &actual).unwrap();
+            continue;
+        }
+        match fs::read_to_string(&snapshot) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => mismatches.push(format!(
+                "{} differs from the generated output at {}",
+                snapshot.display(),
+                first_difference(&expected, &actual)
+            )),
+            Err(_) => mismatches.push(format!("{} is missing", snapshot.display())),
+        }
+    }
+    assert!(
+        mismatches.is_empty(),
+        "{}\n\nrerun the tests with `{}=1` to accept the generated output",
+        mismatches.join("\n\n"),
+        BLESS_VARIABLE
+    );
+}
+
+#[test]
+fn snapshot_differences() {
+    assert_eq!(
+        first_difference("a\nb\nc\n", "a\nx\nc\n"),
+        "line 2:\n  expected: b\n  actual:   x"
+    );
+    assert_eq!(
+        first_difference("a\n", "a\nb\n"),
+        "line 2:\n  expected: <end of file>\n  actual:   b"
+    );
+}
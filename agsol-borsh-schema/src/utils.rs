@@ -4,8 +4,8 @@
 //! # Example
 //!
 //! ```rust
-//! # use agsol_borsh_schema::{generate_layouts, generate_output};
-//! # fn generate_schema() -> Result<(), anyhow::Error> {
+//! # use agsol_borsh_schema::{generate_layouts, generate_output, SchemaError};
+//! # fn generate_schema() -> Result<(), SchemaError> {
 //!     let layouts = generate_layouts("~/input-rust-directory")?;
 //!     generate_output(&layouts, "./output-directory")?;
 //! # Ok(())
@@ -13,73 +13,374 @@
 //!
 //! ```
 
-use crate::layout::{Kind, Layout, LayoutField};
+use crate::backend::{PythonBackend, SchemaBackend, TypeScriptBackend};
+use crate::cfg::is_enabled;
+use crate::dependency::{check_references, sort_layouts};
+pub use crate::error::{SchemaError, SchemaWarning, SourceLocation};
+use crate::layout::{
+    doc_lines, instruction_accounts, BorshType, Kind, Layout, LayoutField, LayoutOptions,
+    LOCKED_ITEM,
+};
+use crate::resolver::Resolver;
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
 
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::ffi::OsStr;
 use std::fs;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 /// Traverses all files in the input directory and looks for data structures
 /// that should be converted into TypeScript and borsh layouts.
-pub fn generate_layouts(directory: impl AsRef<Path>) -> Result<Vec<Layout>, anyhow::Error> {
-    let dir_items = fs::read_dir(directory)?;
-    Ok(dir_items
-        .flat_map(|item| {
-            let path = item?.path();
-            if path.is_dir() {
-                generate_layouts(path)
-            } else {
-                generate_layout_from_file(path)
-            }
-        })
-        .flatten()
-        .collect::<Vec<Layout>>())
+///
+/// The input directory is treated as the root of a crate: module paths are
+/// derived from the directory structure and inline `mod` items, while `use`
+/// declarations and type aliases (`type Foo = ...;`) are taken into account
+/// when resolving the types of fields. Data structures with the same name in
+/// different modules are prefixed with their module path, e.g. `state::State`
+/// and `instruction::State` become `StateState` and `InstructionState`.
+///
+/// The layouts are sorted topologically, i.e. every layout comes after the
+/// layouts it refers to, while independent layouts are ordered by name.
+/// Use [`schema_warnings`](crate::schema_warnings) to check the layouts for
+/// recursive types.
+pub fn generate_layouts(directory: impl AsRef<Path>) -> Result<Vec<Layout>, SchemaError> {
+    generate_layouts_with_options(directory, &LayoutOptions::default())
+}
+
+/// Same as [`generate_layouts`] but parses the data structures according to
+/// the provided options.
+pub fn generate_layouts_with_options(
+    directory: impl AsRef<Path>,
+    options: &LayoutOptions,
+) -> Result<Vec<Layout>, SchemaError> {
+    let files = parse_directory(directory.as_ref(), &[])?;
+    layouts_from_files(&files, options)
 }
 
 /// Generates the TypeScript and borsh layouts of rust data structures found in `.rs`
 /// files.
-pub fn generate_layout_from_file(filepath: impl AsRef<Path>) -> Result<Vec<Layout>, anyhow::Error> {
-    if filepath.as_ref().extension() != Some(OsStr::new("rs")) {
+pub fn generate_layout_from_file(filepath: impl AsRef<Path>) -> Result<Vec<Layout>, SchemaError> {
+    generate_layout_from_file_with_options(filepath, &LayoutOptions::default())
+}
+
+/// Same as [`generate_layout_from_file`] but parses the data structures
+/// according to the provided options.
+pub fn generate_layout_from_file_with_options(
+    filepath: impl AsRef<Path>,
+    options: &LayoutOptions,
+) -> Result<Vec<Layout>, SchemaError> {
+    let files = parse_file(filepath.as_ref(), &[])?;
+    layouts_from_files(&files, options)
+}
+
+/// Collects the layouts of compiled types deriving
+/// [`BorshSchema`](crate::BorshSchema) instead of parsing their source code,
+/// which takes macros, `cfg` attributes and type aliases into account. Every
+/// root is the `add_layouts` function of a type, which adds the layouts of
+/// every type it refers to as well.
+///
+/// The layouts are sorted like the ones returned by [`generate_layouts`],
+/// while their module paths are derived from the crate they are defined in.
+/// Unlike the parsed layouts, data structures with the same name in different
+/// modules are not prefixed, since the types referring to them only know
+/// their names, so they are reported as [`SchemaError::NameCollision`].
+/// Use [`collect_layouts_with_options`] to name the fields differently.
+///
+/// ```rust
+/// # use agsol_borsh_schema::{collect_layouts, BorshSchema};
+/// #[derive(BorshSchema)]
+/// struct State {
+///     owner: [u8; 32],
+///     history: Vec<(u64, Entry)>,
+/// }
+///
+/// #[derive(BorshSchema)]
+/// enum Entry {
+///     Deposit(u64),
+///     Withdraw(u64),
+/// }
+///
+/// let layouts = collect_layouts(&[State::add_layouts]).unwrap();
+/// assert_eq!(layouts.len(), 5);
+/// ```
+#[cfg(feature = "derived-layouts")]
+pub fn collect_layouts(
+    roots: &[fn(&mut Vec<Layout>, &LayoutOptions)],
+) -> Result<Vec<Layout>, SchemaError> {
+    collect_layouts_with_options(roots, &LayoutOptions::default())
+}
+
+/// Collects the layouts of compiled types like [`collect_layouts`], naming
+/// the fields according to the `options`. Since the compiler has already
+/// evaluated the `cfg` attributes, the enabled `features` are ignored.
+#[cfg(feature = "derived-layouts")]
+pub fn collect_layouts_with_options(
+    roots: &[fn(&mut Vec<Layout>, &LayoutOptions)],
+    options: &LayoutOptions,
+) -> Result<Vec<Layout>, SchemaError> {
+    let mut layouts = Vec::new();
+    for add_layouts in roots {
+        add_layouts(&mut layouts, options);
+    }
+    let mut owners = HashMap::<&str, &Layout>::new();
+    for layout in &layouts {
+        if let Some(owner) = owners.insert(&layout.name, layout) {
+            return Err(SchemaError::NameCollision {
+                name: layout.name.clone(),
+                first: owner.path(),
+                first_file: owner.source.clone(),
+                second: layout.path(),
+                second_file: layout.source.clone(),
+            });
+        }
+    }
+    let mut tuples = tuple_layouts(&layouts, options);
+    layouts.append(&mut tuples);
+    Ok(sort_layouts(layouts))
+}
+
+/// A parsed `.rs` file along with the path of the module it represents.
+struct ParsedFile {
+    path: PathBuf,
+    module: Vec<String>,
+    syntax: syn::File,
+}
+
+fn parse_directory(directory: &Path, module: &[String]) -> Result<Vec<ParsedFile>, SchemaError> {
+    let io_error = |source| SchemaError::Io {
+        path: directory.to_path_buf(),
+        source,
+    };
+    let mut files = Vec::new();
+    for item in fs::read_dir(directory).map_err(io_error)? {
+        let path = item.map_err(io_error)?.path();
+        let name = path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        let mut parsed = if path.is_dir() {
+            parse_directory(&path, &[module, &[name]].concat())?
+        } else if matches!(name.as_str(), "lib" | "main" | "mod") {
+            parse_file(&path, module)?
+        } else {
+            parse_file(&path, &[module, &[name]].concat())?
+        };
+        files.append(&mut parsed);
+    }
+    Ok(files)
+}
+
+fn parse_file(filepath: &Path, module: &[String]) -> Result<Vec<ParsedFile>, SchemaError> {
+    if filepath.extension() != Some(OsStr::new("rs")) {
         return Ok(Vec::new());
     }
-    let code = fs::read_to_string(filepath)?;
-    let syntax = syn::parse_file(&code)?;
+    let code = fs::read_to_string(filepath).map_err(|source| SchemaError::Io {
+        path: filepath.to_path_buf(),
+        source,
+    })?;
+    let syntax = syn::parse_file(&code).map_err(|err| SchemaError::Syntax {
+        location: Box::new(SourceLocation {
+            file: filepath.to_path_buf(),
+            ..SourceLocation::from_span(err.span())
+        }),
+        message: err.to_string(),
+    })?;
+    Ok(vec![ParsedFile {
+        path: filepath.to_path_buf(),
+        module: module.to_vec(),
+        syntax,
+    }])
+}
+
+fn layouts_from_files(
+    files: &[ParsedFile],
+    options: &LayoutOptions,
+) -> Result<Vec<Layout>, SchemaError> {
+    let mut disabled = Vec::new();
+    for file in files {
+        disabled_modules(&file.syntax.items, &file.module, options, &mut disabled);
+    }
+    let mut resolver = Resolver::default();
     let mut layouts = Vec::<Layout>::new();
-    for item in syntax.items {
+    for file in files.iter().filter(|file| {
+        !disabled
+            .iter()
+            .any(|module| file.module.starts_with(module))
+    }) {
+        layouts_from_items(
+            &file.syntax.items,
+            &file.module,
+            &file.path,
+            options,
+            &mut resolver,
+            &mut layouts,
+        )
+        .map_err(|err| err.with_location(|location| location.file = file.path.clone()))?;
+    }
+    let mut layouts = resolver.resolve(layouts)?;
+    if !layouts.iter().any(|layout| layout.name == LOCKED_ITEM) {
+        layouts.push(locked_item_layout());
+    }
+    let mut layouts = instantiate_generics(layouts)?;
+    let mut tuples = tuple_layouts(&layouts, options);
+    layouts.append(&mut tuples);
+    Ok(sort_layouts(layouts))
+}
+
+/// Collects the paths of the modules declared in separate files (`mod foo;`)
+/// that are disabled by a `#[cfg(...)]` attribute, so that their files can be
+/// left out.
+fn disabled_modules(
+    items: &[syn::Item],
+    module: &[String],
+    options: &LayoutOptions,
+    disabled: &mut Vec<Vec<String>>,
+) {
+    for item in items {
+        if let syn::Item::Mod(item_mod) = item {
+            let mod_path = [module, &[item_mod.ident.to_string()]].concat();
+            if !is_enabled(&item_mod.attrs, &options.features) {
+                disabled.push(mod_path);
+            } else if let Some((_, mod_items)) = &item_mod.content {
+                disabled_modules(mod_items, &mod_path, options, disabled);
+            }
+        }
+    }
+}
+
+/// Returns the attributes of the items that can contribute to the layouts.
+fn item_attrs(item: &syn::Item) -> &[syn::Attribute] {
+    match item {
+        syn::Item::Use(item_use) => &item_use.attrs,
+        syn::Item::Type(item_type) => &item_type.attrs,
+        syn::Item::Mod(item_mod) => &item_mod.attrs,
+        syn::Item::Struct(item_struct) => &item_struct.attrs,
+        syn::Item::Enum(item_enum) => &item_enum.attrs,
+        _ => &[],
+    }
+}
+
+fn layouts_from_items(
+    items: &[syn::Item],
+    module: &[String],
+    source: &Path,
+    options: &LayoutOptions,
+    resolver: &mut Resolver,
+    layouts: &mut Vec<Layout>,
+) -> Result<(), SchemaError> {
+    for item in items
+        .iter()
+        .filter(|item| is_enabled(item_attrs(item), &options.features))
+    {
         match item {
-            syn::Item::Struct(ref item_struct) => {
+            syn::Item::Use(item_use) => resolver.add_use(module, &item_use.tree),
+            syn::Item::Type(item_type) if item_type.generics.params.is_empty() => {
+                if let Ok(ty) = BorshType::from_str(&item_type.ty.to_token_stream().to_string()) {
+                    resolver.add_alias(module, item_type.ident.to_string(), ty);
+                }
+            }
+            syn::Item::Mod(item_mod) => {
+                if let Some((_, mod_items)) = &item_mod.content {
+                    let mod_path = [module, &[item_mod.ident.to_string()]].concat();
+                    layouts_from_items(mod_items, &mod_path, source, options, resolver, layouts)?;
+                }
+            }
+            syn::Item::Struct(item_struct) => {
                 for attr in &item_struct.attrs {
                     let attribute_string = attr.tokens.to_string();
                     if attribute_string.contains("BorshSchema") {
-                        layouts.push(Layout::from_tokens(
+                        let mut layout = Layout::from_tokens(
                             &item_struct.ident.to_string(),
                             &mut item_struct.fields.iter(),
-                        )?);
+                            options,
+                        )?;
+                        layout.generics = type_params(&item_struct.generics);
+                        layout.module = module.to_vec();
+                        layout.source = source.to_path_buf();
+                        layout.docs = doc_lines(&item_struct.attrs);
+                        layout.derives_max_len = derives(&item_struct.attrs, "MaxSerializedLen");
+                        layouts.push(layout);
                     }
                 }
             }
-            syn::Item::Enum(ref item_enum) => {
+            syn::Item::Enum(item_enum) => {
                 for attr in &item_enum.attrs {
                     let attribute_string = attr.tokens.to_string();
                     if attribute_string.contains("BorshSchema") {
+                        let generics = type_params(&item_enum.generics);
                         let mut enum_layout = Layout {
                             name: item_enum.ident.to_string(),
+                            rust_name: None,
                             kind: Kind::Enum,
                             fields: Vec::new(),
+                            generics: generics.clone(),
+                            module: module.to_vec(),
+                            source: source.to_path_buf(),
+                            docs: doc_lines(&item_enum.attrs),
+                            derives_max_len: derives(&item_enum.attrs, "MaxSerializedLen"),
                         };
                         let parent_name = item_enum.ident.to_string();
-                        let mut variant_layouts = item_enum
+                        let variants = item_enum
                             .variants
                             .iter()
-                            .map(|variant| {
-                                let name = parent_name.clone() + &variant.ident.to_string();
-                                enum_layout
-                                    .fields
-                                    .push(LayoutField::from_enum_variant(&name)?);
-                                Layout::from_tokens(&name, &mut variant.fields.iter())
-                            })
-                            .collect::<Result<Vec<_>, _>>()?;
+                            .filter(|variant| is_enabled(&variant.attrs, &options.features))
+                            .collect::<Vec<_>>();
+                        let discriminants = enum_discriminants(&item_enum.attrs, &variants)
+                            .map_err(|err| {
+                                err.with_location(|location| {
+                                    if location.item.is_empty() {
+                                        location.item = parent_name.clone()
+                                    } else {
+                                        location.item =
+                                            format!("{}::{}", parent_name, location.item)
+                                    }
+                                })
+                            })?;
+                        let mut variant_layouts = Vec::new();
+                        for (variant, discriminant) in variants.into_iter().zip(discriminants) {
+                            let name = parent_name.clone() + &variant.ident.to_string();
+                            let variant_str = variant.ident.to_string();
+                            let docs = doc_lines(&variant.attrs);
+                            let in_variant = |err: SchemaError| {
+                                err.with_location(|location| {
+                                    location.item = format!("{}::{}", parent_name, variant_str)
+                                })
+                            };
+                            let accounts =
+                                instruction_accounts(&variant.attrs).map_err(in_variant)?;
+                            if let Some(field) =
+                                newtype_field(&variant.fields, options).map_err(in_variant)?
+                            {
+                                enum_layout.fields.push(LayoutField {
+                                    name: options.field_naming.apply(&name),
+                                    ty: field.ty,
+                                    variant: Some(variant_str),
+                                    docs,
+                                    discriminant,
+                                    accounts,
+                                });
+                                continue;
+                            }
+                            let mut field =
+                                LayoutField::from_enum_variant(&name, &variant_str, &generics)?;
+                            field.name = options.field_naming.apply(&name);
+                            field.docs = docs.clone();
+                            field.discriminant = discriminant;
+                            field.accounts = accounts;
+                            enum_layout.fields.push(field);
+                            let mut variant_layout =
+                                Layout::from_tokens(&name, &mut variant.fields.iter(), options)
+                                    .map_err(in_variant)?;
+                            variant_layout.generics = generics.clone();
+                            variant_layout.module = module.to_vec();
+                            variant_layout.source = source.to_path_buf();
+                            variant_layout.docs = docs;
+                            variant_layouts.push(variant_layout);
+                        }
                         layouts.push(enum_layout);
                         layouts.append(&mut variant_layouts);
                     }
@@ -88,44 +389,453 @@ pub fn generate_layout_from_file(filepath: impl AsRef<Path>) -> Result<Vec<Layou
             _ => {}
         }
     }
-    Ok(layouts)
+    Ok(())
+}
+
+/// Returns the serialized discriminant of every variant of an enum, or
+/// `None`s if the variants are serialized by their indices.
+///
+/// Explicit discriminants (e.g. `A = 5`) are only serialized if the enum is
+/// annotated with `#[borsh(use_discriminant = true)]`. Otherwise the variant
+/// indices are serialized, just like borsh versions that ignore explicit
+/// discriminants do.
+fn enum_discriminants(
+    attrs: &[syn::Attribute],
+    variants: &[&syn::Variant],
+) -> Result<Vec<Option<u8>>, SchemaError> {
+    let explicit = variants
+        .iter()
+        .any(|variant| variant.discriminant.is_some());
+    if !explicit || use_discriminant(attrs)? != Some(true) {
+        return Ok(vec![None; variants.len()]);
+    }
+
+    let mut next = 0_i128;
+    let mut discriminants = Vec::with_capacity(variants.len());
+    for variant in variants {
+        let invalid = |message: String| SchemaError::InvalidDiscriminant {
+            location: Box::new(SourceLocation {
+                item: variant.ident.to_string(),
+                ..SourceLocation::from_span(variant.ident.span())
+            }),
+            message,
+        };
+        let value = match &variant.discriminant {
+            Some((_, expr)) => discriminant_value(expr).ok_or_else(|| {
+                invalid(format!(
+                    "`{}` is not an integer literal",
+                    expr.to_token_stream()
+                ))
+            })?,
+            None => next,
+        };
+        let discriminant = u8::try_from(value)
+            .map_err(|_| invalid(format!("{} doesn't fit into a `u8`", value)))?;
+        discriminants.push(Some(discriminant));
+        next = value + 1;
+    }
+    Ok(discriminants)
+}
+
+/// Parses the value of `use_discriminant` from the `#[borsh(...)]` attribute.
+fn use_discriminant(attrs: &[syn::Attribute]) -> Result<Option<bool>, SchemaError> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("borsh")) {
+        if let Ok(syn::Meta::List(list)) = attr.parse_meta() {
+            for nested in &list.nested {
+                if let syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) = nested {
+                    if name_value.path.is_ident("use_discriminant") {
+                        return match &name_value.lit {
+                            syn::Lit::Bool(value) => Ok(Some(value.value)),
+                            _ => Err(SchemaError::InvalidAttribute {
+                                location: Box::new(SourceLocation::from_span(
+                                    name_value.lit.span(),
+                                )),
+                                message: "`use_discriminant` expects `true` or `false`".to_owned(),
+                            }),
+                        };
+                    }
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the value of an integer literal discriminant, e.g. `5`, `0x10` or
+/// `-1`.
+fn discriminant_value(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => discriminant_value(expr).map(|value| -value),
+        syn::Expr::Paren(paren) => discriminant_value(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Returns the single field of a newtype variant if newtype variants should be
+/// inlined. Skipped fields are never inlined, because that would remove the
+/// variant from the enum altogether.
+fn newtype_field(
+    fields: &syn::Fields,
+    options: &LayoutOptions,
+) -> Result<Option<LayoutField>, SchemaError> {
+    match fields {
+        syn::Fields::Unnamed(unnamed) if options.inline_newtype_variants => {
+            let enabled = unnamed
+                .unnamed
+                .iter()
+                .filter(|field| is_enabled(&field.attrs, &options.features))
+                .collect::<Vec<_>>();
+            match enabled.as_slice() {
+                [field] => {
+                    let field = LayoutField::from_tokens(field, 0, options)?;
+                    Ok(Some(field).filter(|field| !field.should_skip()))
+                }
+                _ => Ok(None),
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Checks whether the `#[derive(...)]` attributes contain the provided derive
+/// macro, either by name or by path (e.g. `agsol_common::MaxSerializedLen`).
+fn derives(attrs: &[syn::Attribute], name: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+            )
+            .ok()
+        })
+        .flatten()
+        .any(|path| matches!(path.segments.last(), Some(segment) if segment.ident == name))
+}
+
+fn type_params(generics: &syn::Generics) -> Vec<String> {
+    generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect()
+}
+
+/// Replaces generic layouts with a concrete layout for every instantiation
+/// found in other layouts, e.g. a field of type `Wrapper<u64>` generates a
+/// `WrapperU64` layout from the generic `Wrapper<T>` layout.
+fn instantiate_generics(layouts: Vec<Layout>) -> Result<Vec<Layout>, SchemaError> {
+    let mut instances = HashMap::<String, Vec<Layout>>::new();
+    {
+        let templates = layouts
+            .iter()
+            .filter(|layout| !layout.generics.is_empty())
+            .map(|layout| (layout.name.as_str(), layout))
+            .collect::<HashMap<_, _>>();
+        // every instantiation is queued along with the layout it is found in
+        let mut queue = layouts
+            .iter()
+            .filter(|layout| layout.generics.is_empty())
+            .flat_map(|layout| {
+                let location = SourceLocation {
+                    file: layout.source.clone(),
+                    item: layout.name.clone(),
+                    ..SourceLocation::default()
+                };
+                layout
+                    .generic_instances()
+                    .into_iter()
+                    .map(move |instance| (instance, location.clone()))
+            })
+            .collect::<VecDeque<_>>();
+        let mut instantiated = HashSet::<String>::new();
+        while let Some(((name, args), origin)) = queue.pop_front() {
+            if let Some(template) = templates.get(name.as_str()) {
+                let instance = template
+                    .instantiate(&args)
+                    .map_err(|err| err.with_location(|location| *location = origin))?;
+                if instantiated.insert(instance.name.clone()) {
+                    let location = SourceLocation {
+                        file: instance.source.clone(),
+                        item: instance.name.clone(),
+                        ..SourceLocation::default()
+                    };
+                    queue.extend(
+                        instance
+                            .generic_instances()
+                            .into_iter()
+                            .map(|instance| (instance, location.clone())),
+                    );
+                    instances.entry(name).or_default().push(instance);
+                }
+            }
+        }
+    }
+
+    Ok(layouts
+        .into_iter()
+        .flat_map(|layout| {
+            if layout.generics.is_empty() {
+                vec![layout]
+            } else {
+                instances.remove(&layout.name).unwrap_or_default()
+            }
+        })
+        .collect())
+}
+
+/// The generic layout of `LockedItem<T>` from `agsol-common`, used by
+/// `Timelock<T, N>` as well. Like other generic layouts, it is only converted
+/// if it is instantiated somewhere.
+fn locked_item_layout() -> Layout {
+    Layout {
+        name: LOCKED_ITEM.to_owned(),
+        rust_name: None,
+        kind: Kind::Struct,
+        fields: vec![
+            LayoutField {
+                name: "item".to_owned(),
+                ty: BorshType::Custom("T".to_owned()),
+                variant: None,
+                docs: Vec::new(),
+                discriminant: None,
+                accounts: Vec::new(),
+            },
+            LayoutField {
+                name: "expires".to_owned(),
+                ty: BorshType::I64,
+                variant: None,
+                docs: Vec::new(),
+                discriminant: None,
+                accounts: Vec::new(),
+            },
+        ],
+        generics: vec!["T".to_owned()],
+        module: Vec::new(),
+        source: PathBuf::new(),
+        docs: Vec::new(),
+        derives_max_len: false,
+    }
+}
+
+/// Generates a struct layout for every tuple type found in the layouts, e.g.
+/// a field of type `(u64, Pubkey)` generates a `TupleU64Pubkey` layout with
+/// positional fields.
+fn tuple_layouts(layouts: &[Layout], options: &LayoutOptions) -> Vec<Layout> {
+    let mut generated = HashSet::<String>::new();
+    let mut tuples = Vec::new();
+    for field in layouts.iter().flat_map(|layout| &layout.fields) {
+        field.ty.visit(&mut |ty| {
+            if let BorshType::Tuple(elements) = ty {
+                if generated.insert(ty.to_ident()) {
+                    tuples.push(Layout {
+                        name: ty.to_ident(),
+                        rust_name: None,
+                        kind: Kind::Struct,
+                        fields: elements
+                            .iter()
+                            .enumerate()
+                            .map(|(i, element)| LayoutField {
+                                name: format!("{}{}", options.positional_prefix, i),
+                                ty: element.clone(),
+                                variant: None,
+                                docs: Vec::new(),
+                                discriminant: None,
+                                accounts: Vec::new(),
+                            })
+                            .collect(),
+                        generics: Vec::new(),
+                        module: Vec::new(),
+                        source: PathBuf::new(),
+                        docs: Vec::new(),
+                        derives_max_len: false,
+                    });
+                }
+            }
+        });
+    }
+    tuples
+}
+
+/// The language of the generated code.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Language {
+    /// TypeScript classes and a `borsh-js` schema written into `schema.ts`.
+    TypeScript,
+    /// Python dataclasses and `borsh-construct` layouts written into
+    /// `schema.py`. Field names are converted to snake_case.
+    Python,
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Self::TypeScript
+    }
+}
+
+/// Options that control how the generated layouts are written into the
+/// output directory. Use [`generate_output_with_backend`] for finer control
+/// over the generated code, e.g. the path of the TypeScript extensions.
+#[derive(Clone, Debug, Default)]
+pub struct OutputOptions {
+    /// The language of the generated code. The options below that generate
+    /// TypeScript specific code are ignored for other languages.
+    pub language: Language,
+    /// Generates classes with typed constructors and `decode`/`encode`
+    /// methods that don't depend on the `Struct` and `Enum` base classes.
+    /// The borsh extensions are inlined as well, so the output doesn't
+    /// require the `./extensions` directory.
+    pub self_contained: bool,
+    /// Generates a discriminated union type (`{Name}Union`) for every enum,
+    /// along with type guards for its variants and functions that convert
+    /// between the union and the enum class.
+    pub discriminated_unions: bool,
+    /// Generates a `{LAYOUT}_{FIELD}_MAX_LEN` constant for every field
+    /// holding a bounded container from `agsol-common` (e.g.
+    /// `MaxLenString<N>`), so that clients can validate the data before
+    /// sending it.
+    pub bound_constants: bool,
+    /// Generates a `{LAYOUT}_DISCRIMINATOR` constant holding the 8-byte
+    /// account discriminator (see [`Layout::discriminator`]) of every struct
+    /// deriving `MaxSerializedLen`, next to its `{LAYOUT}_MAX_SERIALIZED_LEN`
+    /// constant that is generated regardless of this option.
+    pub account_discriminators: bool,
+    /// Writes a separate TypeScript file for every Rust module (e.g.
+    /// `state.ts` for `state::*` and `instruction/update.ts` for
+    /// `instruction::update::*`) along with an `index.ts` that re-exports
+    /// every class and assembles `SCHEMA`. Data structures of the root module
+    /// and generated tuple layouts are written into `lib.ts`.
+    pub split_modules: bool,
+    /// Generates a `build{Enum}{Variant}` function for every variant of the
+    /// enums whose variants declare the accounts of the instructions with
+    /// `#[schema_accounts(...)]`. The functions return a
+    /// `TransactionInstruction` holding the serialized enum and the account
+    /// metas.
+    pub instruction_builders: bool,
+    /// Writes a JSON IDL (see [`Idl`]) next to the generated code.
+    pub idl: bool,
+}
+
+/// A language agnostic description of the generated layouts that can be
+/// written into a JSON file, so that non-TypeScript clients can decode the
+/// same data structures.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Idl {
+    pub layouts: Vec<Layout>,
+}
+
+/// Writes the JSON IDL of the generated layouts into `idl.json` in the
+/// provided output directory.
+pub fn generate_idl(
+    layouts: &[Layout],
+    output_directory: impl AsRef<Path>,
+) -> Result<(), SchemaError> {
+    validate_layouts(layouts)?;
+
+    let output_path = output_directory.as_ref().join("idl.json");
+    let io_error = |source| SchemaError::Io {
+        path: output_path.clone(),
+        source,
+    };
+    let idl = Idl {
+        layouts: layouts.to_vec(),
+    };
+    fs::create_dir_all(&output_directory).map_err(io_error)?;
+    let file = fs::File::create(&output_path).map_err(io_error)?;
+    serde_json::to_writer_pretty(file, &idl).map_err(|err| io_error(err.into()))
 }
 
 /// Writes the generated layouts into a file in the provided output directory.
 pub fn generate_output(
     layouts: &[Layout],
     output_directory: impl AsRef<Path>,
-) -> Result<(), anyhow::Error> {
-    let schema_string = layouts
-        .iter()
-        .map(|layout| layout.to_borsh_schema())
-        .collect::<String>();
+) -> Result<(), SchemaError> {
+    generate_output_with_options(layouts, output_directory, &OutputOptions::default())
+}
 
-    let classes_string = layouts
-        .iter()
-        .map(|layout| layout.to_ts_class())
-        .collect::<String>();
-
-    let schema = format!(
-        r#"export const SCHEMA = new Map<any, any>([{}
-]);"#,
-        schema_string
-    );
-
-    let imports = String::from(
-        r#"import { PublicKey } from "@solana/web3.js";
-import BN from "bn.js";
-import Enum from "./extensions/enum";
-import Struct from "./extensions/struct";
-import { borshPublicKey } from "./extensions/publicKey";
-
-borshPublicKey();
-
-"#,
-    );
-
-    fs::create_dir_all(&output_directory)?;
-    let mut file = fs::File::create(output_directory.as_ref().join("schema.ts"))?;
-    write!(file, "{}", imports + &classes_string + &schema)?;
+/// Writes the generated layouts into a file in the provided output directory
+/// according to the provided options.
+pub fn generate_output_with_options(
+    layouts: &[Layout],
+    output_directory: impl AsRef<Path>,
+    options: &OutputOptions,
+) -> Result<(), SchemaError> {
+    match options.language {
+        Language::TypeScript => {
+            let backend = TypeScriptBackend {
+                self_contained: options.self_contained,
+                discriminated_unions: options.discriminated_unions,
+                bound_constants: options.bound_constants,
+                account_discriminators: options.account_discriminators,
+                split_modules: options.split_modules,
+                instruction_builders: options.instruction_builders,
+                ..TypeScriptBackend::default()
+            };
+            generate_output_with_backend(layouts, &output_directory, &backend)?;
+        }
+        Language::Python => {
+            generate_output_with_backend(layouts, &output_directory, &PythonBackend)?
+        }
+    }
+
+    if options.idl {
+        generate_idl(layouts, output_directory)?;
+    }
+    Ok(())
+}
+
+/// Writes the files generated by the provided backend into the provided
+/// output directory.
+pub fn generate_output_with_backend(
+    layouts: &[Layout],
+    output_directory: impl AsRef<Path>,
+    backend: &dyn SchemaBackend,
+) -> Result<(), SchemaError> {
+    validate_layouts(layouts)?;
+    backend.validate(layouts)?;
+
+    for (path, output) in backend.files(layouts) {
+        let output_path = output_directory.as_ref().join(path);
+        let io_error = |source| SchemaError::Io {
+            path: output_path.clone(),
+            source,
+        };
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let mut file = fs::File::create(&output_path).map_err(io_error)?;
+        write!(file, "{}", output).map_err(io_error)?;
+    }
     Ok(())
 }
+
+/// Checks that every field of the layouts can be converted. Skipped types are
+/// only allowed as the type of a field, in which case the field is left out,
+/// while referring to a data structure that has no layout is an error.
+fn validate_layouts(layouts: &[Layout]) -> Result<(), SchemaError> {
+    for layout in layouts {
+        for field in layout.fields.iter().filter(|field| !field.should_skip()) {
+            let mut skipped = false;
+            field.ty.visit(&mut |ty| skipped |= *ty == BorshType::Skip);
+            if skipped {
+                return Err(SchemaError::UnsupportedType {
+                    location: Box::new(SourceLocation {
+                        file: layout.source.clone(),
+                        item: layout.name.clone(),
+                        field: Some(field.name.clone()),
+                        ..SourceLocation::default()
+                    }),
+                    ty: format!("{:?}", field.ty),
+                });
+            }
+        }
+    }
+    check_references(layouts)
+}
@@ -0,0 +1,25 @@
+use agsol_borsh_schema::BorshSchema;
+use agsol_common::{MaxLenBTreeMap, MaxLenString, MaxLenVec, MaxSerializedLen, Timelock};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+pub const MAX_MEMBERS: usize = 64;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, MaxSerializedLen)]
+pub struct Guild {
+    name: MaxLenString<32>,
+    admins: MaxLenVec<Pubkey, 8>,
+    members: MaxLenVec<Pubkey, MAX_MEMBERS>,
+    roles: MaxLenBTreeMap<u8, MaxLenString<16>, 4>,
+    locked_funds: Timelock<u64, 10>,
+    last_lock: Option<agsol_common::LockedItem<u64>>,
+}
+
+/// The funds of a guild.
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize, agsol_common::MaxSerializedLen)]
+pub struct Treasury {
+    guild: Pubkey,
+    balance: u64,
+    spenders: MaxLenVec<Pubkey, 4>,
+    last_lock: Option<agsol_common::LockedItem<u64>>,
+}
@@ -0,0 +1,7 @@
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct ClientConfig {
+    pub rpc_url: String,
+    pub payer: Pubkey,
+}
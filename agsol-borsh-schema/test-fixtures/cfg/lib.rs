@@ -0,0 +1,44 @@
+#[cfg(feature = "client")]
+mod client;
+#[cfg(test)]
+mod tests;
+
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct Account {
+    pub owner: Pubkey,
+    #[cfg(feature = "metadata")]
+    pub metadata: String,
+    #[borsh_skip]
+    pub cached_balance: u64,
+    #[borsh(skip)]
+    pub cached_owner: Option<Pubkey>,
+    pub balance: u64,
+}
+
+#[cfg(not(feature = "client"))]
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct ProgramConfig {
+    pub admin: Pubkey,
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub enum Instruction {
+    Initialize,
+    #[cfg(any(test, feature = "admin"))]
+    SetAdmin(Pubkey),
+    Close {
+        #[cfg(feature = "metadata")]
+        reason: String,
+        refund: bool,
+    },
+}
+
+#[cfg(test)]
+mod inline_tests {
+    #[derive(BorshSchema)]
+    pub struct InlineTestData {
+        pub value: u8,
+    }
+}
@@ -0,0 +1,4 @@
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct TestData {
+    pub value: u64,
+}
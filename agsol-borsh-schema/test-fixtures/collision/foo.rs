@@ -0,0 +1,6 @@
+use agsol_borsh_schema::BorshSchema;
+
+#[derive(BorshSchema)]
+pub struct Foo {
+    foo: u8,
+}
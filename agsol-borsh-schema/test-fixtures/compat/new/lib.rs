@@ -0,0 +1,73 @@
+use agsol_borsh_schema::BorshSchema;
+use agsol_common::{MaxLenString, MaxSerializedLen};
+use solana_program::pubkey::Pubkey;
+
+// breaking: reordered and removed fields
+#[derive(BorshSchema)]
+pub struct Account {
+    pub balance: u64,
+    pub owner: Pubkey,
+    pub name: MaxLenString<32>,
+}
+
+// compatible data, but the renamed field breaks the clients: renamed field,
+// smaller bound and a field that isn't serialized removed
+#[derive(BorshSchema)]
+pub struct Profile {
+    pub authority: Pubkey,
+    pub name: MaxLenString<16>,
+}
+
+// breaking: reordered and removed variants, changed variant field type
+#[derive(BorshSchema)]
+pub enum State {
+    Frozen { until: u64 },
+    Active,
+}
+
+// compatible: appended variant that isn't larger than the others
+#[derive(BorshSchema, MaxSerializedLen)]
+pub enum Mode {
+    Read,
+    Write,
+    Admin,
+}
+
+// breaking: struct turned into an enum
+#[derive(BorshSchema)]
+pub enum Config {
+    Fee(u16),
+}
+
+// breaking: the size of the pool becomes unbounded, while the size of the
+// members increases
+#[derive(BorshSchema, MaxSerializedLen)]
+pub struct Pool {
+    pub members: Vec<Member>,
+}
+
+#[derive(BorshSchema, MaxSerializedLen)]
+pub struct Member {
+    pub key: Pubkey,
+    pub tag: Option<[u8; 4]>,
+    pub weight: u32,
+}
+
+// compatible: instructions aren't stored in accounts, so they may grow
+#[derive(BorshSchema)]
+pub enum Instruction {
+    Deposit(u64),
+    Transfer { to: Pubkey, amount: u64 },
+}
+
+// breaking: changed type of a newtype variant
+#[derive(BorshSchema)]
+pub enum Event {
+    Deposit(u32),
+}
+
+// compatible: new data structure
+#[derive(BorshSchema)]
+pub struct Settings {
+    pub admin: Pubkey,
+}
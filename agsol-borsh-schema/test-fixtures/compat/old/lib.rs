@@ -0,0 +1,63 @@
+use agsol_borsh_schema::BorshSchema;
+use agsol_common::{MaxLenString, MaxLenVec, MaxSerializedLen};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSchema)]
+pub struct Account {
+    pub owner: Pubkey,
+    pub balance: u64,
+    pub delegate: Option<Pubkey>,
+    pub name: MaxLenString<32>,
+}
+
+#[derive(BorshSchema)]
+pub struct Profile {
+    pub owner: Pubkey,
+    pub name: MaxLenString<32>,
+    #[schema_skip]
+    pub cache: Vec<u8>,
+}
+
+#[derive(BorshSchema)]
+pub enum State {
+    Active,
+    Frozen { until: i64 },
+    Closed,
+}
+
+#[derive(BorshSchema, MaxSerializedLen)]
+pub enum Mode {
+    Read,
+    Write,
+}
+
+#[derive(BorshSchema)]
+pub struct Config {
+    pub fee: u16,
+}
+
+#[derive(BorshSchema, MaxSerializedLen)]
+pub struct Pool {
+    pub members: MaxLenVec<Member, 4>,
+}
+
+#[derive(BorshSchema, MaxSerializedLen)]
+pub struct Member {
+    pub key: Pubkey,
+    pub tag: Option<[u8; 4]>,
+}
+
+#[derive(BorshSchema)]
+pub struct Deprecated {
+    pub flag: bool,
+}
+
+#[derive(BorshSchema)]
+pub enum Instruction {
+    Deposit(u64),
+}
+
+#[derive(BorshSchema)]
+pub enum Event {
+    Deposit(u64),
+}
@@ -0,0 +1,7 @@
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+#[borsh(use_discriminant = true)]
+pub enum Level {
+    Low,
+    Medium = 300,
+    High,
+}
@@ -0,0 +1,14 @@
+use agsol_borsh_schema::BorshSchema;
+
+const LEN: usize = 8;
+
+#[derive(BorshSchema)]
+pub struct Valid {
+    amount: u64,
+}
+
+#[derive(BorshSchema)]
+pub enum Invalid {
+    A,
+    B { ok: bool, bytes: [u8; LEN] },
+}
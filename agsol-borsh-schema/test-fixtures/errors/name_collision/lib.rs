@@ -0,0 +1,23 @@
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct InstructionState {
+    pub nonce: u8,
+}
+
+#[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+pub struct StateState {
+    pub nonce: u8,
+}
+
+mod instruction {
+    #[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+    pub struct State {
+        pub amount: u64,
+    }
+}
+
+mod state {
+    #[derive(BorshSchema, BorshSerialize, BorshDeserialize)]
+    pub struct State {
+        pub balance: u64,
+    }
+}
@@ -0,0 +1,7 @@
+use agsol_borsh_schema::BorshSchema;
+
+#[derive(BorshSchema)]
+pub struct Broken {
+    amount: u64
+    owner: Pubkey,
+}
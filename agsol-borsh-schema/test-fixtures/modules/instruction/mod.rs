@@ -0,0 +1,17 @@
+use super::math::*;
+use crate::state::State as ProgramState;
+use agsol_borsh_schema::BorshSchema;
+
+#[derive(BorshSchema)]
+pub struct State {
+    previous: ProgramState,
+    next: crate::state::State,
+    ratio: Fraction,
+    amounts: Vec<super::state::Amount>,
+}
+
+#[derive(BorshSchema)]
+pub enum Instruction {
+    Update(State),
+    Reset,
+}
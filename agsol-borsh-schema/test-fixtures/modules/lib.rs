@@ -0,0 +1,22 @@
+mod instruction;
+mod state;
+
+use agsol_borsh_schema::BorshSchema;
+use state::State;
+
+pub mod math {
+    use agsol_borsh_schema::BorshSchema;
+
+    #[derive(BorshSchema)]
+    pub struct Fraction {
+        numerator: u64,
+        denominator: u64,
+    }
+}
+
+#[derive(BorshSchema)]
+pub struct Root {
+    state: State,
+    fraction: math::Fraction,
+    amount: state::Amount,
+}
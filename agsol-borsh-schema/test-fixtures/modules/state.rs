@@ -0,0 +1,9 @@
+use agsol_borsh_schema::BorshSchema;
+
+pub type Amount = u64;
+
+#[derive(BorshSchema)]
+pub struct State {
+    amount: Amount,
+    owner: solana_program::pubkey::Pubkey,
+}
@@ -0,0 +1,26 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+import { borshPublicKey } from "./extensions/publicKey";
+
+borshPublicKey();
+
+export class BTreeWrapper extends Struct {
+    map0: Map<[32], PublicKey>;
+    map1: Map<string, number | null>;
+    map2: Map<number, string>;
+};
+
+export const SCHEMA = new Map<any, any>([
+    [
+            BTreeWrapper,
+            {
+                kind: 'struct', fields: [
+			['map0', { kind: 'map', key: [32], value: 'publicKey' }],
+			['map1', { kind: 'map', key: 'string', value: { kind: 'option', type: 'u32' } }],
+			['map2', { kind: 'map', key: 'u16', value: 'string' }],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
@@ -0,0 +1,88 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { BinaryWriter } from "borsh";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+import { borshPublicKey } from "./extensions/publicKey";
+
+borshPublicKey();
+
+const writeArray = BinaryWriter.prototype.writeArray;
+(BinaryWriter.prototype as any).writeArray = function (this: BinaryWriter, array: any[] | Set<any>, fn: any) {
+    writeArray.call(this, array instanceof Set ? Array.from(array) : array, fn);
+};
+
+export class BoxedState extends Struct {
+    amount: number;
+};
+
+export class TupleU64Pubkey {
+    constructor(fields: { 0: BN; 1: PublicKey }) {
+        return Object.defineProperty([fields[0], fields[1]], "constructor", { value: TupleU64Pubkey });
+    }
+};
+
+export class TupleU8OptionBool {
+    constructor(fields: { 0: number; 1: boolean | null }) {
+        return Object.defineProperty([fields[0], fields[1]], "constructor", { value: TupleU8OptionBool });
+    }
+};
+
+export class CollectionWrapper extends Struct {
+    map: Map<number, string>;
+    hashSet: Set<number>;
+    btreeSet: Set<string>;
+    boxed: BoxedState;
+    pair: [BN, PublicKey];
+    pairs: [number, boolean | null][];
+
+    constructor(properties: any) {
+        super(properties);
+        this.hashSet = new Set(this.hashSet);
+        this.btreeSet = new Set(this.btreeSet);
+        this.pair = new TupleU64Pubkey(this.pair) as [BN, PublicKey];
+        this.pairs = this.pairs.map((item) => new TupleU8OptionBool(item) as [number, boolean | null]);
+    }
+};
+
+export const SCHEMA = new Map<any, any>([
+    [
+            BoxedState,
+            {
+                kind: 'struct', fields: [
+			['amount', 'u32'],
+                ],
+            },
+    ],
+    [
+            TupleU64Pubkey,
+            {
+                kind: 'struct', fields: [
+			['0', 'u64'],
+			['1', 'publicKey'],
+                ],
+            },
+    ],
+    [
+            TupleU8OptionBool,
+            {
+                kind: 'struct', fields: [
+			['0', 'u8'],
+			['1', { kind: 'option', type: 'u8' }],
+                ],
+            },
+    ],
+    [
+            CollectionWrapper,
+            {
+                kind: 'struct', fields: [
+			['map', { kind: 'map', key: 'u8', value: 'string' }],
+			['hashSet', ['u16']],
+			['btreeSet', ['string']],
+			['boxed', BoxedState],
+			['pair', TupleU64Pubkey],
+			['pairs', [TupleU8OptionBool]],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
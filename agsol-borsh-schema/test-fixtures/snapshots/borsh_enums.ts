@@ -0,0 +1,277 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+import { borshPublicKey } from "./extensions/publicKey";
+
+borshPublicKey();
+
+export class PriorityHigh extends Struct {
+};
+
+export class PriorityLow extends Struct {
+};
+
+/**
+ * A C-like enum serialized by its variant indices, ignoring the explicit
+ * discriminants.
+ */
+export class Priority extends Enum {
+    priorityLow: PriorityLow;
+    priorityHigh: PriorityHigh;
+};
+
+export class RandomStruct extends Struct {
+    fieldA: string;
+    fieldB: [2] | null;
+};
+
+export class StatusActive extends Struct {
+};
+
+export class StatusFrozen extends Struct {
+};
+
+export class StatusInactive extends Struct {
+};
+
+/** A C-like enum serialized by its explicit discriminants. */
+export class Status extends Enum {
+    statusInactive: StatusInactive;
+    statusActive: StatusActive;
+    statusFrozen: StatusFrozen;
+};
+
+export class TestEnumVariantA extends Struct {
+};
+
+export class TestEnumVariantB extends Struct {
+};
+
+export class TestEnumVariantC extends Struct {
+    unnamed_0: BN;
+};
+
+export class TestEnumVariantD extends Struct {
+    unnamed_0: PublicKey | null;
+};
+
+export class TestEnumVariantE extends Struct {
+    unnamed_0: number | null;
+};
+
+export class TestEnumVariantF extends Struct {
+    unnamed_0: RandomStruct;
+};
+
+/**
+ * A variant with named fields.
+ *
+ * Its fields are documented *\/ as well.
+ */
+export class TestEnumVariantG extends Struct {
+    /** Arbitrary bytes. */
+    hello: number[];
+    bello: PublicKey[];
+    yello: number;
+    zello: boolean;
+};
+
+export class TestEnum extends Enum {
+    testEnumVariantA: TestEnumVariantA;
+    testEnumVariantB: TestEnumVariantB;
+    testEnumVariantC: TestEnumVariantC;
+    testEnumVariantD: TestEnumVariantD;
+    testEnumVariantE: TestEnumVariantE;
+    testEnumVariantF: TestEnumVariantF;
+    /**
+     * A variant with named fields.
+     *
+     * Its fields are documented *\/ as well.
+     */
+    testEnumVariantG: TestEnumVariantG;
+};
+
+export class TierBasic extends Struct {
+};
+
+export class TierPremium extends Struct {
+};
+
+/**
+ * A C-like enum with explicit discriminants but without `use_discriminant`,
+ * which borsh serializes by the variant indices.
+ */
+export class Tier extends Enum {
+    tierBasic: TierBasic;
+    tierPremium: TierPremium;
+};
+
+export const SCHEMA = new Map<any, any>([
+    [
+            PriorityHigh,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            PriorityLow,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            Priority,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['priorityLow', PriorityLow],
+			['priorityHigh', PriorityHigh],
+                ],
+            },
+    ],
+    [
+            RandomStruct,
+            {
+                kind: 'struct', fields: [
+			['fieldA', 'string'],
+			['fieldB', { kind: 'option', type: [2] }],
+                ],
+            },
+    ],
+    [
+            StatusActive,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            StatusFrozen,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            StatusInactive,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            Status,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['unused0', 'u8'],
+			['unused1', 'u8'],
+			['unused2', 'u8'],
+			['unused3', 'u8'],
+			['unused4', 'u8'],
+			['statusInactive', StatusInactive],
+			['unused6', 'u8'],
+			['unused7', 'u8'],
+			['unused8', 'u8'],
+			['unused9', 'u8'],
+			['statusActive', StatusActive],
+			['statusFrozen', StatusFrozen],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantA,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TestEnumVariantB,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TestEnumVariantC,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantD,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', { kind: 'option', type: 'publicKey' }],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantE,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', { kind: 'option', type: 'u8' }],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantF,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', RandomStruct],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantG,
+            {
+                kind: 'struct', fields: [
+			['hello', ['u8']],
+			['bello', ['publicKey', 3]],
+			['yello', 'u16'],
+			['zello', 'u8'],
+                ],
+            },
+    ],
+    [
+            TestEnum,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['testEnumVariantA', TestEnumVariantA],
+			['testEnumVariantB', TestEnumVariantB],
+			['testEnumVariantC', TestEnumVariantC],
+			['testEnumVariantD', TestEnumVariantD],
+			['testEnumVariantE', TestEnumVariantE],
+			['testEnumVariantF', TestEnumVariantF],
+			['testEnumVariantG', TestEnumVariantG],
+                ],
+            },
+    ],
+    [
+            TierBasic,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TierPremium,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            Tier,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['tierBasic', TierBasic],
+			['tierPremium', TierPremium],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
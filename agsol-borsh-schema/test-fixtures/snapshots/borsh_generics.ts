@@ -0,0 +1,106 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+import { borshPublicKey } from "./extensions/publicKey";
+
+borshPublicKey();
+
+export class EitherLeftU8WrapperString extends Struct {
+    unnamed_0: number;
+};
+
+export class WrapperPubkey extends Struct {
+    inner: PublicKey;
+    history: PublicKey[];
+};
+
+export class WrapperString extends Struct {
+    inner: string;
+    history: string[];
+};
+
+export class EitherRightU8WrapperString extends Struct {
+    unnamed_0: WrapperString;
+};
+
+export class EitherU8WrapperString extends Enum {
+    eitherLeft: EitherLeftU8WrapperString;
+    eitherRight: EitherRightU8WrapperString;
+};
+
+export class WrapperU64 extends Struct {
+    inner: BN;
+    history: BN[];
+};
+
+export class GenericContainer extends Struct {
+    amount: WrapperU64;
+    owner: WrapperPubkey;
+    choice: EitherU8WrapperString;
+};
+
+export const SCHEMA = new Map<any, any>([
+    [
+            EitherLeftU8WrapperString,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u8'],
+                ],
+            },
+    ],
+    [
+            WrapperPubkey,
+            {
+                kind: 'struct', fields: [
+			['inner', 'publicKey'],
+			['history', ['publicKey']],
+                ],
+            },
+    ],
+    [
+            WrapperString,
+            {
+                kind: 'struct', fields: [
+			['inner', 'string'],
+			['history', ['string']],
+                ],
+            },
+    ],
+    [
+            EitherRightU8WrapperString,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', WrapperString],
+                ],
+            },
+    ],
+    [
+            EitherU8WrapperString,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['eitherLeft', EitherLeftU8WrapperString],
+			['eitherRight', EitherRightU8WrapperString],
+                ],
+            },
+    ],
+    [
+            WrapperU64,
+            {
+                kind: 'struct', fields: [
+			['inner', 'u64'],
+			['history', ['u64']],
+                ],
+            },
+    ],
+    [
+            GenericContainer,
+            {
+                kind: 'struct', fields: [
+			['amount', WrapperU64],
+			['owner', WrapperPubkey],
+			['choice', EitherU8WrapperString],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
@@ -0,0 +1,116 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+import { borshPublicKey } from "./extensions/publicKey";
+
+borshPublicKey();
+
+export class Pair extends Struct {
+    unnamed_0: BN;
+    unnamed_1: PublicKey;
+};
+
+export class TupleInstructionClose extends Struct {
+    refund: boolean;
+};
+
+export class TupleInstructionDeposit extends Struct {
+    unnamed_0: BN;
+};
+
+export class TupleInstructionReset extends Struct {
+};
+
+export class TupleInstructionSkipped extends Struct {
+};
+
+export class TupleInstructionSwap extends Struct {
+    unnamed_0: Pair;
+};
+
+export class TupleInstructionTransfer extends Struct {
+    unnamed_0: PublicKey;
+    unnamed_1: BN;
+};
+
+export class TupleInstruction extends Enum {
+    tupleInstructionReset: TupleInstructionReset;
+    tupleInstructionDeposit: TupleInstructionDeposit;
+    tupleInstructionTransfer: TupleInstructionTransfer;
+    tupleInstructionSwap: TupleInstructionSwap;
+    tupleInstructionSkipped: TupleInstructionSkipped;
+    tupleInstructionClose: TupleInstructionClose;
+};
+
+export const SCHEMA = new Map<any, any>([
+    [
+            Pair,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+			['unnamed_1', 'publicKey'],
+                ],
+            },
+    ],
+    [
+            TupleInstructionClose,
+            {
+                kind: 'struct', fields: [
+			['refund', 'u8'],
+                ],
+            },
+    ],
+    [
+            TupleInstructionDeposit,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            TupleInstructionReset,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TupleInstructionSkipped,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TupleInstructionSwap,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', Pair],
+                ],
+            },
+    ],
+    [
+            TupleInstructionTransfer,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'publicKey'],
+			['unnamed_1', 'u64'],
+                ],
+            },
+    ],
+    [
+            TupleInstruction,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['tupleInstructionReset', TupleInstructionReset],
+			['tupleInstructionDeposit', TupleInstructionDeposit],
+			['tupleInstructionTransfer', TupleInstructionTransfer],
+			['tupleInstructionSwap', TupleInstructionSwap],
+			['tupleInstructionSkipped', TupleInstructionSkipped],
+			['tupleInstructionClose', TupleInstructionClose],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
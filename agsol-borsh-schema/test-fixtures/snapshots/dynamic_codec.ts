@@ -0,0 +1,144 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { BinaryReader, BinaryWriter } from "borsh";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+import { borshPublicKey } from "./extensions/publicKey";
+
+borshPublicKey();
+
+(BinaryReader.prototype as any).readI8 = function (this: BinaryReader) {
+    return (this.readU8() << 24) >> 24;
+};
+(BinaryReader.prototype as any).readI16 = function (this: BinaryReader) {
+    return (this.readU16() << 16) >> 16;
+};
+(BinaryReader.prototype as any).readI32 = function (this: BinaryReader) {
+    return this.readU32() | 0;
+};
+(BinaryReader.prototype as any).readI64 = function (this: BinaryReader) {
+    return this.readU64().fromTwos(64);
+};
+(BinaryReader.prototype as any).readI128 = function (this: BinaryReader) {
+    return this.readU128().fromTwos(128);
+};
+(BinaryWriter.prototype as any).writeI8 = function (this: BinaryWriter, value: number) {
+    this.writeU8(value & 0xff);
+};
+(BinaryWriter.prototype as any).writeI16 = function (this: BinaryWriter, value: number) {
+    this.writeU16(value & 0xffff);
+};
+(BinaryWriter.prototype as any).writeI32 = function (this: BinaryWriter, value: number) {
+    this.writeU32(value >>> 0);
+};
+(BinaryWriter.prototype as any).writeI64 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU64(new BN(value).toTwos(64));
+};
+(BinaryWriter.prototype as any).writeI128 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU128(new BN(value).toTwos(128));
+};
+
+export class EntryClose extends Struct {
+};
+
+export class EntryDeposit extends Struct {
+    unnamed_0: BN;
+};
+
+export class EntryWithdraw extends Struct {
+    amount: BN;
+    fee: number | null;
+};
+
+export class Entry extends Enum {
+    entryDeposit: EntryDeposit;
+    entryWithdraw: EntryWithdraw;
+    entryClose: EntryClose;
+};
+
+export class TupleU16Bool {
+    constructor(fields: { 0: number; 1: boolean }) {
+        return Object.defineProperty([fields[0], fields[1]], "constructor", { value: TupleU16Bool });
+    }
+};
+
+export class Vault extends Struct {
+    owner: PublicKey;
+    balance: BN;
+    supply: BN;
+    delta: BN;
+    label: string;
+    history: Entry[];
+    limits: Map<number, number>;
+    seed: [4];
+    pair: [number, boolean];
+    note: string | null;
+
+    constructor(properties: any) {
+        super(properties);
+        this.pair = new TupleU16Bool(this.pair) as [number, boolean];
+    }
+};
+
+export const SCHEMA = new Map<any, any>([
+    [
+            EntryClose,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            EntryDeposit,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            EntryWithdraw,
+            {
+                kind: 'struct', fields: [
+			['amount', 'u64'],
+			['fee', { kind: 'option', type: 'u16' }],
+                ],
+            },
+    ],
+    [
+            Entry,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['entryDeposit', EntryDeposit],
+			['entryWithdraw', EntryWithdraw],
+			['entryClose', EntryClose],
+                ],
+            },
+    ],
+    [
+            TupleU16Bool,
+            {
+                kind: 'struct', fields: [
+			['0', 'u16'],
+			['1', 'u8'],
+                ],
+            },
+    ],
+    [
+            Vault,
+            {
+                kind: 'struct', fields: [
+			['owner', 'publicKey'],
+			['balance', 'u64'],
+			['supply', 'u128'],
+			['delta', 'i128'],
+			['label', 'string'],
+			['history', [Entry]],
+			['limits', { kind: 'map', key: 'u8', value: 'i32' }],
+			['seed', [4]],
+			['pair', TupleU16Bool],
+			['note', { kind: 'option', type: 'string' }],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
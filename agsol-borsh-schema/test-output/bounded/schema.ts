@@ -0,0 +1,175 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { BinaryReader, BinaryWriter, deserializeUnchecked, serialize } from "borsh";
+
+(BinaryReader.prototype as any).readPublicKey = function (this: BinaryReader) {
+    return new PublicKey(this.readFixedArray(32));
+};
+(BinaryWriter.prototype as any).writePublicKey = function (this: BinaryWriter, value: PublicKey) {
+    this.writeFixedArray(value.toBytes());
+};
+(BinaryReader.prototype as any).readI8 = function (this: BinaryReader) {
+    return (this.readU8() << 24) >> 24;
+};
+(BinaryReader.prototype as any).readI16 = function (this: BinaryReader) {
+    return (this.readU16() << 16) >> 16;
+};
+(BinaryReader.prototype as any).readI32 = function (this: BinaryReader) {
+    return this.readU32() | 0;
+};
+(BinaryReader.prototype as any).readI64 = function (this: BinaryReader) {
+    return this.readU64().fromTwos(64);
+};
+(BinaryReader.prototype as any).readI128 = function (this: BinaryReader) {
+    return this.readU128().fromTwos(128);
+};
+(BinaryWriter.prototype as any).writeI8 = function (this: BinaryWriter, value: number) {
+    this.writeU8(value & 0xff);
+};
+(BinaryWriter.prototype as any).writeI16 = function (this: BinaryWriter, value: number) {
+    this.writeU16(value & 0xffff);
+};
+(BinaryWriter.prototype as any).writeI32 = function (this: BinaryWriter, value: number) {
+    this.writeU32(value >>> 0);
+};
+(BinaryWriter.prototype as any).writeI64 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU64(new BN(value).toTwos(64));
+};
+(BinaryWriter.prototype as any).writeI128 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU128(new BN(value).toTwos(128));
+};
+
+const writeArray = BinaryWriter.prototype.writeArray;
+(BinaryWriter.prototype as any).writeArray = function (this: BinaryWriter, array: any[] | Set<any>, fn: any) {
+    writeArray.call(this, array instanceof Set ? Array.from(array) : array, fn);
+};
+
+export class LockedItemU64 {
+    item: BN;
+    expires: BN;
+
+    constructor(fields: {
+        item: BN,
+        expires: BN,
+    }) {
+        this.item = fields.item;
+        this.expires = fields.expires;
+    }
+
+    static decode(buf: Buffer): LockedItemU64 {
+        return deserializeUnchecked(SCHEMA, LockedItemU64, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class Guild {
+    name: string;
+    admins: PublicKey[];
+    members: PublicKey[];
+    roles: Map<number, string>;
+    lockedFunds: LockedItemU64[];
+    lastLock: LockedItemU64 | null;
+
+    constructor(fields: {
+        name: string,
+        admins: PublicKey[],
+        members: PublicKey[],
+        roles: Map<number, string>,
+        lockedFunds: LockedItemU64[],
+        lastLock: LockedItemU64 | null,
+    }) {
+        this.name = fields.name;
+        this.admins = fields.admins;
+        this.members = fields.members;
+        this.roles = fields.roles;
+        this.lockedFunds = fields.lockedFunds;
+        this.lastLock = fields.lastLock;
+    }
+
+    static decode(buf: Buffer): Guild {
+        return deserializeUnchecked(SCHEMA, Guild, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export const GUILD_NAME_MAX_LEN = 32;
+export const GUILD_ADMINS_MAX_LEN = 8;
+export const GUILD_ROLES_MAX_LEN = 4;
+export const GUILD_LOCKED_FUNDS_MAX_LEN = 10;
+
+export const GUILD_DISCRIMINATOR = Buffer.from([74, 176, 57, 164, 195, 188, 156, 237]);
+
+/** The funds of a guild. */
+export class Treasury {
+    guild: PublicKey;
+    balance: BN;
+    spenders: PublicKey[];
+    lastLock: LockedItemU64 | null;
+
+    constructor(fields: {
+        guild: PublicKey,
+        balance: BN,
+        spenders: PublicKey[],
+        lastLock: LockedItemU64 | null,
+    }) {
+        this.guild = fields.guild;
+        this.balance = fields.balance;
+        this.spenders = fields.spenders;
+        this.lastLock = fields.lastLock;
+    }
+
+    static decode(buf: Buffer): Treasury {
+        return deserializeUnchecked(SCHEMA, Treasury, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export const TREASURY_SPENDERS_MAX_LEN = 4;
+
+export const TREASURY_MAX_SERIALIZED_LEN = 189;
+export const TREASURY_DISCRIMINATOR = Buffer.from([238, 239, 123, 238, 89, 1, 168, 253]);
+
+export const SCHEMA = new Map<any, any>([
+    [
+            LockedItemU64,
+            {
+                kind: 'struct', fields: [
+			['item', 'u64'],
+			['expires', 'i64'],
+                ],
+            },
+    ],
+    [
+            Guild,
+            {
+                kind: 'struct', fields: [
+			['name', 'string'],
+			['admins', ['publicKey']],
+			['members', ['publicKey']],
+			['roles', { kind: 'map', key: 'u8', value: 'string' }],
+			['lockedFunds', [LockedItemU64]],
+			['lastLock', { kind: 'option', type: LockedItemU64 }],
+                ],
+            },
+    ],
+    [
+            Treasury,
+            {
+                kind: 'struct', fields: [
+			['guild', 'publicKey'],
+			['balance', 'u64'],
+			['spenders', ['publicKey']],
+			['lastLock', { kind: 'option', type: LockedItemU64 }],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
@@ -0,0 +1,247 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import BN from "bn.js";
+import { BinaryReader, BinaryWriter, deserializeUnchecked, serialize } from "borsh";
+
+(BinaryReader.prototype as any).readPublicKey = function (this: BinaryReader) {
+    return new PublicKey(this.readFixedArray(32));
+};
+(BinaryWriter.prototype as any).writePublicKey = function (this: BinaryWriter, value: PublicKey) {
+    this.writeFixedArray(value.toBytes());
+};
+(BinaryReader.prototype as any).readI8 = function (this: BinaryReader) {
+    return (this.readU8() << 24) >> 24;
+};
+(BinaryReader.prototype as any).readI16 = function (this: BinaryReader) {
+    return (this.readU16() << 16) >> 16;
+};
+(BinaryReader.prototype as any).readI32 = function (this: BinaryReader) {
+    return this.readU32() | 0;
+};
+(BinaryReader.prototype as any).readI64 = function (this: BinaryReader) {
+    return this.readU64().fromTwos(64);
+};
+(BinaryReader.prototype as any).readI128 = function (this: BinaryReader) {
+    return this.readU128().fromTwos(128);
+};
+(BinaryWriter.prototype as any).writeI8 = function (this: BinaryWriter, value: number) {
+    this.writeU8(value & 0xff);
+};
+(BinaryWriter.prototype as any).writeI16 = function (this: BinaryWriter, value: number) {
+    this.writeU16(value & 0xffff);
+};
+(BinaryWriter.prototype as any).writeI32 = function (this: BinaryWriter, value: number) {
+    this.writeU32(value >>> 0);
+};
+(BinaryWriter.prototype as any).writeI64 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU64(new BN(value).toTwos(64));
+};
+(BinaryWriter.prototype as any).writeI128 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU128(new BN(value).toTwos(128));
+};
+
+const writeArray = BinaryWriter.prototype.writeArray;
+(BinaryWriter.prototype as any).writeArray = function (this: BinaryWriter, array: any[] | Set<any>, fn: any) {
+    writeArray.call(this, array instanceof Set ? Array.from(array) : array, fn);
+};
+
+export class PoolInstructionClose {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): PoolInstructionClose {
+        return deserializeUnchecked(SCHEMA, PoolInstructionClose, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class PoolInstructionDeposit {
+    unnamed_0: BN;
+
+    constructor(fields: {
+        unnamed_0: BN,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): PoolInstructionDeposit {
+        return deserializeUnchecked(SCHEMA, PoolInstructionDeposit, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+/** Creates a new pool. */
+export class PoolInstructionInitialize {
+    fee: number;
+
+    constructor(fields: {
+        fee: number,
+    }) {
+        this.fee = fields.fee;
+    }
+
+    static decode(buf: Buffer): PoolInstructionInitialize {
+        return deserializeUnchecked(SCHEMA, PoolInstructionInitialize, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class PoolInstructionPing {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): PoolInstructionPing {
+        return deserializeUnchecked(SCHEMA, PoolInstructionPing, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class PoolInstruction {
+    enum: string;
+    /** Creates a new pool. */
+    poolInstructionInitialize?: PoolInstructionInitialize;
+    poolInstructionDeposit?: PoolInstructionDeposit;
+    poolInstructionClose?: PoolInstructionClose;
+    poolInstructionPing?: PoolInstructionPing;
+
+    constructor(fields: { poolInstructionInitialize: PoolInstructionInitialize } | { poolInstructionDeposit: PoolInstructionDeposit } | { poolInstructionClose: PoolInstructionClose } | { poolInstructionPing: PoolInstructionPing }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): PoolInstruction {
+        return deserializeUnchecked(SCHEMA, PoolInstruction, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+/** Creates a new pool. */
+export function buildPoolInstructionInitialize(
+    programId: PublicKey,
+    accounts: {
+        payer: PublicKey;
+        pool: PublicKey;
+        systemProgram: PublicKey;
+    },
+    args: PoolInstructionInitialize,
+): TransactionInstruction {
+    const data = Buffer.from(serialize(SCHEMA, new PoolInstruction({ poolInstructionInitialize: args })));
+    return new TransactionInstruction({
+        programId,
+        keys: [
+            { pubkey: accounts.payer, isSigner: true, isWritable: true },
+            { pubkey: accounts.pool, isSigner: false, isWritable: true },
+            { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+        ],
+        data,
+    });
+}
+
+export function buildPoolInstructionDeposit(
+    programId: PublicKey,
+    accounts: {
+        owner: PublicKey;
+        pool: PublicKey;
+    },
+    args: PoolInstructionDeposit,
+): TransactionInstruction {
+    const data = Buffer.from(serialize(SCHEMA, new PoolInstruction({ poolInstructionDeposit: args })));
+    return new TransactionInstruction({
+        programId,
+        keys: [
+            { pubkey: accounts.owner, isSigner: true, isWritable: false },
+            { pubkey: accounts.pool, isSigner: false, isWritable: true },
+        ],
+        data,
+    });
+}
+
+export function buildPoolInstructionClose(
+    programId: PublicKey,
+    accounts: {
+        owner: PublicKey;
+        pool: PublicKey;
+    },
+): TransactionInstruction {
+    const data = Buffer.from(serialize(SCHEMA, new PoolInstruction({ poolInstructionClose: new PoolInstructionClose({}) })));
+    return new TransactionInstruction({
+        programId,
+        keys: [
+            { pubkey: accounts.owner, isSigner: true, isWritable: true },
+            { pubkey: accounts.pool, isSigner: false, isWritable: true },
+        ],
+        data,
+    });
+}
+
+export function buildPoolInstructionPing(
+    programId: PublicKey,
+): TransactionInstruction {
+    const data = Buffer.from(serialize(SCHEMA, new PoolInstruction({ poolInstructionPing: new PoolInstructionPing({}) })));
+    return new TransactionInstruction({
+        programId,
+        keys: [],
+        data,
+    });
+}
+
+export const SCHEMA = new Map<any, any>([
+    [
+            PoolInstructionClose,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            PoolInstructionDeposit,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            PoolInstructionInitialize,
+            {
+                kind: 'struct', fields: [
+			['fee', 'u16'],
+                ],
+            },
+    ],
+    [
+            PoolInstructionPing,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            PoolInstruction,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['poolInstructionInitialize', PoolInstructionInitialize],
+			['poolInstructionDeposit', PoolInstructionDeposit],
+			['poolInstructionClose', PoolInstructionClose],
+			['poolInstructionPing', PoolInstructionPing],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
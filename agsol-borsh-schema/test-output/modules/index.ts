@@ -0,0 +1,78 @@
+import { borshPublicKey } from "./extensions/publicKey";
+import { Root } from "./lib";
+import { InstructionReset, InstructionState, InstructionUpdate, Instruction } from "./instruction";
+import { Fraction } from "./math";
+import { StateState } from "./state";
+
+export * from "./lib";
+export * from "./instruction";
+export * from "./math";
+export * from "./state";
+
+borshPublicKey();
+
+export const SCHEMA = new Map<any, any>([
+    [
+            Fraction,
+            {
+                kind: 'struct', fields: [
+			['numerator', 'u64'],
+			['denominator', 'u64'],
+                ],
+            },
+    ],
+    [
+            InstructionReset,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            StateState,
+            {
+                kind: 'struct', fields: [
+			['amount', 'u64'],
+			['owner', 'publicKey'],
+                ],
+            },
+    ],
+    [
+            InstructionState,
+            {
+                kind: 'struct', fields: [
+			['previous', StateState],
+			['next', StateState],
+			['ratio', Fraction],
+			['amounts', ['u64']],
+                ],
+            },
+    ],
+    [
+            InstructionUpdate,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', InstructionState],
+                ],
+            },
+    ],
+    [
+            Instruction,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['instructionUpdate', InstructionUpdate],
+			['instructionReset', InstructionReset],
+                ],
+            },
+    ],
+    [
+            Root,
+            {
+                kind: 'struct', fields: [
+			['state', StateState],
+			['fraction', Fraction],
+			['amount', 'u64'],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
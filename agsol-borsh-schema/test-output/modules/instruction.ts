@@ -0,0 +1,25 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+import { Fraction } from "./math";
+import { StateState } from "./state";
+
+export class InstructionReset extends Struct {
+};
+
+export class InstructionState extends Struct {
+    previous: StateState;
+    next: StateState;
+    ratio: Fraction;
+    amounts: BN[];
+};
+
+export class InstructionUpdate extends Struct {
+    unnamed_0: InstructionState;
+};
+
+export class Instruction extends Enum {
+    instructionUpdate: InstructionUpdate;
+    instructionReset: InstructionReset;
+};
@@ -0,0 +1,12 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+import { Fraction } from "./math";
+import { StateState } from "./state";
+
+export class Root extends Struct {
+    state: StateState;
+    fraction: Fraction;
+    amount: BN;
+};
@@ -0,0 +1,9 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+
+export class StateState extends Struct {
+    amount: BN;
+    owner: PublicKey;
+};
@@ -0,0 +1,1207 @@
+from __future__ import annotations
+
+import typing
+from dataclasses import dataclass, fields
+
+from borsh_construct import (
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Bool,
+    CStruct,
+    HashMap,
+    HashSet,
+    Option,
+    String,
+    Vec,
+)
+from construct import Adapter, Array, Error, LazyBound, Struct, Switch, this
+from construct import Bytes as FixedBytes
+
+BorshPubkey = FixedBytes(32)
+
+
+class _StructAdapter(Adapter):
+    def __init__(self, cls, subcon):
+        super().__init__(subcon)
+        self.cls = cls
+
+    def _decode(self, obj, context, path):
+        return self.cls(**{key: value for key, value in obj.items() if not key.startswith("_")})
+
+    def _encode(self, obj, context, path):
+        return {field.name: getattr(obj, field.name) for field in fields(obj)}
+
+
+class _EnumAdapter(Adapter):
+    def __init__(self, cls, kinds, subcon):
+        super().__init__(subcon)
+        self.cls = cls
+        self.kinds = kinds
+        self.indices = {kind: index for index, kind in kinds.items()}
+
+    def _decode(self, obj, context, path):
+        return self.cls(self.kinds[obj.index], obj.value)
+
+    def _encode(self, obj, context, path):
+        return {"index": self.indices[obj.kind], "value": obj.value}
+
+
+def _struct(cls, *subcons):
+    return _StructAdapter(cls, CStruct(*subcons))
+
+
+def _enum(cls, *variants):
+    # variants are (kind, subcon) or (kind, subcon, discriminant) tuples
+    indices = [variant[2] if len(variant) > 2 else index for index, variant in enumerate(variants)]
+    cases = {index: variant[1] for index, variant in zip(indices, variants)}
+    subcon = Struct("index" / U8, "value" / Switch(this.index, cases, default=Error))
+    return _EnumAdapter(cls, {index: variant[0] for index, variant in zip(indices, variants)}, subcon)
+
+
+@dataclass
+class BTreeWrapper:
+    map0: typing.Dict[bytes, bytes]
+    map1: typing.Dict[str, typing.Optional[int]]
+    map2: typing.Dict[int, str]
+
+    @classmethod
+    def decode(cls, data: bytes) -> BTreeWrapper:
+        return B_TREE_WRAPPER_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return B_TREE_WRAPPER_LAYOUT.build(self)
+
+
+B_TREE_WRAPPER_LAYOUT = _struct(
+    BTreeWrapper,
+    "map0" / HashMap(FixedBytes(32), BorshPubkey),
+    "map1" / HashMap(String, Option(U32)),
+    "map2" / HashMap(U16, String),
+)
+
+
+@dataclass
+class BoxedState:
+    amount: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> BoxedState:
+        return BOXED_STATE_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return BOXED_STATE_LAYOUT.build(self)
+
+
+BOXED_STATE_LAYOUT = _struct(
+    BoxedState,
+    "amount" / U32,
+)
+
+
+@dataclass
+class EitherLeftU8WrapperString:
+    unnamed_0: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> EitherLeftU8WrapperString:
+        return EITHER_LEFT_U8_WRAPPER_STRING_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return EITHER_LEFT_U8_WRAPPER_STRING_LAYOUT.build(self)
+
+
+EITHER_LEFT_U8_WRAPPER_STRING_LAYOUT = _struct(
+    EitherLeftU8WrapperString,
+    "unnamed_0" / U8,
+)
+
+
+@dataclass
+class EntryClose:
+    @classmethod
+    def decode(cls, data: bytes) -> EntryClose:
+        return ENTRY_CLOSE_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return ENTRY_CLOSE_LAYOUT.build(self)
+
+
+ENTRY_CLOSE_LAYOUT = _struct(
+    EntryClose,
+)
+
+
+@dataclass
+class EntryDeposit:
+    unnamed_0: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> EntryDeposit:
+        return ENTRY_DEPOSIT_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return ENTRY_DEPOSIT_LAYOUT.build(self)
+
+
+ENTRY_DEPOSIT_LAYOUT = _struct(
+    EntryDeposit,
+    "unnamed_0" / U64,
+)
+
+
+@dataclass
+class EntryWithdraw:
+    amount: int
+    fee: typing.Optional[int]
+
+    @classmethod
+    def decode(cls, data: bytes) -> EntryWithdraw:
+        return ENTRY_WITHDRAW_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return ENTRY_WITHDRAW_LAYOUT.build(self)
+
+
+ENTRY_WITHDRAW_LAYOUT = _struct(
+    EntryWithdraw,
+    "amount" / U64,
+    "fee" / Option(U16),
+)
+
+
+@dataclass
+class Entry:
+    kind: str
+    value: typing.Union[EntryDeposit, EntryWithdraw, EntryClose]
+
+    @classmethod
+    def decode(cls, data: bytes) -> Entry:
+        return ENTRY_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return ENTRY_LAYOUT.build(self)
+
+
+ENTRY_LAYOUT = _enum(
+    Entry,
+    ("Deposit", LazyBound(lambda: ENTRY_DEPOSIT_LAYOUT)),
+    ("Withdraw", LazyBound(lambda: ENTRY_WITHDRAW_LAYOUT)),
+    ("Close", LazyBound(lambda: ENTRY_CLOSE_LAYOUT)),
+)
+
+
+@dataclass
+class MintConfig:
+    max_supply: int
+    mint_authority: bytes
+    decimals: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> MintConfig:
+        return MINT_CONFIG_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return MINT_CONFIG_LAYOUT.build(self)
+
+
+MINT_CONFIG_LAYOUT = _struct(
+    MintConfig,
+    "max_supply" / U64,
+    "mint_authority" / FixedBytes(32),
+    "decimals" / U8,
+)
+
+
+@dataclass
+class MintUpdateDecimals:
+    decimals: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> MintUpdateDecimals:
+        return MINT_UPDATE_DECIMALS_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return MINT_UPDATE_DECIMALS_LAYOUT.build(self)
+
+
+MINT_UPDATE_DECIMALS_LAYOUT = _struct(
+    MintUpdateDecimals,
+    "decimals" / U8,
+)
+
+
+@dataclass
+class MintUpdateSupply:
+    new_max_supply: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> MintUpdateSupply:
+        return MINT_UPDATE_SUPPLY_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return MINT_UPDATE_SUPPLY_LAYOUT.build(self)
+
+
+MINT_UPDATE_SUPPLY_LAYOUT = _struct(
+    MintUpdateSupply,
+    "new_max_supply" / U64,
+)
+
+
+@dataclass
+class MintUpdate:
+    kind: str
+    value: typing.Union[MintUpdateSupply, MintUpdateDecimals]
+
+    @classmethod
+    def decode(cls, data: bytes) -> MintUpdate:
+        return MINT_UPDATE_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return MINT_UPDATE_LAYOUT.build(self)
+
+
+MINT_UPDATE_LAYOUT = _enum(
+    MintUpdate,
+    ("Supply", LazyBound(lambda: MINT_UPDATE_SUPPLY_LAYOUT)),
+    ("Decimals", LazyBound(lambda: MINT_UPDATE_DECIMALS_LAYOUT)),
+)
+
+
+@dataclass
+class OtherState:
+    amount: int
+    timestamp: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> OtherState:
+        return OTHER_STATE_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return OTHER_STATE_LAYOUT.build(self)
+
+
+OTHER_STATE_LAYOUT = _struct(
+    OtherState,
+    "amount" / U64,
+    "timestamp" / I64,
+)
+
+
+@dataclass
+class AliasedStruct:
+    pool: typing.Optional[typing.List[OtherState]]
+    amounts: typing.List[int]
+    overridden: int
+    key: bytes
+
+    @classmethod
+    def decode(cls, data: bytes) -> AliasedStruct:
+        return ALIASED_STRUCT_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return ALIASED_STRUCT_LAYOUT.build(self)
+
+
+ALIASED_STRUCT_LAYOUT = _struct(
+    AliasedStruct,
+    "pool" / Option(Vec(LazyBound(lambda: OTHER_STATE_LAYOUT))),
+    "amounts" / Vec(U64),
+    "overridden" / U64,
+    "key" / FixedBytes(32),
+)
+
+
+@dataclass
+class Pair:
+    unnamed_0: int
+    unnamed_1: bytes
+
+    @classmethod
+    def decode(cls, data: bytes) -> Pair:
+        return PAIR_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return PAIR_LAYOUT.build(self)
+
+
+PAIR_LAYOUT = _struct(
+    Pair,
+    "unnamed_0" / U64,
+    "unnamed_1" / BorshPubkey,
+)
+
+
+@dataclass
+class PoolInstructionClose:
+    @classmethod
+    def decode(cls, data: bytes) -> PoolInstructionClose:
+        return POOL_INSTRUCTION_CLOSE_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return POOL_INSTRUCTION_CLOSE_LAYOUT.build(self)
+
+
+POOL_INSTRUCTION_CLOSE_LAYOUT = _struct(
+    PoolInstructionClose,
+)
+
+
+@dataclass
+class PoolInstructionDeposit:
+    unnamed_0: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> PoolInstructionDeposit:
+        return POOL_INSTRUCTION_DEPOSIT_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return POOL_INSTRUCTION_DEPOSIT_LAYOUT.build(self)
+
+
+POOL_INSTRUCTION_DEPOSIT_LAYOUT = _struct(
+    PoolInstructionDeposit,
+    "unnamed_0" / U64,
+)
+
+
+@dataclass
+class PoolInstructionInitialize:
+    fee: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> PoolInstructionInitialize:
+        return POOL_INSTRUCTION_INITIALIZE_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return POOL_INSTRUCTION_INITIALIZE_LAYOUT.build(self)
+
+
+POOL_INSTRUCTION_INITIALIZE_LAYOUT = _struct(
+    PoolInstructionInitialize,
+    "fee" / U16,
+)
+
+
+@dataclass
+class PoolInstructionPing:
+    @classmethod
+    def decode(cls, data: bytes) -> PoolInstructionPing:
+        return POOL_INSTRUCTION_PING_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return POOL_INSTRUCTION_PING_LAYOUT.build(self)
+
+
+POOL_INSTRUCTION_PING_LAYOUT = _struct(
+    PoolInstructionPing,
+)
+
+
+@dataclass
+class PoolInstruction:
+    kind: str
+    value: typing.Union[PoolInstructionInitialize, PoolInstructionDeposit, PoolInstructionClose, PoolInstructionPing]
+
+    @classmethod
+    def decode(cls, data: bytes) -> PoolInstruction:
+        return POOL_INSTRUCTION_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return POOL_INSTRUCTION_LAYOUT.build(self)
+
+
+POOL_INSTRUCTION_LAYOUT = _enum(
+    PoolInstruction,
+    ("Initialize", LazyBound(lambda: POOL_INSTRUCTION_INITIALIZE_LAYOUT)),
+    ("Deposit", LazyBound(lambda: POOL_INSTRUCTION_DEPOSIT_LAYOUT)),
+    ("Close", LazyBound(lambda: POOL_INSTRUCTION_CLOSE_LAYOUT)),
+    ("Ping", LazyBound(lambda: POOL_INSTRUCTION_PING_LAYOUT)),
+)
+
+
+@dataclass
+class PriorityHigh:
+    @classmethod
+    def decode(cls, data: bytes) -> PriorityHigh:
+        return PRIORITY_HIGH_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return PRIORITY_HIGH_LAYOUT.build(self)
+
+
+PRIORITY_HIGH_LAYOUT = _struct(
+    PriorityHigh,
+)
+
+
+@dataclass
+class PriorityLow:
+    @classmethod
+    def decode(cls, data: bytes) -> PriorityLow:
+        return PRIORITY_LOW_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return PRIORITY_LOW_LAYOUT.build(self)
+
+
+PRIORITY_LOW_LAYOUT = _struct(
+    PriorityLow,
+)
+
+
+@dataclass
+class Priority:
+    kind: str
+    value: typing.Union[PriorityLow, PriorityHigh]
+
+    @classmethod
+    def decode(cls, data: bytes) -> Priority:
+        return PRIORITY_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return PRIORITY_LAYOUT.build(self)
+
+
+PRIORITY_LAYOUT = _enum(
+    Priority,
+    ("Low", LazyBound(lambda: PRIORITY_LOW_LAYOUT)),
+    ("High", LazyBound(lambda: PRIORITY_HIGH_LAYOUT)),
+)
+
+
+@dataclass
+class RandomStruct:
+    field_a: str
+    field_b: typing.Optional[bytes]
+
+    @classmethod
+    def decode(cls, data: bytes) -> RandomStruct:
+        return RANDOM_STRUCT_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return RANDOM_STRUCT_LAYOUT.build(self)
+
+
+RANDOM_STRUCT_LAYOUT = _struct(
+    RandomStruct,
+    "field_a" / String,
+    "field_b" / Option(FixedBytes(2)),
+)
+
+
+@dataclass
+class SignedStruct:
+    field_a: int
+    field_b: int
+    field_c: int
+    field_d: int
+    field_e: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> SignedStruct:
+        return SIGNED_STRUCT_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return SIGNED_STRUCT_LAYOUT.build(self)
+
+
+SIGNED_STRUCT_LAYOUT = _struct(
+    SignedStruct,
+    "field_a" / I8,
+    "field_b" / I16,
+    "field_c" / I32,
+    "field_d" / I64,
+    "field_e" / I128,
+)
+
+
+@dataclass
+class StatusActive:
+    @classmethod
+    def decode(cls, data: bytes) -> StatusActive:
+        return STATUS_ACTIVE_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return STATUS_ACTIVE_LAYOUT.build(self)
+
+
+STATUS_ACTIVE_LAYOUT = _struct(
+    StatusActive,
+)
+
+
+@dataclass
+class StatusFrozen:
+    @classmethod
+    def decode(cls, data: bytes) -> StatusFrozen:
+        return STATUS_FROZEN_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return STATUS_FROZEN_LAYOUT.build(self)
+
+
+STATUS_FROZEN_LAYOUT = _struct(
+    StatusFrozen,
+)
+
+
+@dataclass
+class StatusInactive:
+    @classmethod
+    def decode(cls, data: bytes) -> StatusInactive:
+        return STATUS_INACTIVE_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return STATUS_INACTIVE_LAYOUT.build(self)
+
+
+STATUS_INACTIVE_LAYOUT = _struct(
+    StatusInactive,
+)
+
+
+@dataclass
+class Status:
+    kind: str
+    value: typing.Union[StatusInactive, StatusActive, StatusFrozen]
+
+    @classmethod
+    def decode(cls, data: bytes) -> Status:
+        return STATUS_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return STATUS_LAYOUT.build(self)
+
+
+STATUS_LAYOUT = _enum(
+    Status,
+    ("Inactive", LazyBound(lambda: STATUS_INACTIVE_LAYOUT), 5),
+    ("Active", LazyBound(lambda: STATUS_ACTIVE_LAYOUT), 10),
+    ("Frozen", LazyBound(lambda: STATUS_FROZEN_LAYOUT), 11),
+)
+
+
+@dataclass
+class TestEnumVariantA:
+    @classmethod
+    def decode(cls, data: bytes) -> TestEnumVariantA:
+        return TEST_ENUM_VARIANT_A_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TEST_ENUM_VARIANT_A_LAYOUT.build(self)
+
+
+TEST_ENUM_VARIANT_A_LAYOUT = _struct(
+    TestEnumVariantA,
+)
+
+
+@dataclass
+class TestEnumVariantB:
+    @classmethod
+    def decode(cls, data: bytes) -> TestEnumVariantB:
+        return TEST_ENUM_VARIANT_B_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TEST_ENUM_VARIANT_B_LAYOUT.build(self)
+
+
+TEST_ENUM_VARIANT_B_LAYOUT = _struct(
+    TestEnumVariantB,
+)
+
+
+@dataclass
+class TestEnumVariantC:
+    unnamed_0: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> TestEnumVariantC:
+        return TEST_ENUM_VARIANT_C_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TEST_ENUM_VARIANT_C_LAYOUT.build(self)
+
+
+TEST_ENUM_VARIANT_C_LAYOUT = _struct(
+    TestEnumVariantC,
+    "unnamed_0" / U64,
+)
+
+
+@dataclass
+class TestEnumVariantD:
+    unnamed_0: typing.Optional[bytes]
+
+    @classmethod
+    def decode(cls, data: bytes) -> TestEnumVariantD:
+        return TEST_ENUM_VARIANT_D_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TEST_ENUM_VARIANT_D_LAYOUT.build(self)
+
+
+TEST_ENUM_VARIANT_D_LAYOUT = _struct(
+    TestEnumVariantD,
+    "unnamed_0" / Option(BorshPubkey),
+)
+
+
+@dataclass
+class TestEnumVariantE:
+    unnamed_0: typing.Optional[int]
+
+    @classmethod
+    def decode(cls, data: bytes) -> TestEnumVariantE:
+        return TEST_ENUM_VARIANT_E_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TEST_ENUM_VARIANT_E_LAYOUT.build(self)
+
+
+TEST_ENUM_VARIANT_E_LAYOUT = _struct(
+    TestEnumVariantE,
+    "unnamed_0" / Option(U8),
+)
+
+
+@dataclass
+class TestEnumVariantF:
+    unnamed_0: RandomStruct
+
+    @classmethod
+    def decode(cls, data: bytes) -> TestEnumVariantF:
+        return TEST_ENUM_VARIANT_F_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TEST_ENUM_VARIANT_F_LAYOUT.build(self)
+
+
+TEST_ENUM_VARIANT_F_LAYOUT = _struct(
+    TestEnumVariantF,
+    "unnamed_0" / LazyBound(lambda: RANDOM_STRUCT_LAYOUT),
+)
+
+
+@dataclass
+class TestEnumVariantG:
+    hello: typing.List[int]
+    bello: typing.List[bytes]
+    yello: int
+    zello: bool
+
+    @classmethod
+    def decode(cls, data: bytes) -> TestEnumVariantG:
+        return TEST_ENUM_VARIANT_G_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TEST_ENUM_VARIANT_G_LAYOUT.build(self)
+
+
+TEST_ENUM_VARIANT_G_LAYOUT = _struct(
+    TestEnumVariantG,
+    "hello" / Vec(U8),
+    "bello" / Array(3, BorshPubkey),
+    "yello" / U16,
+    "zello" / Bool,
+)
+
+
+@dataclass
+class TestEnum:
+    kind: str
+    value: typing.Union[TestEnumVariantA, TestEnumVariantB, TestEnumVariantC, TestEnumVariantD, TestEnumVariantE, TestEnumVariantF, TestEnumVariantG]
+
+    @classmethod
+    def decode(cls, data: bytes) -> TestEnum:
+        return TEST_ENUM_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TEST_ENUM_LAYOUT.build(self)
+
+
+TEST_ENUM_LAYOUT = _enum(
+    TestEnum,
+    ("VariantA", LazyBound(lambda: TEST_ENUM_VARIANT_A_LAYOUT)),
+    ("VariantB", LazyBound(lambda: TEST_ENUM_VARIANT_B_LAYOUT)),
+    ("VariantC", LazyBound(lambda: TEST_ENUM_VARIANT_C_LAYOUT)),
+    ("VariantD", LazyBound(lambda: TEST_ENUM_VARIANT_D_LAYOUT)),
+    ("VariantE", LazyBound(lambda: TEST_ENUM_VARIANT_E_LAYOUT)),
+    ("VariantF", LazyBound(lambda: TEST_ENUM_VARIANT_F_LAYOUT)),
+    ("VariantG", LazyBound(lambda: TEST_ENUM_VARIANT_G_LAYOUT)),
+)
+
+
+@dataclass
+class TestStruct:
+    field_a: int
+    field_b: int
+    field_c: typing.Optional[typing.List[OtherState]]
+
+    @classmethod
+    def decode(cls, data: bytes) -> TestStruct:
+        return TEST_STRUCT_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TEST_STRUCT_LAYOUT.build(self)
+
+
+TEST_STRUCT_LAYOUT = _struct(
+    TestStruct,
+    "field_a" / U64,
+    "field_b" / U8,
+    "field_c" / Option(Vec(LazyBound(lambda: OTHER_STATE_LAYOUT))),
+)
+
+
+@dataclass
+class TierBasic:
+    @classmethod
+    def decode(cls, data: bytes) -> TierBasic:
+        return TIER_BASIC_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TIER_BASIC_LAYOUT.build(self)
+
+
+TIER_BASIC_LAYOUT = _struct(
+    TierBasic,
+)
+
+
+@dataclass
+class TierPremium:
+    @classmethod
+    def decode(cls, data: bytes) -> TierPremium:
+        return TIER_PREMIUM_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TIER_PREMIUM_LAYOUT.build(self)
+
+
+TIER_PREMIUM_LAYOUT = _struct(
+    TierPremium,
+)
+
+
+@dataclass
+class Tier:
+    kind: str
+    value: typing.Union[TierBasic, TierPremium]
+
+    @classmethod
+    def decode(cls, data: bytes) -> Tier:
+        return TIER_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TIER_LAYOUT.build(self)
+
+
+TIER_LAYOUT = _enum(
+    Tier,
+    ("Basic", LazyBound(lambda: TIER_BASIC_LAYOUT)),
+    ("Premium", LazyBound(lambda: TIER_PREMIUM_LAYOUT)),
+)
+
+
+@dataclass
+class TupleInstructionClose:
+    refund: bool
+
+    @classmethod
+    def decode(cls, data: bytes) -> TupleInstructionClose:
+        return TUPLE_INSTRUCTION_CLOSE_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_INSTRUCTION_CLOSE_LAYOUT.build(self)
+
+
+TUPLE_INSTRUCTION_CLOSE_LAYOUT = _struct(
+    TupleInstructionClose,
+    "refund" / Bool,
+)
+
+
+@dataclass
+class TupleInstructionDeposit:
+    unnamed_0: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> TupleInstructionDeposit:
+        return TUPLE_INSTRUCTION_DEPOSIT_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_INSTRUCTION_DEPOSIT_LAYOUT.build(self)
+
+
+TUPLE_INSTRUCTION_DEPOSIT_LAYOUT = _struct(
+    TupleInstructionDeposit,
+    "unnamed_0" / U64,
+)
+
+
+@dataclass
+class TupleInstructionReset:
+    @classmethod
+    def decode(cls, data: bytes) -> TupleInstructionReset:
+        return TUPLE_INSTRUCTION_RESET_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_INSTRUCTION_RESET_LAYOUT.build(self)
+
+
+TUPLE_INSTRUCTION_RESET_LAYOUT = _struct(
+    TupleInstructionReset,
+)
+
+
+@dataclass
+class TupleInstructionSkipped:
+    @classmethod
+    def decode(cls, data: bytes) -> TupleInstructionSkipped:
+        return TUPLE_INSTRUCTION_SKIPPED_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_INSTRUCTION_SKIPPED_LAYOUT.build(self)
+
+
+TUPLE_INSTRUCTION_SKIPPED_LAYOUT = _struct(
+    TupleInstructionSkipped,
+)
+
+
+@dataclass
+class TupleInstructionSwap:
+    unnamed_0: Pair
+
+    @classmethod
+    def decode(cls, data: bytes) -> TupleInstructionSwap:
+        return TUPLE_INSTRUCTION_SWAP_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_INSTRUCTION_SWAP_LAYOUT.build(self)
+
+
+TUPLE_INSTRUCTION_SWAP_LAYOUT = _struct(
+    TupleInstructionSwap,
+    "unnamed_0" / LazyBound(lambda: PAIR_LAYOUT),
+)
+
+
+@dataclass
+class TupleInstructionTransfer:
+    unnamed_0: bytes
+    unnamed_1: int
+
+    @classmethod
+    def decode(cls, data: bytes) -> TupleInstructionTransfer:
+        return TUPLE_INSTRUCTION_TRANSFER_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_INSTRUCTION_TRANSFER_LAYOUT.build(self)
+
+
+TUPLE_INSTRUCTION_TRANSFER_LAYOUT = _struct(
+    TupleInstructionTransfer,
+    "unnamed_0" / BorshPubkey,
+    "unnamed_1" / U64,
+)
+
+
+@dataclass
+class TupleInstruction:
+    kind: str
+    value: typing.Union[TupleInstructionReset, TupleInstructionDeposit, TupleInstructionTransfer, TupleInstructionSwap, TupleInstructionSkipped, TupleInstructionClose]
+
+    @classmethod
+    def decode(cls, data: bytes) -> TupleInstruction:
+        return TUPLE_INSTRUCTION_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_INSTRUCTION_LAYOUT.build(self)
+
+
+TUPLE_INSTRUCTION_LAYOUT = _enum(
+    TupleInstruction,
+    ("Reset", LazyBound(lambda: TUPLE_INSTRUCTION_RESET_LAYOUT)),
+    ("Deposit", LazyBound(lambda: TUPLE_INSTRUCTION_DEPOSIT_LAYOUT)),
+    ("Transfer", LazyBound(lambda: TUPLE_INSTRUCTION_TRANSFER_LAYOUT)),
+    ("Swap", LazyBound(lambda: TUPLE_INSTRUCTION_SWAP_LAYOUT)),
+    ("Skipped", LazyBound(lambda: TUPLE_INSTRUCTION_SKIPPED_LAYOUT)),
+    ("Close", LazyBound(lambda: TUPLE_INSTRUCTION_CLOSE_LAYOUT)),
+)
+
+
+@dataclass
+class TupleStruct:
+    unnamed_0: int
+    unnamed_1: int
+    unnamed_2: OtherState
+
+    @classmethod
+    def decode(cls, data: bytes) -> TupleStruct:
+        return TUPLE_STRUCT_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_STRUCT_LAYOUT.build(self)
+
+
+TUPLE_STRUCT_LAYOUT = _struct(
+    TupleStruct,
+    "unnamed_0" / U8,
+    "unnamed_1" / I32,
+    "unnamed_2" / LazyBound(lambda: OTHER_STATE_LAYOUT),
+)
+
+
+@dataclass
+class TupleU16Bool:
+    unnamed_0: int
+    unnamed_1: bool
+
+    @classmethod
+    def decode(cls, data: bytes) -> TupleU16Bool:
+        return TUPLE_U16_BOOL_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_U16_BOOL_LAYOUT.build(self)
+
+
+TUPLE_U16_BOOL_LAYOUT = _struct(
+    TupleU16Bool,
+    "unnamed_0" / U16,
+    "unnamed_1" / Bool,
+)
+
+
+@dataclass
+class TupleU64Pubkey:
+    unnamed_0: int
+    unnamed_1: bytes
+
+    @classmethod
+    def decode(cls, data: bytes) -> TupleU64Pubkey:
+        return TUPLE_U64_PUBKEY_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_U64_PUBKEY_LAYOUT.build(self)
+
+
+TUPLE_U64_PUBKEY_LAYOUT = _struct(
+    TupleU64Pubkey,
+    "unnamed_0" / U64,
+    "unnamed_1" / BorshPubkey,
+)
+
+
+@dataclass
+class TupleU8OptionBool:
+    unnamed_0: int
+    unnamed_1: typing.Optional[bool]
+
+    @classmethod
+    def decode(cls, data: bytes) -> TupleU8OptionBool:
+        return TUPLE_U8_OPTION_BOOL_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return TUPLE_U8_OPTION_BOOL_LAYOUT.build(self)
+
+
+TUPLE_U8_OPTION_BOOL_LAYOUT = _struct(
+    TupleU8OptionBool,
+    "unnamed_0" / U8,
+    "unnamed_1" / Option(Bool),
+)
+
+
+@dataclass
+class CollectionWrapper:
+    map: typing.Dict[int, str]
+    hash_set: typing.Set[int]
+    btree_set: typing.Set[str]
+    boxed: BoxedState
+    pair: TupleU64Pubkey
+    pairs: typing.List[TupleU8OptionBool]
+
+    @classmethod
+    def decode(cls, data: bytes) -> CollectionWrapper:
+        return COLLECTION_WRAPPER_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return COLLECTION_WRAPPER_LAYOUT.build(self)
+
+
+COLLECTION_WRAPPER_LAYOUT = _struct(
+    CollectionWrapper,
+    "map" / HashMap(U8, String),
+    "hash_set" / HashSet(U16),
+    "btree_set" / HashSet(String),
+    "boxed" / LazyBound(lambda: BOXED_STATE_LAYOUT),
+    "pair" / LazyBound(lambda: TUPLE_U64_PUBKEY_LAYOUT),
+    "pairs" / Vec(LazyBound(lambda: TUPLE_U8_OPTION_BOOL_LAYOUT)),
+)
+
+
+@dataclass
+class Vault:
+    owner: bytes
+    balance: int
+    supply: int
+    delta: int
+    label: str
+    history: typing.List[Entry]
+    limits: typing.Dict[int, int]
+    seed: bytes
+    pair: TupleU16Bool
+    note: typing.Optional[str]
+
+    @classmethod
+    def decode(cls, data: bytes) -> Vault:
+        return VAULT_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return VAULT_LAYOUT.build(self)
+
+
+VAULT_LAYOUT = _struct(
+    Vault,
+    "owner" / BorshPubkey,
+    "balance" / U64,
+    "supply" / U128,
+    "delta" / I128,
+    "label" / String,
+    "history" / Vec(LazyBound(lambda: ENTRY_LAYOUT)),
+    "limits" / HashMap(U8, I32),
+    "seed" / FixedBytes(4),
+    "pair" / LazyBound(lambda: TUPLE_U16_BOOL_LAYOUT),
+    "note" / Option(String),
+)
+
+
+@dataclass
+class WrapperPubkey:
+    inner: bytes
+    history: typing.List[bytes]
+
+    @classmethod
+    def decode(cls, data: bytes) -> WrapperPubkey:
+        return WRAPPER_PUBKEY_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return WRAPPER_PUBKEY_LAYOUT.build(self)
+
+
+WRAPPER_PUBKEY_LAYOUT = _struct(
+    WrapperPubkey,
+    "inner" / BorshPubkey,
+    "history" / Vec(BorshPubkey),
+)
+
+
+@dataclass
+class WrapperString:
+    inner: str
+    history: typing.List[str]
+
+    @classmethod
+    def decode(cls, data: bytes) -> WrapperString:
+        return WRAPPER_STRING_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return WRAPPER_STRING_LAYOUT.build(self)
+
+
+WRAPPER_STRING_LAYOUT = _struct(
+    WrapperString,
+    "inner" / String,
+    "history" / Vec(String),
+)
+
+
+@dataclass
+class EitherRightU8WrapperString:
+    unnamed_0: WrapperString
+
+    @classmethod
+    def decode(cls, data: bytes) -> EitherRightU8WrapperString:
+        return EITHER_RIGHT_U8_WRAPPER_STRING_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return EITHER_RIGHT_U8_WRAPPER_STRING_LAYOUT.build(self)
+
+
+EITHER_RIGHT_U8_WRAPPER_STRING_LAYOUT = _struct(
+    EitherRightU8WrapperString,
+    "unnamed_0" / LazyBound(lambda: WRAPPER_STRING_LAYOUT),
+)
+
+
+@dataclass
+class EitherU8WrapperString:
+    kind: str
+    value: typing.Union[EitherLeftU8WrapperString, EitherRightU8WrapperString]
+
+    @classmethod
+    def decode(cls, data: bytes) -> EitherU8WrapperString:
+        return EITHER_U8_WRAPPER_STRING_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return EITHER_U8_WRAPPER_STRING_LAYOUT.build(self)
+
+
+EITHER_U8_WRAPPER_STRING_LAYOUT = _enum(
+    EitherU8WrapperString,
+    ("Left", LazyBound(lambda: EITHER_LEFT_U8_WRAPPER_STRING_LAYOUT)),
+    ("Right", LazyBound(lambda: EITHER_RIGHT_U8_WRAPPER_STRING_LAYOUT)),
+)
+
+
+@dataclass
+class WrapperU64:
+    inner: int
+    history: typing.List[int]
+
+    @classmethod
+    def decode(cls, data: bytes) -> WrapperU64:
+        return WRAPPER_U64_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return WRAPPER_U64_LAYOUT.build(self)
+
+
+WRAPPER_U64_LAYOUT = _struct(
+    WrapperU64,
+    "inner" / U64,
+    "history" / Vec(U64),
+)
+
+
+@dataclass
+class GenericContainer:
+    amount: WrapperU64
+    owner: WrapperPubkey
+    choice: EitherU8WrapperString
+
+    @classmethod
+    def decode(cls, data: bytes) -> GenericContainer:
+        return GENERIC_CONTAINER_LAYOUT.parse(data)
+
+    def encode(self) -> bytes:
+        return GENERIC_CONTAINER_LAYOUT.build(self)
+
+
+GENERIC_CONTAINER_LAYOUT = _struct(
+    GenericContainer,
+    "amount" / LazyBound(lambda: WRAPPER_U64_LAYOUT),
+    "owner" / LazyBound(lambda: WRAPPER_PUBKEY_LAYOUT),
+    "choice" / LazyBound(lambda: EITHER_U8_WRAPPER_STRING_LAYOUT),
+)
@@ -1,24 +1,184 @@
 import { PublicKey } from "@solana/web3.js";
 import BN from "bn.js";
+import { BinaryReader, BinaryWriter } from "borsh";
 import Enum from "./extensions/enum";
 import Struct from "./extensions/struct";
 import { borshPublicKey } from "./extensions/publicKey";
 
 borshPublicKey();
 
+(BinaryReader.prototype as any).readI8 = function (this: BinaryReader) {
+    return (this.readU8() << 24) >> 24;
+};
+(BinaryReader.prototype as any).readI16 = function (this: BinaryReader) {
+    return (this.readU16() << 16) >> 16;
+};
+(BinaryReader.prototype as any).readI32 = function (this: BinaryReader) {
+    return this.readU32() | 0;
+};
+(BinaryReader.prototype as any).readI64 = function (this: BinaryReader) {
+    return this.readU64().fromTwos(64);
+};
+(BinaryReader.prototype as any).readI128 = function (this: BinaryReader) {
+    return this.readU128().fromTwos(128);
+};
+(BinaryWriter.prototype as any).writeI8 = function (this: BinaryWriter, value: number) {
+    this.writeU8(value & 0xff);
+};
+(BinaryWriter.prototype as any).writeI16 = function (this: BinaryWriter, value: number) {
+    this.writeU16(value & 0xffff);
+};
+(BinaryWriter.prototype as any).writeI32 = function (this: BinaryWriter, value: number) {
+    this.writeU32(value >>> 0);
+};
+(BinaryWriter.prototype as any).writeI64 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU64(new BN(value).toTwos(64));
+};
+(BinaryWriter.prototype as any).writeI128 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU128(new BN(value).toTwos(128));
+};
+
+const writeArray = BinaryWriter.prototype.writeArray;
+(BinaryWriter.prototype as any).writeArray = function (this: BinaryWriter, array: any[] | Set<any>, fn: any) {
+    writeArray.call(this, array instanceof Set ? Array.from(array) : array, fn);
+};
+
+export class BTreeWrapper extends Struct {
+    map0: Map<[32], PublicKey>;
+    map1: Map<string, number | null>;
+    map2: Map<number, string>;
+};
+
+export class BoxedState extends Struct {
+    amount: number;
+};
+
+export class EitherLeftU8WrapperString extends Struct {
+    unnamed_0: number;
+};
+
+export class EntryClose extends Struct {
+};
+
+export class EntryDeposit extends Struct {
+    unnamed_0: BN;
+};
+
+export class EntryWithdraw extends Struct {
+    amount: BN;
+    fee: number | null;
+};
+
+export class Entry extends Enum {
+    entryDeposit: EntryDeposit;
+    entryWithdraw: EntryWithdraw;
+    entryClose: EntryClose;
+};
+
+export class MintConfig extends Struct {
+    maxSupply: BN;
+    mintAuthority: [32];
+    decimals: number;
+};
+
+export class MintUpdateDecimals extends Struct {
+    decimals: number;
+};
+
+export class MintUpdateSupply extends Struct {
+    newMaxSupply: BN;
+};
+
+export class MintUpdate extends Enum {
+    mintUpdateSupply: MintUpdateSupply;
+    mintUpdateDecimals: MintUpdateDecimals;
+};
+
+/** Amount held at a given time. */
+export class OtherState extends Struct {
+    /** The amount in lamports. */
+    amount: BN;
+    /** Unix timestamp in seconds. */
+    timestamp: BN;
+};
+
+export class AliasedStruct extends Struct {
+    pool: OtherState[] | null;
+    amounts: BN[];
+    overridden: BN;
+    key: [32];
+};
+
+export class Pair extends Struct {
+    unnamed_0: BN;
+    unnamed_1: PublicKey;
+};
+
+export class PoolInstructionClose extends Struct {
+};
+
+export class PoolInstructionDeposit extends Struct {
+    unnamed_0: BN;
+};
+
+/** Creates a new pool. */
+export class PoolInstructionInitialize extends Struct {
+    fee: number;
+};
+
+export class PoolInstructionPing extends Struct {
+};
+
+export class PoolInstruction extends Enum {
+    /** Creates a new pool. */
+    poolInstructionInitialize: PoolInstructionInitialize;
+    poolInstructionDeposit: PoolInstructionDeposit;
+    poolInstructionClose: PoolInstructionClose;
+    poolInstructionPing: PoolInstructionPing;
+};
+
+export class PriorityHigh extends Struct {
+};
+
+export class PriorityLow extends Struct {
+};
+
+/**
+ * A C-like enum serialized by its variant indices, ignoring the explicit
+ * discriminants.
+ */
+export class Priority extends Enum {
+    priorityLow: PriorityLow;
+    priorityHigh: PriorityHigh;
+};
+
 export class RandomStruct extends Struct {
     fieldA: string;
     fieldB: [2] | null;
 };
 
-export class TestEnum extends Enum {
-    testEnumVariantA: TestEnumVariantA;
-    testEnumVariantB: TestEnumVariantB;
-    testEnumVariantC: TestEnumVariantC;
-    testEnumVariantD: TestEnumVariantD;
-    testEnumVariantE: TestEnumVariantE;
-    testEnumVariantF: TestEnumVariantF;
-    testEnumVariantG: TestEnumVariantG;
+export class SignedStruct extends Struct {
+    fieldA: number;
+    fieldB: number;
+    fieldC: number;
+    fieldD: BN;
+    fieldE: BN;
+};
+
+export class StatusActive extends Struct {
+};
+
+export class StatusFrozen extends Struct {
+};
+
+export class StatusInactive extends Struct {
+};
+
+/** A C-like enum serialized by its explicit discriminants. */
+export class Status extends Enum {
+    statusInactive: StatusInactive;
+    statusActive: StatusActive;
+    statusFrozen: StatusFrozen;
 };
 
 export class TestEnumVariantA extends Struct {
@@ -43,22 +203,85 @@ export class TestEnumVariantF extends Struct {
     unnamed_0: RandomStruct;
 };
 
+/**
+ * A variant with named fields.
+ *
+ * Its fields are documented *\/ as well.
+ */
 export class TestEnumVariantG extends Struct {
+    /** Arbitrary bytes. */
     hello: number[];
     bello: PublicKey[];
     yello: number;
     zello: boolean;
 };
 
+export class TestEnum extends Enum {
+    testEnumVariantA: TestEnumVariantA;
+    testEnumVariantB: TestEnumVariantB;
+    testEnumVariantC: TestEnumVariantC;
+    testEnumVariantD: TestEnumVariantD;
+    testEnumVariantE: TestEnumVariantE;
+    testEnumVariantF: TestEnumVariantF;
+    /**
+     * A variant with named fields.
+     *
+     * Its fields are documented *\/ as well.
+     */
+    testEnumVariantG: TestEnumVariantG;
+};
+
 export class TestStruct extends Struct {
     fieldA: BN;
     fieldB: number;
     fieldC: OtherState[] | null;
 };
 
-export class OtherState extends Struct {
-    amount: BN;
-    timestamp: BN;
+export class TierBasic extends Struct {
+};
+
+export class TierPremium extends Struct {
+};
+
+/**
+ * A C-like enum with explicit discriminants but without `use_discriminant`,
+ * which borsh serializes by the variant indices.
+ */
+export class Tier extends Enum {
+    tierBasic: TierBasic;
+    tierPremium: TierPremium;
+};
+
+export class TupleInstructionClose extends Struct {
+    refund: boolean;
+};
+
+export class TupleInstructionDeposit extends Struct {
+    unnamed_0: BN;
+};
+
+export class TupleInstructionReset extends Struct {
+};
+
+export class TupleInstructionSkipped extends Struct {
+};
+
+export class TupleInstructionSwap extends Struct {
+    unnamed_0: Pair;
+};
+
+export class TupleInstructionTransfer extends Struct {
+    unnamed_0: PublicKey;
+    unnamed_1: BN;
+};
+
+export class TupleInstruction extends Enum {
+    tupleInstructionReset: TupleInstructionReset;
+    tupleInstructionDeposit: TupleInstructionDeposit;
+    tupleInstructionTransfer: TupleInstructionTransfer;
+    tupleInstructionSwap: TupleInstructionSwap;
+    tupleInstructionSkipped: TupleInstructionSkipped;
+    tupleInstructionClose: TupleInstructionClose;
 };
 
 export class TupleStruct extends Struct {
@@ -67,13 +290,278 @@ export class TupleStruct extends Struct {
     unnamed_2: OtherState;
 };
 
-export class BTreeWrapper extends Struct {
-    map0: Map<[32], PublicKey>;
-    map1: Map<string, number | null>;
-    map2: Map<number, string>;
+export class TupleU16Bool {
+    constructor(fields: { 0: number; 1: boolean }) {
+        return Object.defineProperty([fields[0], fields[1]], "constructor", { value: TupleU16Bool });
+    }
+};
+
+export class TupleU64Pubkey {
+    constructor(fields: { 0: BN; 1: PublicKey }) {
+        return Object.defineProperty([fields[0], fields[1]], "constructor", { value: TupleU64Pubkey });
+    }
+};
+
+export class TupleU8OptionBool {
+    constructor(fields: { 0: number; 1: boolean | null }) {
+        return Object.defineProperty([fields[0], fields[1]], "constructor", { value: TupleU8OptionBool });
+    }
+};
+
+export class CollectionWrapper extends Struct {
+    map: Map<number, string>;
+    hashSet: Set<number>;
+    btreeSet: Set<string>;
+    boxed: BoxedState;
+    pair: [BN, PublicKey];
+    pairs: [number, boolean | null][];
+
+    constructor(properties: any) {
+        super(properties);
+        this.hashSet = new Set(this.hashSet);
+        this.btreeSet = new Set(this.btreeSet);
+        this.pair = new TupleU64Pubkey(this.pair) as [BN, PublicKey];
+        this.pairs = this.pairs.map((item) => new TupleU8OptionBool(item) as [number, boolean | null]);
+    }
+};
+
+export class Vault extends Struct {
+    owner: PublicKey;
+    balance: BN;
+    supply: BN;
+    delta: BN;
+    label: string;
+    history: Entry[];
+    limits: Map<number, number>;
+    seed: [4];
+    pair: [number, boolean];
+    note: string | null;
+
+    constructor(properties: any) {
+        super(properties);
+        this.pair = new TupleU16Bool(this.pair) as [number, boolean];
+    }
+};
+
+export class WrapperPubkey extends Struct {
+    inner: PublicKey;
+    history: PublicKey[];
+};
+
+export class WrapperString extends Struct {
+    inner: string;
+    history: string[];
+};
+
+export class EitherRightU8WrapperString extends Struct {
+    unnamed_0: WrapperString;
+};
+
+export class EitherU8WrapperString extends Enum {
+    eitherLeft: EitherLeftU8WrapperString;
+    eitherRight: EitherRightU8WrapperString;
+};
+
+export class WrapperU64 extends Struct {
+    inner: BN;
+    history: BN[];
+};
+
+export class GenericContainer extends Struct {
+    amount: WrapperU64;
+    owner: WrapperPubkey;
+    choice: EitherU8WrapperString;
 };
 
 export const SCHEMA = new Map<any, any>([
+    [
+            BTreeWrapper,
+            {
+                kind: 'struct', fields: [
+			['map0', { kind: 'map', key: [32], value: 'publicKey' }],
+			['map1', { kind: 'map', key: 'string', value: { kind: 'option', type: 'u32' } }],
+			['map2', { kind: 'map', key: 'u16', value: 'string' }],
+                ],
+            },
+    ],
+    [
+            BoxedState,
+            {
+                kind: 'struct', fields: [
+			['amount', 'u32'],
+                ],
+            },
+    ],
+    [
+            EitherLeftU8WrapperString,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u8'],
+                ],
+            },
+    ],
+    [
+            EntryClose,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            EntryDeposit,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            EntryWithdraw,
+            {
+                kind: 'struct', fields: [
+			['amount', 'u64'],
+			['fee', { kind: 'option', type: 'u16' }],
+                ],
+            },
+    ],
+    [
+            Entry,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['entryDeposit', EntryDeposit],
+			['entryWithdraw', EntryWithdraw],
+			['entryClose', EntryClose],
+                ],
+            },
+    ],
+    [
+            MintConfig,
+            {
+                kind: 'struct', fields: [
+			['maxSupply', 'u64'],
+			['mintAuthority', [32]],
+			['decimals', 'u8'],
+                ],
+            },
+    ],
+    [
+            MintUpdateDecimals,
+            {
+                kind: 'struct', fields: [
+			['decimals', 'u8'],
+                ],
+            },
+    ],
+    [
+            MintUpdateSupply,
+            {
+                kind: 'struct', fields: [
+			['newMaxSupply', 'u64'],
+                ],
+            },
+    ],
+    [
+            MintUpdate,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['mintUpdateSupply', MintUpdateSupply],
+			['mintUpdateDecimals', MintUpdateDecimals],
+                ],
+            },
+    ],
+    [
+            OtherState,
+            {
+                kind: 'struct', fields: [
+			['amount', 'u64'],
+			['timestamp', 'i64'],
+                ],
+            },
+    ],
+    [
+            AliasedStruct,
+            {
+                kind: 'struct', fields: [
+			['pool', { kind: 'option', type: [OtherState] }],
+			['amounts', ['u64']],
+			['overridden', 'u64'],
+			['key', [32]],
+                ],
+            },
+    ],
+    [
+            Pair,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+			['unnamed_1', 'publicKey'],
+                ],
+            },
+    ],
+    [
+            PoolInstructionClose,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            PoolInstructionDeposit,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            PoolInstructionInitialize,
+            {
+                kind: 'struct', fields: [
+			['fee', 'u16'],
+                ],
+            },
+    ],
+    [
+            PoolInstructionPing,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            PoolInstruction,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['poolInstructionInitialize', PoolInstructionInitialize],
+			['poolInstructionDeposit', PoolInstructionDeposit],
+			['poolInstructionClose', PoolInstructionClose],
+			['poolInstructionPing', PoolInstructionPing],
+                ],
+            },
+    ],
+    [
+            PriorityHigh,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            PriorityLow,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            Priority,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['priorityLow', PriorityLow],
+			['priorityHigh', PriorityHigh],
+                ],
+            },
+    ],
     [
             RandomStruct,
             {
@@ -84,16 +572,54 @@ export const SCHEMA = new Map<any, any>([
             },
     ],
     [
-            TestEnum,
+            SignedStruct,
+            {
+                kind: 'struct', fields: [
+			['fieldA', 'i8'],
+			['fieldB', 'i16'],
+			['fieldC', 'i32'],
+			['fieldD', 'i64'],
+			['fieldE', 'i128'],
+                ],
+            },
+    ],
+    [
+            StatusActive,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            StatusFrozen,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            StatusInactive,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            Status,
             {
                 kind: 'enum', field: 'enum', values: [
-			['testEnumVariantA', TestEnumVariantA],
-			['testEnumVariantB', TestEnumVariantB],
-			['testEnumVariantC', TestEnumVariantC],
-			['testEnumVariantD', TestEnumVariantD],
-			['testEnumVariantE', TestEnumVariantE],
-			['testEnumVariantF', TestEnumVariantF],
-			['testEnumVariantG', TestEnumVariantG],
+			['unused0', 'u8'],
+			['unused1', 'u8'],
+			['unused2', 'u8'],
+			['unused3', 'u8'],
+			['unused4', 'u8'],
+			['statusInactive', StatusInactive],
+			['unused6', 'u8'],
+			['unused7', 'u8'],
+			['unused8', 'u8'],
+			['unused9', 'u8'],
+			['statusActive', StatusActive],
+			['statusFrozen', StatusFrozen],
                 ],
             },
     ],
@@ -154,6 +680,20 @@ export const SCHEMA = new Map<any, any>([
                 ],
             },
     ],
+    [
+            TestEnum,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['testEnumVariantA', TestEnumVariantA],
+			['testEnumVariantB', TestEnumVariantB],
+			['testEnumVariantC', TestEnumVariantC],
+			['testEnumVariantD', TestEnumVariantD],
+			['testEnumVariantE', TestEnumVariantE],
+			['testEnumVariantF', TestEnumVariantF],
+			['testEnumVariantG', TestEnumVariantG],
+                ],
+            },
+    ],
     [
             TestStruct,
             {
@@ -165,11 +705,85 @@ export const SCHEMA = new Map<any, any>([
             },
     ],
     [
-            OtherState,
+            TierBasic,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TierPremium,
             {
                 kind: 'struct', fields: [
-			['amount', 'u64'],
-			['timestamp', 'u64'],
+                ],
+            },
+    ],
+    [
+            Tier,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['tierBasic', TierBasic],
+			['tierPremium', TierPremium],
+                ],
+            },
+    ],
+    [
+            TupleInstructionClose,
+            {
+                kind: 'struct', fields: [
+			['refund', 'u8'],
+                ],
+            },
+    ],
+    [
+            TupleInstructionDeposit,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            TupleInstructionReset,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TupleInstructionSkipped,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TupleInstructionSwap,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', Pair],
+                ],
+            },
+    ],
+    [
+            TupleInstructionTransfer,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'publicKey'],
+			['unnamed_1', 'u64'],
+                ],
+            },
+    ],
+    [
+            TupleInstruction,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['tupleInstructionReset', TupleInstructionReset],
+			['tupleInstructionDeposit', TupleInstructionDeposit],
+			['tupleInstructionTransfer', TupleInstructionTransfer],
+			['tupleInstructionSwap', TupleInstructionSwap],
+			['tupleInstructionSkipped', TupleInstructionSkipped],
+			['tupleInstructionClose', TupleInstructionClose],
                 ],
             },
     ],
@@ -178,18 +792,119 @@ export const SCHEMA = new Map<any, any>([
             {
                 kind: 'struct', fields: [
 			['unnamed_0', 'u8'],
-			['unnamed_1', 'u32'],
+			['unnamed_1', 'i32'],
 			['unnamed_2', OtherState],
                 ],
             },
     ],
     [
-            BTreeWrapper,
+            TupleU16Bool,
             {
                 kind: 'struct', fields: [
-			['map0', { kind: 'map', key: [32], value: 'publicKey' }],
-			['map1', { kind: 'map', key: 'string', value: { kind: 'option', type: 'u32' } }],
-			['map2', { kind: 'map', key: 'u16', value: 'string' }],
+			['0', 'u16'],
+			['1', 'u8'],
+                ],
+            },
+    ],
+    [
+            TupleU64Pubkey,
+            {
+                kind: 'struct', fields: [
+			['0', 'u64'],
+			['1', 'publicKey'],
+                ],
+            },
+    ],
+    [
+            TupleU8OptionBool,
+            {
+                kind: 'struct', fields: [
+			['0', 'u8'],
+			['1', { kind: 'option', type: 'u8' }],
+                ],
+            },
+    ],
+    [
+            CollectionWrapper,
+            {
+                kind: 'struct', fields: [
+			['map', { kind: 'map', key: 'u8', value: 'string' }],
+			['hashSet', ['u16']],
+			['btreeSet', ['string']],
+			['boxed', BoxedState],
+			['pair', TupleU64Pubkey],
+			['pairs', [TupleU8OptionBool]],
+                ],
+            },
+    ],
+    [
+            Vault,
+            {
+                kind: 'struct', fields: [
+			['owner', 'publicKey'],
+			['balance', 'u64'],
+			['supply', 'u128'],
+			['delta', 'i128'],
+			['label', 'string'],
+			['history', [Entry]],
+			['limits', { kind: 'map', key: 'u8', value: 'i32' }],
+			['seed', [4]],
+			['pair', TupleU16Bool],
+			['note', { kind: 'option', type: 'string' }],
+                ],
+            },
+    ],
+    [
+            WrapperPubkey,
+            {
+                kind: 'struct', fields: [
+			['inner', 'publicKey'],
+			['history', ['publicKey']],
+                ],
+            },
+    ],
+    [
+            WrapperString,
+            {
+                kind: 'struct', fields: [
+			['inner', 'string'],
+			['history', ['string']],
+                ],
+            },
+    ],
+    [
+            EitherRightU8WrapperString,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', WrapperString],
+                ],
+            },
+    ],
+    [
+            EitherU8WrapperString,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['eitherLeft', EitherLeftU8WrapperString],
+			['eitherRight', EitherRightU8WrapperString],
+                ],
+            },
+    ],
+    [
+            WrapperU64,
+            {
+                kind: 'struct', fields: [
+			['inner', 'u64'],
+			['history', ['u64']],
+                ],
+            },
+    ],
+    [
+            GenericContainer,
+            {
+                kind: 'struct', fields: [
+			['amount', WrapperU64],
+			['owner', WrapperPubkey],
+			['choice', EitherU8WrapperString],
                 ],
             },
     ],
@@ -0,0 +1,2134 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { BinaryReader, BinaryWriter, deserializeUnchecked, serialize } from "borsh";
+
+(BinaryReader.prototype as any).readPublicKey = function (this: BinaryReader) {
+    return new PublicKey(this.readFixedArray(32));
+};
+(BinaryWriter.prototype as any).writePublicKey = function (this: BinaryWriter, value: PublicKey) {
+    this.writeFixedArray(value.toBytes());
+};
+(BinaryReader.prototype as any).readI8 = function (this: BinaryReader) {
+    return (this.readU8() << 24) >> 24;
+};
+(BinaryReader.prototype as any).readI16 = function (this: BinaryReader) {
+    return (this.readU16() << 16) >> 16;
+};
+(BinaryReader.prototype as any).readI32 = function (this: BinaryReader) {
+    return this.readU32() | 0;
+};
+(BinaryReader.prototype as any).readI64 = function (this: BinaryReader) {
+    return this.readU64().fromTwos(64);
+};
+(BinaryReader.prototype as any).readI128 = function (this: BinaryReader) {
+    return this.readU128().fromTwos(128);
+};
+(BinaryWriter.prototype as any).writeI8 = function (this: BinaryWriter, value: number) {
+    this.writeU8(value & 0xff);
+};
+(BinaryWriter.prototype as any).writeI16 = function (this: BinaryWriter, value: number) {
+    this.writeU16(value & 0xffff);
+};
+(BinaryWriter.prototype as any).writeI32 = function (this: BinaryWriter, value: number) {
+    this.writeU32(value >>> 0);
+};
+(BinaryWriter.prototype as any).writeI64 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU64(new BN(value).toTwos(64));
+};
+(BinaryWriter.prototype as any).writeI128 = function (this: BinaryWriter, value: number | BN) {
+    this.writeU128(new BN(value).toTwos(128));
+};
+
+const writeArray = BinaryWriter.prototype.writeArray;
+(BinaryWriter.prototype as any).writeArray = function (this: BinaryWriter, array: any[] | Set<any>, fn: any) {
+    writeArray.call(this, array instanceof Set ? Array.from(array) : array, fn);
+};
+
+export class BTreeWrapper {
+    map0: Map<[32], PublicKey>;
+    map1: Map<string, number | null>;
+    map2: Map<number, string>;
+
+    constructor(fields: {
+        map0: Map<[32], PublicKey>,
+        map1: Map<string, number | null>,
+        map2: Map<number, string>,
+    }) {
+        this.map0 = fields.map0;
+        this.map1 = fields.map1;
+        this.map2 = fields.map2;
+    }
+
+    static decode(buf: Buffer): BTreeWrapper {
+        return deserializeUnchecked(SCHEMA, BTreeWrapper, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class BoxedState {
+    amount: number;
+
+    constructor(fields: {
+        amount: number,
+    }) {
+        this.amount = fields.amount;
+    }
+
+    static decode(buf: Buffer): BoxedState {
+        return deserializeUnchecked(SCHEMA, BoxedState, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class EitherLeftU8WrapperString {
+    unnamed_0: number;
+
+    constructor(fields: {
+        unnamed_0: number,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): EitherLeftU8WrapperString {
+        return deserializeUnchecked(SCHEMA, EitherLeftU8WrapperString, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class EntryClose {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): EntryClose {
+        return deserializeUnchecked(SCHEMA, EntryClose, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class EntryDeposit {
+    unnamed_0: BN;
+
+    constructor(fields: {
+        unnamed_0: BN,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): EntryDeposit {
+        return deserializeUnchecked(SCHEMA, EntryDeposit, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class EntryWithdraw {
+    amount: BN;
+    fee: number | null;
+
+    constructor(fields: {
+        amount: BN,
+        fee: number | null,
+    }) {
+        this.amount = fields.amount;
+        this.fee = fields.fee;
+    }
+
+    static decode(buf: Buffer): EntryWithdraw {
+        return deserializeUnchecked(SCHEMA, EntryWithdraw, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class Entry {
+    enum: string;
+    entryDeposit?: EntryDeposit;
+    entryWithdraw?: EntryWithdraw;
+    entryClose?: EntryClose;
+
+    constructor(fields: { entryDeposit: EntryDeposit } | { entryWithdraw: EntryWithdraw } | { entryClose: EntryClose }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): Entry {
+        return deserializeUnchecked(SCHEMA, Entry, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export type EntryUnion =
+    | { kind: "Deposit"; value: EntryDeposit }
+    | { kind: "Withdraw"; value: EntryWithdraw }
+    | { kind: "Close" };
+
+export function isEntryDeposit(value: EntryUnion): value is Extract<EntryUnion, { kind: "Deposit" }> {
+    return value.kind === "Deposit";
+}
+
+export function isEntryWithdraw(value: EntryUnion): value is Extract<EntryUnion, { kind: "Withdraw" }> {
+    return value.kind === "Withdraw";
+}
+
+export function isEntryClose(value: EntryUnion): value is Extract<EntryUnion, { kind: "Close" }> {
+    return value.kind === "Close";
+}
+
+export function toEntryUnion(value: Entry): EntryUnion {
+    switch (value.enum) {
+        case "entryDeposit":
+            return { kind: "Deposit", value: value.entryDeposit! };
+        case "entryWithdraw":
+            return { kind: "Withdraw", value: value.entryWithdraw! };
+        case "entryClose":
+            return { kind: "Close" };
+    }
+    throw new Error(`Unknown Entry variant: ${value.enum}`);
+}
+
+export function fromEntryUnion(value: EntryUnion): Entry {
+    switch (value.kind) {
+        case "Deposit":
+            return new Entry({ entryDeposit: value.value });
+        case "Withdraw":
+            return new Entry({ entryWithdraw: value.value });
+        case "Close":
+            return new Entry({ entryClose: new EntryClose({}) });
+    }
+}
+
+export class MintConfig {
+    maxSupply: BN;
+    mintAuthority: [32];
+    decimals: number;
+
+    constructor(fields: {
+        maxSupply: BN,
+        mintAuthority: [32],
+        decimals: number,
+    }) {
+        this.maxSupply = fields.maxSupply;
+        this.mintAuthority = fields.mintAuthority;
+        this.decimals = fields.decimals;
+    }
+
+    static decode(buf: Buffer): MintConfig {
+        return deserializeUnchecked(SCHEMA, MintConfig, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class MintUpdateDecimals {
+    decimals: number;
+
+    constructor(fields: {
+        decimals: number,
+    }) {
+        this.decimals = fields.decimals;
+    }
+
+    static decode(buf: Buffer): MintUpdateDecimals {
+        return deserializeUnchecked(SCHEMA, MintUpdateDecimals, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class MintUpdateSupply {
+    newMaxSupply: BN;
+
+    constructor(fields: {
+        newMaxSupply: BN,
+    }) {
+        this.newMaxSupply = fields.newMaxSupply;
+    }
+
+    static decode(buf: Buffer): MintUpdateSupply {
+        return deserializeUnchecked(SCHEMA, MintUpdateSupply, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class MintUpdate {
+    enum: string;
+    mintUpdateSupply?: MintUpdateSupply;
+    mintUpdateDecimals?: MintUpdateDecimals;
+
+    constructor(fields: { mintUpdateSupply: MintUpdateSupply } | { mintUpdateDecimals: MintUpdateDecimals }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): MintUpdate {
+        return deserializeUnchecked(SCHEMA, MintUpdate, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export type MintUpdateUnion =
+    | { kind: "Supply"; value: MintUpdateSupply }
+    | { kind: "Decimals"; value: MintUpdateDecimals };
+
+export function isMintUpdateSupply(value: MintUpdateUnion): value is Extract<MintUpdateUnion, { kind: "Supply" }> {
+    return value.kind === "Supply";
+}
+
+export function isMintUpdateDecimals(value: MintUpdateUnion): value is Extract<MintUpdateUnion, { kind: "Decimals" }> {
+    return value.kind === "Decimals";
+}
+
+export function toMintUpdateUnion(value: MintUpdate): MintUpdateUnion {
+    switch (value.enum) {
+        case "mintUpdateSupply":
+            return { kind: "Supply", value: value.mintUpdateSupply! };
+        case "mintUpdateDecimals":
+            return { kind: "Decimals", value: value.mintUpdateDecimals! };
+    }
+    throw new Error(`Unknown MintUpdate variant: ${value.enum}`);
+}
+
+export function fromMintUpdateUnion(value: MintUpdateUnion): MintUpdate {
+    switch (value.kind) {
+        case "Supply":
+            return new MintUpdate({ mintUpdateSupply: value.value });
+        case "Decimals":
+            return new MintUpdate({ mintUpdateDecimals: value.value });
+    }
+}
+
+/** Amount held at a given time. */
+export class OtherState {
+    /** The amount in lamports. */
+    amount: BN;
+    /** Unix timestamp in seconds. */
+    timestamp: BN;
+
+    constructor(fields: {
+        amount: BN,
+        timestamp: BN,
+    }) {
+        this.amount = fields.amount;
+        this.timestamp = fields.timestamp;
+    }
+
+    static decode(buf: Buffer): OtherState {
+        return deserializeUnchecked(SCHEMA, OtherState, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class AliasedStruct {
+    pool: OtherState[] | null;
+    amounts: BN[];
+    overridden: BN;
+    key: [32];
+
+    constructor(fields: {
+        pool: OtherState[] | null,
+        amounts: BN[],
+        overridden: BN,
+        key: [32],
+    }) {
+        this.pool = fields.pool;
+        this.amounts = fields.amounts;
+        this.overridden = fields.overridden;
+        this.key = fields.key;
+    }
+
+    static decode(buf: Buffer): AliasedStruct {
+        return deserializeUnchecked(SCHEMA, AliasedStruct, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class Pair {
+    unnamed_0: BN;
+    unnamed_1: PublicKey;
+
+    constructor(fields: {
+        unnamed_0: BN,
+        unnamed_1: PublicKey,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+        this.unnamed_1 = fields.unnamed_1;
+    }
+
+    static decode(buf: Buffer): Pair {
+        return deserializeUnchecked(SCHEMA, Pair, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class PoolInstructionClose {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): PoolInstructionClose {
+        return deserializeUnchecked(SCHEMA, PoolInstructionClose, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class PoolInstructionDeposit {
+    unnamed_0: BN;
+
+    constructor(fields: {
+        unnamed_0: BN,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): PoolInstructionDeposit {
+        return deserializeUnchecked(SCHEMA, PoolInstructionDeposit, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+/** Creates a new pool. */
+export class PoolInstructionInitialize {
+    fee: number;
+
+    constructor(fields: {
+        fee: number,
+    }) {
+        this.fee = fields.fee;
+    }
+
+    static decode(buf: Buffer): PoolInstructionInitialize {
+        return deserializeUnchecked(SCHEMA, PoolInstructionInitialize, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class PoolInstructionPing {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): PoolInstructionPing {
+        return deserializeUnchecked(SCHEMA, PoolInstructionPing, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class PoolInstruction {
+    enum: string;
+    /** Creates a new pool. */
+    poolInstructionInitialize?: PoolInstructionInitialize;
+    poolInstructionDeposit?: PoolInstructionDeposit;
+    poolInstructionClose?: PoolInstructionClose;
+    poolInstructionPing?: PoolInstructionPing;
+
+    constructor(fields: { poolInstructionInitialize: PoolInstructionInitialize } | { poolInstructionDeposit: PoolInstructionDeposit } | { poolInstructionClose: PoolInstructionClose } | { poolInstructionPing: PoolInstructionPing }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): PoolInstruction {
+        return deserializeUnchecked(SCHEMA, PoolInstruction, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export type PoolInstructionUnion =
+    | { kind: "Initialize"; value: PoolInstructionInitialize }
+    | { kind: "Deposit"; value: PoolInstructionDeposit }
+    | { kind: "Close" }
+    | { kind: "Ping" };
+
+export function isPoolInstructionInitialize(value: PoolInstructionUnion): value is Extract<PoolInstructionUnion, { kind: "Initialize" }> {
+    return value.kind === "Initialize";
+}
+
+export function isPoolInstructionDeposit(value: PoolInstructionUnion): value is Extract<PoolInstructionUnion, { kind: "Deposit" }> {
+    return value.kind === "Deposit";
+}
+
+export function isPoolInstructionClose(value: PoolInstructionUnion): value is Extract<PoolInstructionUnion, { kind: "Close" }> {
+    return value.kind === "Close";
+}
+
+export function isPoolInstructionPing(value: PoolInstructionUnion): value is Extract<PoolInstructionUnion, { kind: "Ping" }> {
+    return value.kind === "Ping";
+}
+
+export function toPoolInstructionUnion(value: PoolInstruction): PoolInstructionUnion {
+    switch (value.enum) {
+        case "poolInstructionInitialize":
+            return { kind: "Initialize", value: value.poolInstructionInitialize! };
+        case "poolInstructionDeposit":
+            return { kind: "Deposit", value: value.poolInstructionDeposit! };
+        case "poolInstructionClose":
+            return { kind: "Close" };
+        case "poolInstructionPing":
+            return { kind: "Ping" };
+    }
+    throw new Error(`Unknown PoolInstruction variant: ${value.enum}`);
+}
+
+export function fromPoolInstructionUnion(value: PoolInstructionUnion): PoolInstruction {
+    switch (value.kind) {
+        case "Initialize":
+            return new PoolInstruction({ poolInstructionInitialize: value.value });
+        case "Deposit":
+            return new PoolInstruction({ poolInstructionDeposit: value.value });
+        case "Close":
+            return new PoolInstruction({ poolInstructionClose: new PoolInstructionClose({}) });
+        case "Ping":
+            return new PoolInstruction({ poolInstructionPing: new PoolInstructionPing({}) });
+    }
+}
+
+export class PriorityHigh {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): PriorityHigh {
+        return deserializeUnchecked(SCHEMA, PriorityHigh, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class PriorityLow {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): PriorityLow {
+        return deserializeUnchecked(SCHEMA, PriorityLow, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+/**
+ * A C-like enum serialized by its variant indices, ignoring the explicit
+ * discriminants.
+ */
+export class Priority {
+    enum: string;
+    priorityLow?: PriorityLow;
+    priorityHigh?: PriorityHigh;
+
+    constructor(fields: { priorityLow: PriorityLow } | { priorityHigh: PriorityHigh }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): Priority {
+        return deserializeUnchecked(SCHEMA, Priority, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export type PriorityUnion =
+    | { kind: "Low" }
+    | { kind: "High" };
+
+export function isPriorityLow(value: PriorityUnion): value is Extract<PriorityUnion, { kind: "Low" }> {
+    return value.kind === "Low";
+}
+
+export function isPriorityHigh(value: PriorityUnion): value is Extract<PriorityUnion, { kind: "High" }> {
+    return value.kind === "High";
+}
+
+export function toPriorityUnion(value: Priority): PriorityUnion {
+    switch (value.enum) {
+        case "priorityLow":
+            return { kind: "Low" };
+        case "priorityHigh":
+            return { kind: "High" };
+    }
+    throw new Error(`Unknown Priority variant: ${value.enum}`);
+}
+
+export function fromPriorityUnion(value: PriorityUnion): Priority {
+    switch (value.kind) {
+        case "Low":
+            return new Priority({ priorityLow: new PriorityLow({}) });
+        case "High":
+            return new Priority({ priorityHigh: new PriorityHigh({}) });
+    }
+}
+
+export class RandomStruct {
+    fieldA: string;
+    fieldB: [2] | null;
+
+    constructor(fields: {
+        fieldA: string,
+        fieldB: [2] | null,
+    }) {
+        this.fieldA = fields.fieldA;
+        this.fieldB = fields.fieldB;
+    }
+
+    static decode(buf: Buffer): RandomStruct {
+        return deserializeUnchecked(SCHEMA, RandomStruct, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class SignedStruct {
+    fieldA: number;
+    fieldB: number;
+    fieldC: number;
+    fieldD: BN;
+    fieldE: BN;
+
+    constructor(fields: {
+        fieldA: number,
+        fieldB: number,
+        fieldC: number,
+        fieldD: BN,
+        fieldE: BN,
+    }) {
+        this.fieldA = fields.fieldA;
+        this.fieldB = fields.fieldB;
+        this.fieldC = fields.fieldC;
+        this.fieldD = fields.fieldD;
+        this.fieldE = fields.fieldE;
+    }
+
+    static decode(buf: Buffer): SignedStruct {
+        return deserializeUnchecked(SCHEMA, SignedStruct, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class StatusActive {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): StatusActive {
+        return deserializeUnchecked(SCHEMA, StatusActive, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class StatusFrozen {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): StatusFrozen {
+        return deserializeUnchecked(SCHEMA, StatusFrozen, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class StatusInactive {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): StatusInactive {
+        return deserializeUnchecked(SCHEMA, StatusInactive, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+/** A C-like enum serialized by its explicit discriminants. */
+export class Status {
+    enum: string;
+    statusInactive?: StatusInactive;
+    statusActive?: StatusActive;
+    statusFrozen?: StatusFrozen;
+
+    constructor(fields: { statusInactive: StatusInactive } | { statusActive: StatusActive } | { statusFrozen: StatusFrozen }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): Status {
+        return deserializeUnchecked(SCHEMA, Status, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export type StatusUnion =
+    | { kind: "Inactive" }
+    | { kind: "Active" }
+    | { kind: "Frozen" };
+
+export function isStatusInactive(value: StatusUnion): value is Extract<StatusUnion, { kind: "Inactive" }> {
+    return value.kind === "Inactive";
+}
+
+export function isStatusActive(value: StatusUnion): value is Extract<StatusUnion, { kind: "Active" }> {
+    return value.kind === "Active";
+}
+
+export function isStatusFrozen(value: StatusUnion): value is Extract<StatusUnion, { kind: "Frozen" }> {
+    return value.kind === "Frozen";
+}
+
+export function toStatusUnion(value: Status): StatusUnion {
+    switch (value.enum) {
+        case "statusInactive":
+            return { kind: "Inactive" };
+        case "statusActive":
+            return { kind: "Active" };
+        case "statusFrozen":
+            return { kind: "Frozen" };
+    }
+    throw new Error(`Unknown Status variant: ${value.enum}`);
+}
+
+export function fromStatusUnion(value: StatusUnion): Status {
+    switch (value.kind) {
+        case "Inactive":
+            return new Status({ statusInactive: new StatusInactive({}) });
+        case "Active":
+            return new Status({ statusActive: new StatusActive({}) });
+        case "Frozen":
+            return new Status({ statusFrozen: new StatusFrozen({}) });
+    }
+}
+
+export class TestEnumVariantA {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): TestEnumVariantA {
+        return deserializeUnchecked(SCHEMA, TestEnumVariantA, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TestEnumVariantB {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): TestEnumVariantB {
+        return deserializeUnchecked(SCHEMA, TestEnumVariantB, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TestEnumVariantC {
+    unnamed_0: BN;
+
+    constructor(fields: {
+        unnamed_0: BN,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): TestEnumVariantC {
+        return deserializeUnchecked(SCHEMA, TestEnumVariantC, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TestEnumVariantD {
+    unnamed_0: PublicKey | null;
+
+    constructor(fields: {
+        unnamed_0: PublicKey | null,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): TestEnumVariantD {
+        return deserializeUnchecked(SCHEMA, TestEnumVariantD, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TestEnumVariantE {
+    unnamed_0: number | null;
+
+    constructor(fields: {
+        unnamed_0: number | null,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): TestEnumVariantE {
+        return deserializeUnchecked(SCHEMA, TestEnumVariantE, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TestEnumVariantF {
+    unnamed_0: RandomStruct;
+
+    constructor(fields: {
+        unnamed_0: RandomStruct,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): TestEnumVariantF {
+        return deserializeUnchecked(SCHEMA, TestEnumVariantF, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+/**
+ * A variant with named fields.
+ *
+ * Its fields are documented *\/ as well.
+ */
+export class TestEnumVariantG {
+    /** Arbitrary bytes. */
+    hello: number[];
+    bello: PublicKey[];
+    yello: number;
+    zello: boolean;
+
+    constructor(fields: {
+        hello: number[],
+        bello: PublicKey[],
+        yello: number,
+        zello: boolean,
+    }) {
+        this.hello = fields.hello;
+        this.bello = fields.bello;
+        this.yello = fields.yello;
+        this.zello = fields.zello;
+    }
+
+    static decode(buf: Buffer): TestEnumVariantG {
+        return deserializeUnchecked(SCHEMA, TestEnumVariantG, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TestEnum {
+    enum: string;
+    testEnumVariantA?: TestEnumVariantA;
+    testEnumVariantB?: TestEnumVariantB;
+    testEnumVariantC?: TestEnumVariantC;
+    testEnumVariantD?: TestEnumVariantD;
+    testEnumVariantE?: TestEnumVariantE;
+    testEnumVariantF?: TestEnumVariantF;
+    /**
+     * A variant with named fields.
+     *
+     * Its fields are documented *\/ as well.
+     */
+    testEnumVariantG?: TestEnumVariantG;
+
+    constructor(fields: { testEnumVariantA: TestEnumVariantA } | { testEnumVariantB: TestEnumVariantB } | { testEnumVariantC: TestEnumVariantC } | { testEnumVariantD: TestEnumVariantD } | { testEnumVariantE: TestEnumVariantE } | { testEnumVariantF: TestEnumVariantF } | { testEnumVariantG: TestEnumVariantG }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): TestEnum {
+        return deserializeUnchecked(SCHEMA, TestEnum, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export type TestEnumUnion =
+    | { kind: "VariantA" }
+    | { kind: "VariantB" }
+    | { kind: "VariantC"; value: TestEnumVariantC }
+    | { kind: "VariantD"; value: TestEnumVariantD }
+    | { kind: "VariantE"; value: TestEnumVariantE }
+    | { kind: "VariantF"; value: TestEnumVariantF }
+    | { kind: "VariantG"; value: TestEnumVariantG };
+
+export function isTestEnumVariantA(value: TestEnumUnion): value is Extract<TestEnumUnion, { kind: "VariantA" }> {
+    return value.kind === "VariantA";
+}
+
+export function isTestEnumVariantB(value: TestEnumUnion): value is Extract<TestEnumUnion, { kind: "VariantB" }> {
+    return value.kind === "VariantB";
+}
+
+export function isTestEnumVariantC(value: TestEnumUnion): value is Extract<TestEnumUnion, { kind: "VariantC" }> {
+    return value.kind === "VariantC";
+}
+
+export function isTestEnumVariantD(value: TestEnumUnion): value is Extract<TestEnumUnion, { kind: "VariantD" }> {
+    return value.kind === "VariantD";
+}
+
+export function isTestEnumVariantE(value: TestEnumUnion): value is Extract<TestEnumUnion, { kind: "VariantE" }> {
+    return value.kind === "VariantE";
+}
+
+export function isTestEnumVariantF(value: TestEnumUnion): value is Extract<TestEnumUnion, { kind: "VariantF" }> {
+    return value.kind === "VariantF";
+}
+
+export function isTestEnumVariantG(value: TestEnumUnion): value is Extract<TestEnumUnion, { kind: "VariantG" }> {
+    return value.kind === "VariantG";
+}
+
+export function toTestEnumUnion(value: TestEnum): TestEnumUnion {
+    switch (value.enum) {
+        case "testEnumVariantA":
+            return { kind: "VariantA" };
+        case "testEnumVariantB":
+            return { kind: "VariantB" };
+        case "testEnumVariantC":
+            return { kind: "VariantC", value: value.testEnumVariantC! };
+        case "testEnumVariantD":
+            return { kind: "VariantD", value: value.testEnumVariantD! };
+        case "testEnumVariantE":
+            return { kind: "VariantE", value: value.testEnumVariantE! };
+        case "testEnumVariantF":
+            return { kind: "VariantF", value: value.testEnumVariantF! };
+        case "testEnumVariantG":
+            return { kind: "VariantG", value: value.testEnumVariantG! };
+    }
+    throw new Error(`Unknown TestEnum variant: ${value.enum}`);
+}
+
+export function fromTestEnumUnion(value: TestEnumUnion): TestEnum {
+    switch (value.kind) {
+        case "VariantA":
+            return new TestEnum({ testEnumVariantA: new TestEnumVariantA({}) });
+        case "VariantB":
+            return new TestEnum({ testEnumVariantB: new TestEnumVariantB({}) });
+        case "VariantC":
+            return new TestEnum({ testEnumVariantC: value.value });
+        case "VariantD":
+            return new TestEnum({ testEnumVariantD: value.value });
+        case "VariantE":
+            return new TestEnum({ testEnumVariantE: value.value });
+        case "VariantF":
+            return new TestEnum({ testEnumVariantF: value.value });
+        case "VariantG":
+            return new TestEnum({ testEnumVariantG: value.value });
+    }
+}
+
+export class TestStruct {
+    fieldA: BN;
+    fieldB: number;
+    fieldC: OtherState[] | null;
+
+    constructor(fields: {
+        fieldA: BN,
+        fieldB: number,
+        fieldC: OtherState[] | null,
+    }) {
+        this.fieldA = fields.fieldA;
+        this.fieldB = fields.fieldB;
+        this.fieldC = fields.fieldC;
+    }
+
+    static decode(buf: Buffer): TestStruct {
+        return deserializeUnchecked(SCHEMA, TestStruct, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TierBasic {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): TierBasic {
+        return deserializeUnchecked(SCHEMA, TierBasic, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TierPremium {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): TierPremium {
+        return deserializeUnchecked(SCHEMA, TierPremium, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+/**
+ * A C-like enum with explicit discriminants but without `use_discriminant`,
+ * which borsh serializes by the variant indices.
+ */
+export class Tier {
+    enum: string;
+    tierBasic?: TierBasic;
+    tierPremium?: TierPremium;
+
+    constructor(fields: { tierBasic: TierBasic } | { tierPremium: TierPremium }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): Tier {
+        return deserializeUnchecked(SCHEMA, Tier, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export type TierUnion =
+    | { kind: "Basic" }
+    | { kind: "Premium" };
+
+export function isTierBasic(value: TierUnion): value is Extract<TierUnion, { kind: "Basic" }> {
+    return value.kind === "Basic";
+}
+
+export function isTierPremium(value: TierUnion): value is Extract<TierUnion, { kind: "Premium" }> {
+    return value.kind === "Premium";
+}
+
+export function toTierUnion(value: Tier): TierUnion {
+    switch (value.enum) {
+        case "tierBasic":
+            return { kind: "Basic" };
+        case "tierPremium":
+            return { kind: "Premium" };
+    }
+    throw new Error(`Unknown Tier variant: ${value.enum}`);
+}
+
+export function fromTierUnion(value: TierUnion): Tier {
+    switch (value.kind) {
+        case "Basic":
+            return new Tier({ tierBasic: new TierBasic({}) });
+        case "Premium":
+            return new Tier({ tierPremium: new TierPremium({}) });
+    }
+}
+
+export class TupleInstructionClose {
+    refund: boolean;
+
+    constructor(fields: {
+        refund: boolean,
+    }) {
+        this.refund = fields.refund;
+    }
+
+    static decode(buf: Buffer): TupleInstructionClose {
+        return deserializeUnchecked(SCHEMA, TupleInstructionClose, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TupleInstructionDeposit {
+    unnamed_0: BN;
+
+    constructor(fields: {
+        unnamed_0: BN,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): TupleInstructionDeposit {
+        return deserializeUnchecked(SCHEMA, TupleInstructionDeposit, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TupleInstructionReset {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): TupleInstructionReset {
+        return deserializeUnchecked(SCHEMA, TupleInstructionReset, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TupleInstructionSkipped {
+
+    constructor(_fields: {} = {}) {}
+
+    static decode(buf: Buffer): TupleInstructionSkipped {
+        return deserializeUnchecked(SCHEMA, TupleInstructionSkipped, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TupleInstructionSwap {
+    unnamed_0: Pair;
+
+    constructor(fields: {
+        unnamed_0: Pair,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): TupleInstructionSwap {
+        return deserializeUnchecked(SCHEMA, TupleInstructionSwap, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TupleInstructionTransfer {
+    unnamed_0: PublicKey;
+    unnamed_1: BN;
+
+    constructor(fields: {
+        unnamed_0: PublicKey,
+        unnamed_1: BN,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+        this.unnamed_1 = fields.unnamed_1;
+    }
+
+    static decode(buf: Buffer): TupleInstructionTransfer {
+        return deserializeUnchecked(SCHEMA, TupleInstructionTransfer, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TupleInstruction {
+    enum: string;
+    tupleInstructionReset?: TupleInstructionReset;
+    tupleInstructionDeposit?: TupleInstructionDeposit;
+    tupleInstructionTransfer?: TupleInstructionTransfer;
+    tupleInstructionSwap?: TupleInstructionSwap;
+    tupleInstructionSkipped?: TupleInstructionSkipped;
+    tupleInstructionClose?: TupleInstructionClose;
+
+    constructor(fields: { tupleInstructionReset: TupleInstructionReset } | { tupleInstructionDeposit: TupleInstructionDeposit } | { tupleInstructionTransfer: TupleInstructionTransfer } | { tupleInstructionSwap: TupleInstructionSwap } | { tupleInstructionSkipped: TupleInstructionSkipped } | { tupleInstructionClose: TupleInstructionClose }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): TupleInstruction {
+        return deserializeUnchecked(SCHEMA, TupleInstruction, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export type TupleInstructionUnion =
+    | { kind: "Reset" }
+    | { kind: "Deposit"; value: TupleInstructionDeposit }
+    | { kind: "Transfer"; value: TupleInstructionTransfer }
+    | { kind: "Swap"; value: TupleInstructionSwap }
+    | { kind: "Skipped" }
+    | { kind: "Close"; value: TupleInstructionClose };
+
+export function isTupleInstructionReset(value: TupleInstructionUnion): value is Extract<TupleInstructionUnion, { kind: "Reset" }> {
+    return value.kind === "Reset";
+}
+
+export function isTupleInstructionDeposit(value: TupleInstructionUnion): value is Extract<TupleInstructionUnion, { kind: "Deposit" }> {
+    return value.kind === "Deposit";
+}
+
+export function isTupleInstructionTransfer(value: TupleInstructionUnion): value is Extract<TupleInstructionUnion, { kind: "Transfer" }> {
+    return value.kind === "Transfer";
+}
+
+export function isTupleInstructionSwap(value: TupleInstructionUnion): value is Extract<TupleInstructionUnion, { kind: "Swap" }> {
+    return value.kind === "Swap";
+}
+
+export function isTupleInstructionSkipped(value: TupleInstructionUnion): value is Extract<TupleInstructionUnion, { kind: "Skipped" }> {
+    return value.kind === "Skipped";
+}
+
+export function isTupleInstructionClose(value: TupleInstructionUnion): value is Extract<TupleInstructionUnion, { kind: "Close" }> {
+    return value.kind === "Close";
+}
+
+export function toTupleInstructionUnion(value: TupleInstruction): TupleInstructionUnion {
+    switch (value.enum) {
+        case "tupleInstructionReset":
+            return { kind: "Reset" };
+        case "tupleInstructionDeposit":
+            return { kind: "Deposit", value: value.tupleInstructionDeposit! };
+        case "tupleInstructionTransfer":
+            return { kind: "Transfer", value: value.tupleInstructionTransfer! };
+        case "tupleInstructionSwap":
+            return { kind: "Swap", value: value.tupleInstructionSwap! };
+        case "tupleInstructionSkipped":
+            return { kind: "Skipped" };
+        case "tupleInstructionClose":
+            return { kind: "Close", value: value.tupleInstructionClose! };
+    }
+    throw new Error(`Unknown TupleInstruction variant: ${value.enum}`);
+}
+
+export function fromTupleInstructionUnion(value: TupleInstructionUnion): TupleInstruction {
+    switch (value.kind) {
+        case "Reset":
+            return new TupleInstruction({ tupleInstructionReset: new TupleInstructionReset({}) });
+        case "Deposit":
+            return new TupleInstruction({ tupleInstructionDeposit: value.value });
+        case "Transfer":
+            return new TupleInstruction({ tupleInstructionTransfer: value.value });
+        case "Swap":
+            return new TupleInstruction({ tupleInstructionSwap: value.value });
+        case "Skipped":
+            return new TupleInstruction({ tupleInstructionSkipped: new TupleInstructionSkipped({}) });
+        case "Close":
+            return new TupleInstruction({ tupleInstructionClose: value.value });
+    }
+}
+
+export class TupleStruct {
+    unnamed_0: number;
+    unnamed_1: number;
+    unnamed_2: OtherState;
+
+    constructor(fields: {
+        unnamed_0: number,
+        unnamed_1: number,
+        unnamed_2: OtherState,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+        this.unnamed_1 = fields.unnamed_1;
+        this.unnamed_2 = fields.unnamed_2;
+    }
+
+    static decode(buf: Buffer): TupleStruct {
+        return deserializeUnchecked(SCHEMA, TupleStruct, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class TupleU16Bool {
+    constructor(fields: { 0: number; 1: boolean }) {
+        return Object.defineProperty([fields[0], fields[1]], "constructor", { value: TupleU16Bool });
+    }
+};
+
+export class TupleU64Pubkey {
+    constructor(fields: { 0: BN; 1: PublicKey }) {
+        return Object.defineProperty([fields[0], fields[1]], "constructor", { value: TupleU64Pubkey });
+    }
+};
+
+export class TupleU8OptionBool {
+    constructor(fields: { 0: number; 1: boolean | null }) {
+        return Object.defineProperty([fields[0], fields[1]], "constructor", { value: TupleU8OptionBool });
+    }
+};
+
+export class CollectionWrapper {
+    map: Map<number, string>;
+    hashSet: Set<number>;
+    btreeSet: Set<string>;
+    boxed: BoxedState;
+    pair: [BN, PublicKey];
+    pairs: [number, boolean | null][];
+
+    constructor(fields: {
+        map: Map<number, string>,
+        hashSet: Set<number>,
+        btreeSet: Set<string>,
+        boxed: BoxedState,
+        pair: [BN, PublicKey],
+        pairs: [number, boolean | null][],
+    }) {
+        this.map = fields.map;
+        this.hashSet = new Set(fields.hashSet);
+        this.btreeSet = new Set(fields.btreeSet);
+        this.boxed = fields.boxed;
+        this.pair = new TupleU64Pubkey(fields.pair) as [BN, PublicKey];
+        this.pairs = fields.pairs.map((item) => new TupleU8OptionBool(item) as [number, boolean | null]);
+    }
+
+    static decode(buf: Buffer): CollectionWrapper {
+        return deserializeUnchecked(SCHEMA, CollectionWrapper, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class Vault {
+    owner: PublicKey;
+    balance: BN;
+    supply: BN;
+    delta: BN;
+    label: string;
+    history: Entry[];
+    limits: Map<number, number>;
+    seed: [4];
+    pair: [number, boolean];
+    note: string | null;
+
+    constructor(fields: {
+        owner: PublicKey,
+        balance: BN,
+        supply: BN,
+        delta: BN,
+        label: string,
+        history: Entry[],
+        limits: Map<number, number>,
+        seed: [4],
+        pair: [number, boolean],
+        note: string | null,
+    }) {
+        this.owner = fields.owner;
+        this.balance = fields.balance;
+        this.supply = fields.supply;
+        this.delta = fields.delta;
+        this.label = fields.label;
+        this.history = fields.history;
+        this.limits = fields.limits;
+        this.seed = fields.seed;
+        this.pair = new TupleU16Bool(fields.pair) as [number, boolean];
+        this.note = fields.note;
+    }
+
+    static decode(buf: Buffer): Vault {
+        return deserializeUnchecked(SCHEMA, Vault, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class WrapperPubkey {
+    inner: PublicKey;
+    history: PublicKey[];
+
+    constructor(fields: {
+        inner: PublicKey,
+        history: PublicKey[],
+    }) {
+        this.inner = fields.inner;
+        this.history = fields.history;
+    }
+
+    static decode(buf: Buffer): WrapperPubkey {
+        return deserializeUnchecked(SCHEMA, WrapperPubkey, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class WrapperString {
+    inner: string;
+    history: string[];
+
+    constructor(fields: {
+        inner: string,
+        history: string[],
+    }) {
+        this.inner = fields.inner;
+        this.history = fields.history;
+    }
+
+    static decode(buf: Buffer): WrapperString {
+        return deserializeUnchecked(SCHEMA, WrapperString, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class EitherRightU8WrapperString {
+    unnamed_0: WrapperString;
+
+    constructor(fields: {
+        unnamed_0: WrapperString,
+    }) {
+        this.unnamed_0 = fields.unnamed_0;
+    }
+
+    static decode(buf: Buffer): EitherRightU8WrapperString {
+        return deserializeUnchecked(SCHEMA, EitherRightU8WrapperString, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class EitherU8WrapperString {
+    enum: string;
+    eitherLeft?: EitherLeftU8WrapperString;
+    eitherRight?: EitherRightU8WrapperString;
+
+    constructor(fields: { eitherLeft: EitherLeftU8WrapperString } | { eitherRight: EitherRightU8WrapperString }) {
+        if (Object.keys(fields).length !== 1) {
+            throw new Error("Enum can only take single value");
+        }
+        this.enum = Object.keys(fields)[0];
+        Object.assign(this, fields);
+    }
+
+    static decode(buf: Buffer): EitherU8WrapperString {
+        return deserializeUnchecked(SCHEMA, EitherU8WrapperString, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export type EitherU8WrapperStringUnion =
+    | { kind: "Left"; value: EitherLeftU8WrapperString }
+    | { kind: "Right"; value: EitherRightU8WrapperString };
+
+export function isEitherU8WrapperStringLeft(value: EitherU8WrapperStringUnion): value is Extract<EitherU8WrapperStringUnion, { kind: "Left" }> {
+    return value.kind === "Left";
+}
+
+export function isEitherU8WrapperStringRight(value: EitherU8WrapperStringUnion): value is Extract<EitherU8WrapperStringUnion, { kind: "Right" }> {
+    return value.kind === "Right";
+}
+
+export function toEitherU8WrapperStringUnion(value: EitherU8WrapperString): EitherU8WrapperStringUnion {
+    switch (value.enum) {
+        case "eitherLeft":
+            return { kind: "Left", value: value.eitherLeft! };
+        case "eitherRight":
+            return { kind: "Right", value: value.eitherRight! };
+    }
+    throw new Error(`Unknown EitherU8WrapperString variant: ${value.enum}`);
+}
+
+export function fromEitherU8WrapperStringUnion(value: EitherU8WrapperStringUnion): EitherU8WrapperString {
+    switch (value.kind) {
+        case "Left":
+            return new EitherU8WrapperString({ eitherLeft: value.value });
+        case "Right":
+            return new EitherU8WrapperString({ eitherRight: value.value });
+    }
+}
+
+export class WrapperU64 {
+    inner: BN;
+    history: BN[];
+
+    constructor(fields: {
+        inner: BN,
+        history: BN[],
+    }) {
+        this.inner = fields.inner;
+        this.history = fields.history;
+    }
+
+    static decode(buf: Buffer): WrapperU64 {
+        return deserializeUnchecked(SCHEMA, WrapperU64, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export class GenericContainer {
+    amount: WrapperU64;
+    owner: WrapperPubkey;
+    choice: EitherU8WrapperString;
+
+    constructor(fields: {
+        amount: WrapperU64,
+        owner: WrapperPubkey,
+        choice: EitherU8WrapperString,
+    }) {
+        this.amount = fields.amount;
+        this.owner = fields.owner;
+        this.choice = fields.choice;
+    }
+
+    static decode(buf: Buffer): GenericContainer {
+        return deserializeUnchecked(SCHEMA, GenericContainer, buf);
+    }
+
+    encode(): Buffer {
+        return Buffer.from(serialize(SCHEMA, this));
+    }
+};
+
+export const SCHEMA = new Map<any, any>([
+    [
+            BTreeWrapper,
+            {
+                kind: 'struct', fields: [
+			['map0', { kind: 'map', key: [32], value: 'publicKey' }],
+			['map1', { kind: 'map', key: 'string', value: { kind: 'option', type: 'u32' } }],
+			['map2', { kind: 'map', key: 'u16', value: 'string' }],
+                ],
+            },
+    ],
+    [
+            BoxedState,
+            {
+                kind: 'struct', fields: [
+			['amount', 'u32'],
+                ],
+            },
+    ],
+    [
+            EitherLeftU8WrapperString,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u8'],
+                ],
+            },
+    ],
+    [
+            EntryClose,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            EntryDeposit,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            EntryWithdraw,
+            {
+                kind: 'struct', fields: [
+			['amount', 'u64'],
+			['fee', { kind: 'option', type: 'u16' }],
+                ],
+            },
+    ],
+    [
+            Entry,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['entryDeposit', EntryDeposit],
+			['entryWithdraw', EntryWithdraw],
+			['entryClose', EntryClose],
+                ],
+            },
+    ],
+    [
+            MintConfig,
+            {
+                kind: 'struct', fields: [
+			['maxSupply', 'u64'],
+			['mintAuthority', [32]],
+			['decimals', 'u8'],
+                ],
+            },
+    ],
+    [
+            MintUpdateDecimals,
+            {
+                kind: 'struct', fields: [
+			['decimals', 'u8'],
+                ],
+            },
+    ],
+    [
+            MintUpdateSupply,
+            {
+                kind: 'struct', fields: [
+			['newMaxSupply', 'u64'],
+                ],
+            },
+    ],
+    [
+            MintUpdate,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['mintUpdateSupply', MintUpdateSupply],
+			['mintUpdateDecimals', MintUpdateDecimals],
+                ],
+            },
+    ],
+    [
+            OtherState,
+            {
+                kind: 'struct', fields: [
+			['amount', 'u64'],
+			['timestamp', 'i64'],
+                ],
+            },
+    ],
+    [
+            AliasedStruct,
+            {
+                kind: 'struct', fields: [
+			['pool', { kind: 'option', type: [OtherState] }],
+			['amounts', ['u64']],
+			['overridden', 'u64'],
+			['key', [32]],
+                ],
+            },
+    ],
+    [
+            Pair,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+			['unnamed_1', 'publicKey'],
+                ],
+            },
+    ],
+    [
+            PoolInstructionClose,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            PoolInstructionDeposit,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            PoolInstructionInitialize,
+            {
+                kind: 'struct', fields: [
+			['fee', 'u16'],
+                ],
+            },
+    ],
+    [
+            PoolInstructionPing,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            PoolInstruction,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['poolInstructionInitialize', PoolInstructionInitialize],
+			['poolInstructionDeposit', PoolInstructionDeposit],
+			['poolInstructionClose', PoolInstructionClose],
+			['poolInstructionPing', PoolInstructionPing],
+                ],
+            },
+    ],
+    [
+            PriorityHigh,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            PriorityLow,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            Priority,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['priorityLow', PriorityLow],
+			['priorityHigh', PriorityHigh],
+                ],
+            },
+    ],
+    [
+            RandomStruct,
+            {
+                kind: 'struct', fields: [
+			['fieldA', 'string'],
+			['fieldB', { kind: 'option', type: [2] }],
+                ],
+            },
+    ],
+    [
+            SignedStruct,
+            {
+                kind: 'struct', fields: [
+			['fieldA', 'i8'],
+			['fieldB', 'i16'],
+			['fieldC', 'i32'],
+			['fieldD', 'i64'],
+			['fieldE', 'i128'],
+                ],
+            },
+    ],
+    [
+            StatusActive,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            StatusFrozen,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            StatusInactive,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            Status,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['unused0', 'u8'],
+			['unused1', 'u8'],
+			['unused2', 'u8'],
+			['unused3', 'u8'],
+			['unused4', 'u8'],
+			['statusInactive', StatusInactive],
+			['unused6', 'u8'],
+			['unused7', 'u8'],
+			['unused8', 'u8'],
+			['unused9', 'u8'],
+			['statusActive', StatusActive],
+			['statusFrozen', StatusFrozen],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantA,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TestEnumVariantB,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TestEnumVariantC,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantD,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', { kind: 'option', type: 'publicKey' }],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantE,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', { kind: 'option', type: 'u8' }],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantF,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', RandomStruct],
+                ],
+            },
+    ],
+    [
+            TestEnumVariantG,
+            {
+                kind: 'struct', fields: [
+			['hello', ['u8']],
+			['bello', ['publicKey', 3]],
+			['yello', 'u16'],
+			['zello', 'u8'],
+                ],
+            },
+    ],
+    [
+            TestEnum,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['testEnumVariantA', TestEnumVariantA],
+			['testEnumVariantB', TestEnumVariantB],
+			['testEnumVariantC', TestEnumVariantC],
+			['testEnumVariantD', TestEnumVariantD],
+			['testEnumVariantE', TestEnumVariantE],
+			['testEnumVariantF', TestEnumVariantF],
+			['testEnumVariantG', TestEnumVariantG],
+                ],
+            },
+    ],
+    [
+            TestStruct,
+            {
+                kind: 'struct', fields: [
+			['fieldA', 'u64'],
+			['fieldB', 'u8'],
+			['fieldC', { kind: 'option', type: [OtherState] }],
+                ],
+            },
+    ],
+    [
+            TierBasic,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TierPremium,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            Tier,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['tierBasic', TierBasic],
+			['tierPremium', TierPremium],
+                ],
+            },
+    ],
+    [
+            TupleInstructionClose,
+            {
+                kind: 'struct', fields: [
+			['refund', 'u8'],
+                ],
+            },
+    ],
+    [
+            TupleInstructionDeposit,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u64'],
+                ],
+            },
+    ],
+    [
+            TupleInstructionReset,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TupleInstructionSkipped,
+            {
+                kind: 'struct', fields: [
+                ],
+            },
+    ],
+    [
+            TupleInstructionSwap,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', Pair],
+                ],
+            },
+    ],
+    [
+            TupleInstructionTransfer,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'publicKey'],
+			['unnamed_1', 'u64'],
+                ],
+            },
+    ],
+    [
+            TupleInstruction,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['tupleInstructionReset', TupleInstructionReset],
+			['tupleInstructionDeposit', TupleInstructionDeposit],
+			['tupleInstructionTransfer', TupleInstructionTransfer],
+			['tupleInstructionSwap', TupleInstructionSwap],
+			['tupleInstructionSkipped', TupleInstructionSkipped],
+			['tupleInstructionClose', TupleInstructionClose],
+                ],
+            },
+    ],
+    [
+            TupleStruct,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', 'u8'],
+			['unnamed_1', 'i32'],
+			['unnamed_2', OtherState],
+                ],
+            },
+    ],
+    [
+            TupleU16Bool,
+            {
+                kind: 'struct', fields: [
+			['0', 'u16'],
+			['1', 'u8'],
+                ],
+            },
+    ],
+    [
+            TupleU64Pubkey,
+            {
+                kind: 'struct', fields: [
+			['0', 'u64'],
+			['1', 'publicKey'],
+                ],
+            },
+    ],
+    [
+            TupleU8OptionBool,
+            {
+                kind: 'struct', fields: [
+			['0', 'u8'],
+			['1', { kind: 'option', type: 'u8' }],
+                ],
+            },
+    ],
+    [
+            CollectionWrapper,
+            {
+                kind: 'struct', fields: [
+			['map', { kind: 'map', key: 'u8', value: 'string' }],
+			['hashSet', ['u16']],
+			['btreeSet', ['string']],
+			['boxed', BoxedState],
+			['pair', TupleU64Pubkey],
+			['pairs', [TupleU8OptionBool]],
+                ],
+            },
+    ],
+    [
+            Vault,
+            {
+                kind: 'struct', fields: [
+			['owner', 'publicKey'],
+			['balance', 'u64'],
+			['supply', 'u128'],
+			['delta', 'i128'],
+			['label', 'string'],
+			['history', [Entry]],
+			['limits', { kind: 'map', key: 'u8', value: 'i32' }],
+			['seed', [4]],
+			['pair', TupleU16Bool],
+			['note', { kind: 'option', type: 'string' }],
+                ],
+            },
+    ],
+    [
+            WrapperPubkey,
+            {
+                kind: 'struct', fields: [
+			['inner', 'publicKey'],
+			['history', ['publicKey']],
+                ],
+            },
+    ],
+    [
+            WrapperString,
+            {
+                kind: 'struct', fields: [
+			['inner', 'string'],
+			['history', ['string']],
+                ],
+            },
+    ],
+    [
+            EitherRightU8WrapperString,
+            {
+                kind: 'struct', fields: [
+			['unnamed_0', WrapperString],
+                ],
+            },
+    ],
+    [
+            EitherU8WrapperString,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['eitherLeft', EitherLeftU8WrapperString],
+			['eitherRight', EitherRightU8WrapperString],
+                ],
+            },
+    ],
+    [
+            WrapperU64,
+            {
+                kind: 'struct', fields: [
+			['inner', 'u64'],
+			['history', ['u64']],
+                ],
+            },
+    ],
+    [
+            GenericContainer,
+            {
+                kind: 'struct', fields: [
+			['amount', WrapperU64],
+			['owner', WrapperPubkey],
+			['choice', EitherU8WrapperString],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
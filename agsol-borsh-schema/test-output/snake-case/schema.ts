@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import Enum from "./extensions/enum";
+import Struct from "./extensions/struct";
+import { borshPublicKey } from "./extensions/publicKey";
+
+borshPublicKey();
+
+export class MintConfig extends Struct {
+    max_supply: BN;
+    mintAuthority: [32];
+    decimals: number;
+};
+
+export class MintUpdateDecimals extends Struct {
+    decimals: number;
+};
+
+export class MintUpdateSupply extends Struct {
+    new_max_supply: BN;
+};
+
+export class MintUpdate extends Enum {
+    mint_update_supply: MintUpdateSupply;
+    mint_update_decimals: MintUpdateDecimals;
+};
+
+export const SCHEMA = new Map<any, any>([
+    [
+            MintConfig,
+            {
+                kind: 'struct', fields: [
+			['max_supply', 'u64'],
+			['mintAuthority', [32]],
+			['decimals', 'u8'],
+                ],
+            },
+    ],
+    [
+            MintUpdateDecimals,
+            {
+                kind: 'struct', fields: [
+			['decimals', 'u8'],
+                ],
+            },
+    ],
+    [
+            MintUpdateSupply,
+            {
+                kind: 'struct', fields: [
+			['new_max_supply', 'u64'],
+                ],
+            },
+    ],
+    [
+            MintUpdate,
+            {
+                kind: 'enum', field: 'enum', values: [
+			['mint_update_supply', MintUpdateSupply],
+			['mint_update_decimals', MintUpdateDecimals],
+                ],
+            },
+    ],
+]);
\ No newline at end of file
@@ -1,7 +1,17 @@
 import {
+    AliasedStruct,
     BTreeWrapper,
+    CollectionWrapper,
+    EitherU8WrapperString,
+    EitherLeftU8WrapperString,
+    EitherRightU8WrapperString,
+    GenericContainer,
+    WrapperPubkey,
+    WrapperString,
+    WrapperU64,
     OtherState,
     RandomStruct,
+    SignedStruct,
     TestStruct,
     TupleStruct,
     TestEnum,
@@ -14,6 +24,8 @@ import {
     TestEnumVariantG,
     SCHEMA
 } from "./schema";
+import * as SelfContained from "./self-contained/schema";
+import * as Bounded from "./bounded/schema";
 import { PublicKey } from "@solana/web3.js";
 import BN from "bn.js";
 import { serialize, deserializeUnchecked } from "borsh";
@@ -27,6 +39,12 @@ var data = fs.readFileSync("../test-data/test_enums.json");
 const enumData = JSON.parse(data.toString());
 var data = fs.readFileSync("../test-data/test_btree.json");
 const btreeData = JSON.parse(data.toString());
+var data = fs.readFileSync("../test-data/test_collections.json");
+const collectionData = JSON.parse(data.toString());
+var data = fs.readFileSync("../test-data/test_bounded.json");
+const boundedData = JSON.parse(data.toString());
+var data = fs.readFileSync("../test-data/test_generics.json");
+const genericData = JSON.parse(data.toString());
 
 // STRUCT TESTS
 let otherStateOne = new OtherState({
@@ -68,6 +86,26 @@ let tupleStruct = new TupleStruct(
     }
 );
 
+let signedStructPositive = new SignedStruct(
+    {
+        fieldA: 100,
+        fieldB: 30000,
+        fieldC: 2000000000,
+        fieldD: new BN("9000000000"),
+        fieldE: new BN("170000000000000000000"),
+    }
+);
+
+let signedStructNegative = new SignedStruct(
+    {
+        fieldA: -100,
+        fieldB: -30000,
+        fieldC: -2000000000,
+        fieldD: new BN("-1234567890"),
+        fieldE: new BN("-170000000000000000000"),
+    }
+);
+
 // test struct with some field
 const deStructSome = deserializeUnchecked(SCHEMA, TestStruct, Buffer.from(structData.testStructSome));
 assert(deStructSome.fieldA.toNumber() === testStructSome.fieldA.toNumber());
@@ -99,10 +137,44 @@ for (var i = 0; i < serStructNone.length; i++) {
 
 const deTupleStruct = deserializeUnchecked(SCHEMA, TupleStruct, Buffer.from(structData.tupleStruct));
 assert(deTupleStruct.unnamed_0 === tupleStruct.unnamed_0);
-assert((deTupleStruct.unnamed_1 << 32) >> 32 === tupleStruct.unnamed_1);
+assert(deTupleStruct.unnamed_1 === tupleStruct.unnamed_1);
 assert(deTupleStruct.unnamed_2.amount.toNumber() === tupleStruct.unnamed_2.amount.toNumber());
 assert(deTupleStruct.unnamed_2.timestamp.toNumber() === tupleStruct.unnamed_2.timestamp.toNumber());
 
+// test automatically resolved aliases
+const deAliasedStruct = deserializeUnchecked(SCHEMA, AliasedStruct, Buffer.from(structData.aliasedStruct));
+assert(deAliasedStruct.pool.length === 1);
+assert(deAliasedStruct.pool[0].amount.toNumber() === otherStateTwo.amount.toNumber());
+assert(deAliasedStruct.amounts.map((amount) => amount.toNumber()).toString() === "1,2,3");
+assert(deAliasedStruct.overridden.toNumber() === 4);
+assert(deAliasedStruct.key.length === 32);
+assert(deAliasedStruct.key[0] === 7);
+
+const serAliasedStruct = Array.from(serialize(SCHEMA, deAliasedStruct));
+assert(serAliasedStruct.length === structData.aliasedStruct.length);
+for (var i = 0; i < serAliasedStruct.length; i++) {
+    assert(serAliasedStruct[i] === structData.aliasedStruct[i]);
+}
+
+// test signed integers
+for (const [signedStruct, signedData] of [
+    [signedStructPositive, structData.signedStructPositive],
+    [signedStructNegative, structData.signedStructNegative],
+]) {
+    const deSignedStruct = deserializeUnchecked(SCHEMA, SignedStruct, Buffer.from(signedData));
+    assert(deSignedStruct.fieldA === signedStruct.fieldA);
+    assert(deSignedStruct.fieldB === signedStruct.fieldB);
+    assert(deSignedStruct.fieldC === signedStruct.fieldC);
+    assert(deSignedStruct.fieldD.eq(signedStruct.fieldD));
+    assert(deSignedStruct.fieldE.eq(signedStruct.fieldE));
+
+    const serSignedStruct = Array.from(serialize(SCHEMA, signedStruct));
+    assert(serSignedStruct.length === signedData.length);
+    for (var i = 0; i < serSignedStruct.length; i++) {
+        assert(serSignedStruct[i] === signedData[i]);
+    }
+}
+
 // ENUM TESTS
 const testEnumVariantA = new TestEnumVariantA({});
 const testEnumVariantB = new TestEnumVariantB({});
@@ -332,3 +404,137 @@ assert(wrapperDeserialized.map1.get("yello") == null);
 assert(wrapperDeserialized.map1.get("zello") === 44);
 assert(wrapperDeserialized.map2.get(168) === "value");
 assert(wrapperDeserialized.map2.get(169) === "values");
+
+// GENERIC TESTS
+let genericPubkey = new Array(32).fill(0);
+genericPubkey[0] = 42;
+const genericOwner = new PublicKey(genericPubkey);
+
+const genericContainerLeft = new GenericContainer({
+    amount: new WrapperU64({
+        inner: new BN(1000000),
+        history: [new BN(10), new BN(20), new BN(30)],
+    }),
+    owner: new WrapperPubkey({ inner: genericOwner, history: [] }),
+    choice: new EitherU8WrapperString({
+        eitherLeft: new EitherLeftU8WrapperString({ unnamed_0: 5 }),
+    }),
+});
+
+const genericContainerRight = new GenericContainer({
+    amount: new WrapperU64({ inner: new BN(0), history: [] }),
+    owner: new WrapperPubkey({ inner: genericOwner, history: [genericOwner] }),
+    choice: new EitherU8WrapperString({
+        eitherRight: new EitherRightU8WrapperString({
+            unnamed_0: new WrapperString({ inner: "hello", history: ["bello"] }),
+        }),
+    }),
+});
+
+for (const [genericContainer, genericBytes] of [
+    [genericContainerLeft, genericData.genericContainerLeft],
+    [genericContainerRight, genericData.genericContainerRight],
+]) {
+    const serGenericContainer = Array.from(serialize(SCHEMA, genericContainer));
+    assert(serGenericContainer.length === genericBytes.length);
+    for (var i = 0; i < serGenericContainer.length; i++) {
+        assert(serGenericContainer[i] === genericBytes[i]);
+    }
+}
+
+const deGenericContainerLeft = deserializeUnchecked(
+    SCHEMA,
+    GenericContainer,
+    Buffer.from(genericData.genericContainerLeft)
+);
+assert(deGenericContainerLeft.amount.inner.toNumber() === 1000000);
+assert(deGenericContainerLeft.amount.history.length === 3);
+assert(deGenericContainerLeft.owner.inner.toString() === genericOwner.toString());
+assert(deGenericContainerLeft.choice.enum === "eitherLeft");
+assert(deGenericContainerLeft.choice.eitherLeft.unnamed_0 === 5);
+
+const deGenericContainerRight = deserializeUnchecked(
+    SCHEMA,
+    GenericContainer,
+    Buffer.from(genericData.genericContainerRight)
+);
+assert(deGenericContainerRight.owner.history[0].toString() === genericOwner.toString());
+assert(deGenericContainerRight.choice.enum === "eitherRight");
+assert(deGenericContainerRight.choice.eitherRight.unnamed_0.inner === "hello");
+assert(deGenericContainerRight.choice.eitherRight.unnamed_0.history[0] === "bello");
+
+// SELF-CONTAINED CLASS TESTS
+const selfContainedStruct = SelfContained.TestStruct.decode(Buffer.from(structData.testStructSome));
+assert(selfContainedStruct instanceof SelfContained.TestStruct);
+assert(selfContainedStruct.fieldA.toNumber() === testStructSome.fieldA.toNumber());
+assert(selfContainedStruct.fieldC.length === 3);
+assert(selfContainedStruct.fieldC[2].timestamp.toNumber() === otherStateThree.timestamp.toNumber());
+assert(selfContainedStruct.encode().equals(Buffer.from(structData.testStructSome)));
+
+const selfContainedSigned = new SelfContained.SignedStruct({
+    fieldA: -100,
+    fieldB: -30000,
+    fieldC: -2000000000,
+    fieldD: new BN("-1234567890"),
+    fieldE: new BN("-170000000000000000000"),
+});
+assert(selfContainedSigned.encode().equals(Buffer.from(structData.signedStructNegative)));
+
+const selfContainedEnum = new SelfContained.TestEnum({
+    testEnumVariantC: new SelfContained.TestEnumVariantC({ unnamed_0: new BN(1234567890) }),
+});
+assert(selfContainedEnum.enum === "testEnumVariantC");
+assert(selfContainedEnum.encode().equals(Buffer.from(enumData.enumVariantC)));
+const deSelfContainedEnum = SelfContained.TestEnum.decode(Buffer.from(enumData.enumVariantG));
+assert(deSelfContainedEnum.enum === "testEnumVariantG");
+assert(deSelfContainedEnum.testEnumVariantG.yello === 234);
+
+// DISCRIMINATED UNION TESTS
+const unionC = SelfContained.toTestEnumUnion(SelfContained.TestEnum.decode(Buffer.from(enumData.enumVariantC)));
+assert(SelfContained.isTestEnumVariantC(unionC));
+switch (unionC.kind) {
+    case "VariantC":
+        assert(unionC.value.unnamed_0.toNumber() === 1234567890);
+        break;
+    default:
+        assert(false);
+}
+const unionA: SelfContained.TestEnumUnion = { kind: "VariantA" };
+assert(!SelfContained.isTestEnumVariantC(unionA));
+assert(SelfContained.fromTestEnumUnion(unionA).encode().equals(Buffer.from(enumData.enumVariantA)));
+assert(SelfContained.fromTestEnumUnion(unionC).encode().equals(Buffer.from(enumData.enumVariantC)));
+
+// COLLECTION TESTS
+const collections = deserializeUnchecked(SCHEMA, CollectionWrapper, Buffer.from(collectionData));
+assert(collections.map.get(1) === "one");
+assert(collections.map.get(2) === "two");
+assert(collections.hashSet.size === 2);
+assert(collections.hashSet.has(5) && collections.hashSet.has(300));
+assert(collections.btreeSet.has("a") && collections.btreeSet.has("b"));
+assert(collections.boxed.amount === 7);
+assert(collections.pair[0].toNumber() === 10);
+assert(collections.pair[1].toString() === new PublicKey(new Uint8Array(32).fill(3)).toString());
+assert(collections.pairs.length === 2);
+assert(collections.pairs[0][1] === null);
+assert(collections.pairs[1][0] === 2 && collections.pairs[1][1] as any === 1);
+assert(Buffer.from(serialize(SCHEMA, collections)).equals(Buffer.from(collectionData)));
+// plain sets and tuples are converted by the constructor
+const rebuiltCollections = new CollectionWrapper({
+    ...collections,
+    hashSet: new Set([5, 300]),
+    pair: [new BN(10), new PublicKey(new Uint8Array(32).fill(3))],
+});
+assert(Buffer.from(serialize(SCHEMA, rebuiltCollections)).equals(Buffer.from(collectionData)));
+
+// BOUNDED CONTAINER TESTS
+const guild = Bounded.Guild.decode(Buffer.from(boundedData));
+assert(guild.name === "agora");
+assert(guild.name.length <= Bounded.GUILD_NAME_MAX_LEN);
+assert(guild.admins.length <= Bounded.GUILD_ADMINS_MAX_LEN);
+assert(guild.members.length === 2);
+assert(guild.roles.get(1) === "member");
+assert(guild.lockedFunds.length <= Bounded.GUILD_LOCKED_FUNDS_MAX_LEN);
+assert(guild.lockedFunds[0].item.toNumber() === 100);
+assert(guild.lockedFunds[0].expires.toNumber() === 1650000000);
+assert(guild.lastLock!.expires.toNumber() === -1);
+assert(guild.encode().equals(Buffer.from(boundedData)));
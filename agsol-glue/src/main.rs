@@ -1,4 +1,7 @@
-use agsol_borsh_schema::{generate_layouts, generate_output};
+use agsol_borsh_schema::{
+    breaking_changes, generate_layouts_with_options, generate_output, schema_warnings, FieldNaming,
+    LayoutOptions, SchemaError,
+};
 use structopt::StructOpt;
 
 use std::path::{Path, PathBuf};
@@ -11,6 +14,31 @@ enum GlueCmd {
             help = "path to the directory containing rust data structures for schema generation"
         )]
         path: PathBuf,
+        #[structopt(
+            short = "-f",
+            long,
+            help = "features enabled when evaluating '#[cfg(...)]' attributes"
+        )]
+        features: Vec<String>,
+        #[structopt(
+            short = "-n",
+            long,
+            default_value = "camelCase",
+            help = "naming convention of the fields: 'camelCase', 'snake_case' or 'preserve'"
+        )]
+        naming: FieldNaming,
+    },
+    Check {
+        #[structopt(help = "path to the directory containing the old rust data structures")]
+        old: PathBuf,
+        #[structopt(help = "path to the directory containing the new rust data structures")]
+        new: PathBuf,
+        #[structopt(
+            short = "-f",
+            long,
+            help = "features enabled when evaluating '#[cfg(...)]' attributes"
+        )]
+        features: Vec<String>,
     },
     Wasm {
         #[structopt(help = "path to the directory containing wasm bindings")]
@@ -38,12 +66,58 @@ struct Glue {
 fn main() -> Result<(), anyhow::Error> {
     let glue = Glue::from_args();
 
-    clone_template(&glue.output)?;
+    if !matches!(glue.cmd, GlueCmd::Check { .. }) {
+        clone_template(&glue.output)?;
+    }
 
     match glue.cmd {
-        GlueCmd::Schema { path } => {
-            let layouts = generate_layouts(path)?;
-            generate_output(&layouts, &glue.output)?;
+        GlueCmd::Schema {
+            path,
+            features,
+            naming,
+        } => {
+            let options = LayoutOptions {
+                features,
+                field_naming: naming,
+                ..LayoutOptions::default()
+            };
+            let result = generate_layouts_with_options(path, &options).and_then(|layouts| {
+                for warning in schema_warnings(&layouts) {
+                    eprintln!("warning: {}", warning);
+                }
+                generate_output(&layouts, &glue.output)
+            });
+            if let Err(error) = result {
+                print_diagnostic(&error);
+                std::process::exit(1);
+            }
+        }
+        GlueCmd::Check { old, new, features } => {
+            let options = LayoutOptions {
+                features,
+                ..LayoutOptions::default()
+            };
+            let result = generate_layouts_with_options(old, &options)
+                .and_then(|old| generate_layouts_with_options(new, &options).map(|new| (old, new)));
+            match result {
+                Ok((old, new)) => {
+                    let changes = breaking_changes(&old, &new);
+                    for change in &changes {
+                        if change.breaks_serialization() {
+                            eprintln!("error: {}", change);
+                        } else {
+                            eprintln!("warning: {}", change);
+                        }
+                    }
+                    if changes.iter().any(|change| change.breaks_serialization()) {
+                        std::process::exit(1);
+                    }
+                }
+                Err(error) => {
+                    print_diagnostic(&error);
+                    std::process::exit(1);
+                }
+            }
         }
         GlueCmd::Wasm {
             path,
@@ -85,6 +159,15 @@ fn main() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+/// Prints a schema generation error in a compiler-like format, pointing to
+/// the offending source code if possible.
+fn print_diagnostic(error: &SchemaError) {
+    eprintln!("error: {}", error);
+    if let Some(location) = error.location() {
+        eprintln!("  --> {}", location);
+    }
+}
+
 fn clone_template(output_dir: &Path) -> Result<(), anyhow::Error> {
     if !output_dir.is_dir() {
         let output_dir_string = output_dir.to_string_lossy();